@@ -23,3 +23,25 @@ sol! {
         error MyOtherError(uint32 a, uint32 b);
     }
 }
+
+#[test]
+fn contract_items_resolve_custom_crate_path() {
+    use alloy_core::sol_types::{SolCall, SolError, SolEvent, SolStruct};
+
+    assert_eq!(MyContract::MyOtherStruct::eip712_encode_type(), "MyOtherStruct(uint32 a,uint32 b)");
+    let call = MyContract::myOtherFunctionCall { a: 1, b: 2 };
+    assert_eq!(MyContract::myOtherFunctionCall::abi_decode(&call.abi_encode(), true).unwrap().b, 2);
+    assert_eq!(MyContract::MyOtherEvent::SIGNATURE, "MyOtherEvent(uint32,uint32)");
+    assert_eq!(MyContract::MyOtherError::SIGNATURE, "MyOtherError(uint32,uint32)");
+    assert_eq!(myFunctionCall::SIGNATURE, "myFunction(uint32,uint32)");
+}
+
+#[test]
+fn selectors() {
+    use alloy_core::sol_types::SolCall;
+
+    sol!(selectors {
+        myFunction(uint32,uint32),
+    });
+    assert_eq!(myFunction, myFunctionCall::SELECTOR);
+}
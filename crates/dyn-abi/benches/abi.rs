@@ -2,7 +2,7 @@
 
 use alloy_dyn_abi::{DynSolType, DynSolValue};
 use alloy_primitives::{hex, U256};
-use alloy_sol_types::{sol, sol_data, SolType, SolValue};
+use alloy_sol_types::{abi::DecodeBuffer, sol, sol_data, SolCall, SolType, SolValue};
 use criterion::{
     criterion_group, criterion_main, measurement::WallTime, BenchmarkGroup, Criterion,
 };
@@ -100,6 +100,18 @@ fn sol_types_encode(c: &mut Criterion) {
         b.iter(|| black_box(&input).abi_encode());
     });
 
+    // Encoding a large array alongside other values, either by cloning them
+    // into an owned tuple or by borrowing them.
+    g.bench_function("array/owned", |b| {
+        let (array, string) = encode_array_input();
+        b.iter(|| (black_box(&array).clone(), black_box(&string).clone()).abi_encode());
+    });
+
+    g.bench_function("array/borrowed", |b| {
+        let (array, string) = encode_array_input();
+        b.iter(|| (black_box(&array), black_box(&string)).abi_encode());
+    });
+
     g.finish();
 }
 
@@ -116,6 +128,31 @@ fn sol_types_decode(c: &mut Criterion) {
         b.iter(|| sol_data::String::abi_decode(black_box(&input), false).unwrap());
     });
 
+    // Decoding calldata with the selector check, or only the arguments, e.g.
+    // after a dispatcher has already matched the selector.
+    g.bench_function("call", |b| {
+        let input = decode_call_input();
+        b.iter(|| exactInputSingleCall::abi_decode(black_box(&input), false).unwrap());
+    });
+
+    g.bench_function("call/raw", |b| {
+        let input = decode_call_input();
+        b.iter(|| exactInputSingleCall::abi_decode_raw(black_box(&input[4..]), false).unwrap());
+    });
+
+    // Validated decoding re-encodes the result, which allocates on every call
+    // unless a scratch buffer is reused.
+    g.bench_function("validate", |b| {
+        let input = decode_dynamic_input();
+        b.iter(|| sol_data::String::abi_decode(black_box(&input), true).unwrap());
+    });
+
+    g.bench_function("validate/buffer", |b| {
+        let input = decode_dynamic_input();
+        let mut buf = DecodeBuffer::new();
+        b.iter(|| sol_data::String::abi_decode_with(black_box(&input), true, &mut buf).unwrap());
+    });
+
     g.finish();
 }
 
@@ -132,6 +169,8 @@ sol! {
         uint256 amountOutMinimum;
         uint160 sqrtPriceLimitX96;
     }
+
+    function exactInputSingle(Input calldata params) external payable returns (uint256 amountOut);
 }
 
 fn encode_single_input() -> String {
@@ -151,6 +190,10 @@ fn encode_struct_input() -> Input {
     }
 }
 
+fn encode_array_input() -> (Vec<U256>, String) {
+    ((0..1000_u64).map(U256::from).collect(), encode_single_input())
+}
+
 fn encode_struct_input_tokens() -> [ethabi::Token; 8] {
     let input = encode_struct_input();
     [
@@ -196,6 +239,10 @@ fn decode_dynamic_input() -> Vec<u8> {
     .to_vec()
 }
 
+fn decode_call_input() -> Vec<u8> {
+    exactInputSingleCall { params: encode_struct_input() }.abi_encode()
+}
+
 fn group<'a>(c: &'a mut Criterion, group_name: &str) -> BenchmarkGroup<'a, WallTime> {
     let mut g = c.benchmark_group(group_name);
     g.noise_threshold(0.03)
@@ -47,6 +47,9 @@ impl DynSolType {
         doc = "- [`CustomStruct`](DynSolType::CustomStruct): the same as `Tuple`"
     )]
     ///
+    /// `bytes` and `bytesN` values are [displayed](DynSolValue#impl-Display-for-DynSolValue) as
+    /// `0x`-prefixed hex, so they can be coerced back from their `Display` output.
+    ///
     /// # Examples
     ///
     /// ```
@@ -990,6 +993,55 @@ mod tests {
         */
     }
 
+    #[test]
+    fn bytes_display_round_trip() {
+        let mk_word = |sl: &[u8]| {
+            let mut out = Word::ZERO;
+            out[..sl.len()].copy_from_slice(sl);
+            out
+        };
+
+        let values = [
+            DynSolValue::Bytes(vec![]),
+            DynSolValue::Bytes(vec![0x00]),
+            DynSolValue::Bytes(vec![0xde, 0xad, 0xbe, 0xef, 0x00]),
+            DynSolValue::FixedBytes(mk_word(&[]), 0),
+            DynSolValue::FixedBytes(mk_word(&[0xab]), 1),
+            DynSolValue::FixedBytes(mk_word(&[0x00, 0x17, 0x00]), 3),
+            DynSolValue::FixedBytes(Word::repeat_byte(0x42), 32),
+            DynSolValue::Tuple(vec![
+                DynSolValue::Bytes(vec![0x12, 0x34]),
+                DynSolValue::Array(vec![DynSolValue::FixedBytes(mk_word(&[0xff; 4]), 4)]),
+            ]),
+        ];
+        for value in values {
+            let ty = value.as_type().unwrap();
+            let s = value.to_string();
+            assert!(s.contains("0x"), "{s}");
+            assert_eq!(ty.coerce_str(&s).unwrap(), value, "{s}");
+        }
+
+        assert_eq!(DynSolValue::Bytes(vec![0xab, 0xcd]).to_string(), "0xabcd");
+        assert_eq!(DynSolValue::FixedBytes(mk_word(&[0xab, 0xcd]), 2).to_string(), "0xabcd");
+
+        // fixed bytes must have exactly the right length
+        let bytes32 = DynSolType::FixedBytes(32);
+        let e = bytes32.coerce_str(&hex::encode_prefixed([0x42; 31])).unwrap_err();
+        assert_error_contains(&e, &hex::FromHexError::InvalidStringLength.to_string());
+        let e = bytes32.coerce_str(&hex::encode_prefixed([0x42; 33])).unwrap_err();
+        assert_error_contains(&e, &hex::FromHexError::InvalidStringLength.to_string());
+
+        // dynamic bytes accept any even length
+        for len in [0, 1, 31, 32, 33, 100] {
+            let s = hex::encode_prefixed(vec![0x42; len]);
+            assert_eq!(
+                DynSolType::Bytes.coerce_str(&s).unwrap(),
+                DynSolValue::Bytes(vec![0x42; len])
+            );
+        }
+        assert!(DynSolType::Bytes.coerce_str("0xabc").is_err());
+    }
+
     #[test]
     fn coerce_string() {
         assert_eq!(
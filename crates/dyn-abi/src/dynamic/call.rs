@@ -131,6 +131,7 @@ pub(crate) fn encode_typeck(tys: &[DynSolType], values: &[DynSolValue]) -> Resul
                 actual: value.sol_type_name().unwrap_or_else(|| "<none>".into()).into_owned(),
             });
         }
+        value.check_int_ranges()?;
     }
 
     Ok(abi_encode(values))
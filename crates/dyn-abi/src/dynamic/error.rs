@@ -1,5 +1,5 @@
 use crate::{DynSolType, DynSolValue, Error, Result};
-use alloc::vec::Vec;
+use alloc::{borrow::Cow, boxed::Box, vec::Vec};
 use alloy_primitives::Selector;
 use alloy_sol_types::SolError;
 
@@ -78,11 +78,20 @@ impl DynSolError {
 
     /// Decode the error from the given data.
     pub fn decode_error(&self, data: &[u8]) -> Result<DecodedError> {
+        let Some(actual) = data.get(..4) else {
+            return Err(alloy_sol_types::Error::Decode {
+                offset: 0,
+                ty: Some("bytes4".into()),
+                error: Box::new(alloy_sol_types::Error::Overrun),
+            }
+            .into());
+        };
+
         // Check selector validity.
-        if !data.starts_with(self.selector.as_slice()) {
+        if actual != self.selector.as_slice() {
             return Err(Error::SelectorMismatch {
                 expected: self.selector,
-                actual: Selector::from_slice(&data[0..4]),
+                actual: Selector::from_slice(actual),
             });
         }
 
@@ -90,6 +99,55 @@ impl DynSolError {
         let data = data.split_at(4).1;
         self.decode_error_body(data)
     }
+
+    /// Decodes revert data with the first of the given errors whose selector
+    /// matches, falling back to the built-in [`revert`](Self::revert) and
+    /// [`panic`](Self::panic) errors if none of them do.
+    ///
+    /// Returns the matched error along with the decoded body, or `None` if no
+    /// error matches the selector or if the data fails to decode.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use alloy_dyn_abi::{DynSolError, DynSolValue, JsonAbiExt, Specifier};
+    /// use alloy_json_abi::Error;
+    /// use alloy_primitives::U256;
+    ///
+    /// let error = Error::parse("error InsufficientBalance(uint256 available, uint256 required)")?;
+    /// let errors = [error.resolve()?];
+    ///
+    /// let data = error.abi_encode_input(&[U256::from(5).into(), U256::from(10).into()])?;
+    /// let (matched, decoded) = DynSolError::decode_revert(&errors, &data).unwrap();
+    /// assert_eq!(matched.selector(), error.selector());
+    /// assert_eq!(decoded.body, [U256::from(5).into(), U256::from(10).into()]);
+    ///
+    /// // `Error(string)` and `Panic(uint256)` are always recognized.
+    /// let data = alloy_sol_types::SolError::abi_encode(&alloy_sol_types::Revert::from("oops"));
+    /// let (matched, decoded) = DynSolError::decode_revert(&errors, &data).unwrap();
+    /// assert_eq!(*matched, DynSolError::revert());
+    /// assert_eq!(decoded.body, [DynSolValue::String("oops".into())]);
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn decode_revert<'a, I>(errors: I, data: &[u8]) -> Option<(Cow<'a, Self>, DecodedError)>
+    where
+        I: IntoIterator<Item = &'a Self>,
+    {
+        if data.len() < 4 {
+            return None;
+        }
+        let (selector, body) = data.split_at(4);
+        let error = match errors.into_iter().find(|error| error.selector == selector) {
+            Some(error) => Cow::Borrowed(error),
+            None => match Selector::from_slice(selector) {
+                REVERT_SELECTOR => Cow::Owned(Self::revert()),
+                PANIC_SELECTOR => Cow::Owned(Self::panic()),
+                _ => return None,
+            },
+        };
+        let decoded = error.decode_error_body(body).ok()?;
+        Some((error, decoded))
+    }
 }
 
 /// A decoded dynamic ABI error.
@@ -102,8 +160,9 @@ pub struct DecodedError {
 #[cfg(test)]
 mod test {
     use super::DynSolError;
-    use crate::DynSolValue;
-    use alloy_primitives::hex;
+    use crate::{DynSolType, DynSolValue};
+    use alloc::borrow::Cow;
+    use alloy_primitives::{hex, U256};
 
     #[test]
     fn decode_revert_message() {
@@ -122,4 +181,61 @@ mod test {
         let decoded = error.decode_error(&data).unwrap();
         assert_eq!(decoded.body, vec![DynSolValue::Uint(alloy_primitives::Uint::from(1), 256)]);
     }
+
+    #[test]
+    fn decode_short_data() {
+        let error = DynSolError::revert();
+        error.decode_error(&[]).unwrap_err();
+        let err = error.decode_error(&hex!("08c379")).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "failed to decode bytes4 at offset 0: buffer overrun while deserializing"
+        );
+        assert_eq!(DynSolError::decode_revert([&error], &hex!("08c379")), None);
+    }
+
+    #[test]
+    fn decode_revert() {
+        let custom = DynSolError::new(
+            alloy_primitives::fixed_bytes!("cf479181"),
+            DynSolType::Tuple(vec![DynSolType::Uint(256), DynSolType::Uint(256)]),
+        )
+        .unwrap();
+        let errors = [custom.clone()];
+
+        let data = hex!(
+            "cf479181"
+            "0000000000000000000000000000000000000000000000000000000000000005"
+            "000000000000000000000000000000000000000000000000000000000000000a"
+        );
+        let (matched, decoded) = DynSolError::decode_revert(&errors, &data).unwrap();
+        assert!(matches!(matched, Cow::Borrowed(_)));
+        assert_eq!(*matched, custom);
+        assert_eq!(decoded.body, [U256::from(5).into(), U256::from(10).into()]);
+
+        // falls back to the built-in errors
+        let data = hex!("4e487b710000000000000000000000000000000000000000000000000000000000000011");
+        let (matched, decoded) = DynSolError::decode_revert(&errors, &data).unwrap();
+        assert_eq!(*matched, DynSolError::panic());
+        assert_eq!(decoded.body, [DynSolValue::Uint(U256::from(0x11), 256)]);
+
+        let data = hex!("08c379a0000000000000000000000000000000000000000000000000000000000000002000000000000000000000000000000000000000000000000000000000000000042020202000000000000000000000000000000000000000000000000000000000");
+        let (matched, decoded) = DynSolError::decode_revert(&errors, &data).unwrap();
+        assert_eq!(*matched, DynSolError::revert());
+        assert_eq!(decoded.body, [DynSolValue::String("    ".into())]);
+
+        // custom errors take precedence over the built-in ones
+        let shadow =
+            DynSolError::new(super::REVERT_SELECTOR, DynSolType::Tuple(vec![DynSolType::Bytes]))
+                .unwrap();
+        let (matched, decoded) = DynSolError::decode_revert([&shadow], &data).unwrap();
+        assert_eq!(*matched, shadow);
+        assert_eq!(decoded.body, [DynSolValue::Bytes(b"    ".to_vec())]);
+
+        // unknown selector
+        let data = hex!("deadbeef0000000000000000000000000000000000000000000000000000000000000001");
+        assert_eq!(DynSolError::decode_revert(&errors, &data), None);
+        // matching selector, but invalid body
+        assert_eq!(DynSolError::decode_revert(&errors, &hex!("cf479181")), None);
+    }
 }
@@ -0,0 +1,149 @@
+use crate::{DynSolType, DynSolValue, Result};
+use alloc::string::ToString;
+use serde_json::Value;
+
+impl DynSolType {
+    /// ABI-decodes `data` as this type, and converts the result to JSON.
+    ///
+    /// Data is decoded with [`abi_decode_params`](Self::abi_decode_params), and
+    /// converted with [`DynSolValue::to_json`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use alloy_dyn_abi::{DynSolType, DynSolValue};
+    /// use alloy_primitives::U256;
+    /// use serde_json::json;
+    ///
+    /// let ty: DynSolType = "(uint256,bool[])".parse()?;
+    /// let value = DynSolValue::Tuple(vec![
+    ///     DynSolValue::Uint(U256::MAX, 256),
+    ///     DynSolValue::Array(vec![DynSolValue::Bool(true)]),
+    /// ]);
+    /// let json = ty.decode_as_json(&value.abi_encode_params())?;
+    /// assert_eq!(json, json!([U256::MAX.to_string(), [true]]));
+    /// # Ok::<_, alloy_dyn_abi::Error>(())
+    /// ```
+    pub fn decode_as_json(&self, data: &[u8]) -> Result<Value> {
+        self.abi_decode_params(data).map(|value| value.to_json())
+    }
+}
+
+impl DynSolValue {
+    /// Converts this value to JSON.
+    ///
+    /// - integers are decimal strings, so that large values are not rounded to floats;
+    /// - addresses are [EIP-55] checksummed strings;
+    /// - fixed bytes, bytes and function pointers are `0x`-prefixed hex strings;
+    /// - arrays and tuples are arrays;
+    /// - custom structs are objects keyed by their property names.
+    ///
+    /// [EIP-55]: https://eips.ethereum.org/EIPS/eip-55
+    pub fn to_json(&self) -> Value {
+        match self {
+            Self::Bool(b) => Value::Bool(*b),
+            Self::Int(i, _) => Value::String(i.to_string()),
+            Self::Uint(u, _) => Value::String(u.to_string()),
+            Self::FixedBytes(word, size) => {
+                Value::String(hex::encode_prefixed(&word[..(*size).min(32)]))
+            }
+            Self::Address(address) => Value::String(address.to_checksum(None)),
+            Self::Function(function) => Value::String(hex::encode_prefixed(function)),
+            Self::Bytes(bytes) => Value::String(hex::encode_prefixed(bytes)),
+            Self::String(s) => Value::String(s.clone()),
+            Self::Array(values) | Self::FixedArray(values) | Self::Tuple(values) => {
+                Value::Array(values.iter().map(Self::to_json).collect())
+            }
+            #[cfg(feature = "eip712")]
+            Self::CustomStruct { prop_names, tuple, .. } => Value::Object(
+                prop_names.iter().cloned().zip(tuple.iter().map(Self::to_json)).collect(),
+            ),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_primitives::{address, Address, I256, U256};
+    use serde_json::json;
+
+    #[test]
+    fn nested_tuples() {
+        let ty: DynSolType = "(int8,(address,bytes4,(uint256,string)[]),bytes)".parse().unwrap();
+        let addr = address!("5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed");
+        let value = DynSolValue::Tuple(vec![
+            DynSolValue::Int(I256::MINUS_ONE, 8),
+            DynSolValue::Tuple(vec![
+                DynSolValue::Address(addr),
+                DynSolValue::FixedBytes(alloy_primitives::B256::repeat_byte(0xab), 4),
+                DynSolValue::Array(vec![
+                    DynSolValue::Tuple(vec![
+                        DynSolValue::Uint(U256::MAX, 256),
+                        DynSolValue::String("a".into()),
+                    ]),
+                    DynSolValue::Tuple(vec![
+                        DynSolValue::Uint(U256::from(1), 256),
+                        DynSolValue::String("".into()),
+                    ]),
+                ]),
+            ]),
+            DynSolValue::Bytes(vec![0xde, 0xad]),
+        ]);
+
+        let json = ty.decode_as_json(&value.abi_encode_params()).unwrap();
+        assert_eq!(
+            json,
+            json!([
+                "-1",
+                [
+                    "0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed",
+                    "0xabababab",
+                    [[U256::MAX.to_string(), "a"], ["1", ""]],
+                ],
+                "0xdead",
+            ])
+        );
+    }
+
+    #[test]
+    fn large_integers_are_strings() {
+        let json = DynSolValue::Uint(U256::from(u64::MAX) + U256::from(1), 256).to_json();
+        assert_eq!(json, json!("18446744073709551616"));
+        assert_eq!(DynSolValue::Uint(U256::ZERO, 8).to_json(), json!("0"));
+        assert_eq!(DynSolValue::Int(I256::MIN, 256).to_json(), json!(I256::MIN.to_string()));
+        assert_eq!(DynSolValue::Address(Address::ZERO).to_json(), json!(Address::ZERO.to_string()));
+    }
+
+    // the size of a manually constructed value may exceed the word size
+    #[test]
+    fn fixed_bytes_size_is_clamped() {
+        let word = alloy_primitives::B256::repeat_byte(0xab);
+        assert_eq!(DynSolValue::FixedBytes(word, 0).to_json(), json!("0x"));
+        assert_eq!(DynSolValue::FixedBytes(word, 33).to_json(), json!(word.to_string()));
+        assert_eq!(DynSolValue::FixedBytes(word, usize::MAX).to_json(), json!(word.to_string()));
+    }
+
+    #[test]
+    #[cfg(feature = "eip712")]
+    fn custom_struct() {
+        let ty = DynSolType::CustomStruct {
+            name: "Pair".into(),
+            prop_names: vec!["amount".into(), "inner".into()],
+            tuple: vec![
+                DynSolType::Uint(256),
+                DynSolType::Tuple(vec![DynSolType::Bool, DynSolType::String]),
+            ],
+        };
+        let value = DynSolValue::CustomStruct {
+            name: "Pair".into(),
+            prop_names: vec!["amount".into(), "inner".into()],
+            tuple: vec![
+                DynSolValue::Uint(U256::from(10), 256),
+                DynSolValue::Tuple(vec![DynSolValue::Bool(false), DynSolValue::String("x".into())]),
+            ],
+        };
+        let json = ty.decode_as_json(&value.abi_encode_params()).unwrap();
+        assert_eq!(json, json!({ "amount": "10", "inner": [false, "x"] }));
+    }
+}
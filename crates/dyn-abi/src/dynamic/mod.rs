@@ -14,4 +14,7 @@ mod token;
 pub use token::DynToken;
 
 mod value;
-pub use value::DynSolValue;
+pub use value::{abi_encode_params, DynSolValue};
+
+#[cfg(feature = "serde_json")]
+mod json;
@@ -173,9 +173,12 @@ impl<'a> DynToken<'a> {
 
                 // Check that the decoder contains enough words to decode the
                 // sequence. Each item in the sequence is at least one word, so
-                // the remaining words must be at least the size of the sequence
-                if child.remaining_words() < template.minimum_words() * size {
-                    return Err(alloy_sol_types::Error::Overrun.into());
+                // the remaining words must be at least the size of the sequence.
+                // The multiplication is checked, as `size` is read from the
+                // untrusted input and may be as large as `usize::MAX`
+                let min_words = template.minimum_words().checked_mul(size);
+                if min_words.map_or(true, |words| child.remaining_words() < words) {
+                    return Err(child.error_at(0, alloy_sol_types::Error::Overrun).into());
                 }
 
                 let mut new_tokens = if size == 1 {
@@ -1,8 +1,10 @@
 use crate::{DynSolValue, DynToken, Error, Result, SolType, Specifier, Word};
 use alloc::{borrow::Cow, boxed::Box, string::String, vec::Vec};
+use alloy_json_abi::Param;
 use alloy_primitives::{
     try_vec,
     utils::{box_try_new, vec_try_with_capacity},
+    Address, Function, I256, U256,
 };
 use alloy_sol_types::{abi::Decoder, sol_data};
 use core::{fmt, iter::zip, num::NonZeroUsize, str::FromStr};
@@ -77,6 +79,7 @@ pub(crate) use as_tuple;
 /// # Ok::<_, alloy_dyn_abi::Error>(())
 /// ```
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum DynSolType {
     /// Boolean.
     Bool,
@@ -131,9 +134,25 @@ impl FromStr for DynSolType {
     }
 }
 
+impl TryFrom<&Param> for DynSolType {
+    type Error = Error;
+
+    /// Converts a JSON ABI parameter into a [`DynSolType`].
+    ///
+    /// On failure, the error is wrapped in [`Error::InvalidParam`], which
+    /// includes the parameter's name, type string and internal type.
+    #[inline]
+    fn try_from(param: &Param) -> Result<Self, Self::Error> {
+        param.resolve().map_err(|e| Error::invalid_param(param, e))
+    }
+}
+
 impl DynSolType {
     /// Parses a Solidity type name string into a [`DynSolType`].
     ///
+    /// The `uint`, `int` and `byte` aliases are normalized to `uint256`, `int256`
+    /// and `bytes1`. Fixed-point types, `fixed` and `ufixed`, are not supported.
+    ///
     /// # Examples
     ///
     /// ```
@@ -147,6 +166,9 @@ impl DynSolType {
     /// // alternatively, you can use the FromStr impl
     /// let ty2 = type_name.parse::<DynSolType>()?;
     /// assert_eq!(ty2, ty);
+    ///
+    /// assert_eq!(DynSolType::parse("uint")?, ty);
+    /// assert!(DynSolType::parse("ufixed128x18").is_err());
     /// # Ok::<_, alloy_dyn_abi::Error>(())
     /// ```
     #[inline]
@@ -154,6 +176,105 @@ impl DynSolType {
         TypeSpecifier::parse(s).map_err(Error::TypeParser).and_then(|t| t.resolve())
     }
 
+    /// Parses a Solidity type name string into a [`DynSolType`], allowing
+    /// tuples to be nested up to `max_depth` levels deep.
+    ///
+    /// [`parse`](Self::parse) uses a default limit of
+    /// [`DEFAULT_MAX_DEPTH`](alloy_sol_type_parser::DEFAULT_MAX_DEPTH). Deeper
+    /// types are rejected with an error instead of overflowing the stack.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use alloy_dyn_abi::DynSolType;
+    /// let ty = DynSolType::parse_with_max_depth("((uint256,),bool)", 2)?;
+    /// assert_eq!(ty.nesting_depth(), 2);
+    ///
+    /// let err = DynSolType::parse_with_max_depth("((uint256,),bool)", 1).unwrap_err();
+    /// assert!(err.to_string().contains("nesting depth exceeds the maximum of 1"));
+    /// # Ok::<_, alloy_dyn_abi::Error>(())
+    /// ```
+    #[inline]
+    pub fn parse_with_max_depth(s: &str, max_depth: usize) -> Result<Self> {
+        TypeSpecifier::parse_with_max_depth(s, max_depth)
+            .map_err(Error::TypeParser)
+            .and_then(|t| t.resolve())
+    }
+
+    /// Parses a Solidity type name string into a [`DynSolType`], calling `resolver` to resolve
+    /// any names that are not elementary types, such as struct names.
+    ///
+    /// Resolved types are used as-is, so the resolver is responsible for returning types that
+    /// are themselves fully resolved. Since a [`DynSolType`] is a finite tree, this also means
+    /// that recursive structs, which are not ABI-encodable, cannot be produced.
+    ///
+    /// Returns [`Error::MissingType`] if `resolver` returns `None` for a name.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use alloy_dyn_abi::{DynSolType, Error};
+    /// let resolver = |name: &str| match name {
+    ///     "Order" => Some(DynSolType::Tuple(vec![DynSolType::Address, DynSolType::Uint(256)])),
+    ///     _ => None,
+    /// };
+    ///
+    /// let ty = DynSolType::parse_with_resolver("(Order[],bool)", &resolver)?;
+    /// assert_eq!(ty.sol_type_name(), "((address,uint256)[],bool)");
+    ///
+    /// let err = DynSolType::parse_with_resolver("Fill", &resolver).unwrap_err();
+    /// assert_eq!(err, Error::MissingType("Fill".into()));
+    /// # Ok::<_, alloy_dyn_abi::Error>(())
+    /// ```
+    pub fn parse_with_resolver<F>(s: &str, resolver: &F) -> Result<Self>
+    where
+        F: Fn(&str) -> Option<Self>,
+    {
+        TypeSpecifier::parse(s)
+            .map_err(Error::TypeParser)
+            .and_then(|t| crate::specifier::resolve_with(&t, resolver))
+    }
+
+    /// Converts a JSON ABI parameter into a [`DynSolType`].
+    ///
+    /// Tuple parameters have a `tuple` type, optionally followed by array
+    /// sizes such as `tuple[]`, `tuple[2]` or `tuple[][2]`, and are resolved
+    /// from their `components`, recursively. All other parameters are parsed
+    /// from their type string.
+    ///
+    /// With the `eip712` feature, tuples whose internal type is a struct are
+    /// resolved to `DynSolType::CustomStruct`.
+    ///
+    /// This is equivalent to resolving the parameter with [`Specifier`], but
+    /// errors are wrapped in [`Error::InvalidParam`] to include the
+    /// parameter's name, type string and internal type. The internal type
+    /// never affects the layout of the resolved type.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use alloy_dyn_abi::DynSolType;
+    /// use alloy_json_abi::Param;
+    ///
+    /// let param: Param = serde_json::from_str(
+    ///     r#"{
+    ///         "name": "orders",
+    ///         "type": "tuple[][2]",
+    ///         "components": [
+    ///             { "name": "maker", "type": "address" },
+    ///             { "name": "amounts", "type": "uint256[]" }
+    ///         ]
+    ///     }"#,
+    /// )?;
+    /// let ty = DynSolType::from_json_abi_param(&param)?;
+    /// assert_eq!(ty.sol_type_name(), "(address,uint256[])[][2]");
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    #[inline]
+    pub fn from_json_abi_param(param: &Param) -> Result<Self> {
+        Self::try_from(param)
+    }
+
     /// Calculate the nesting depth of this type. Simple types have a nesting
     /// depth of 0, while all other types have a nesting depth of at least 1.
     pub fn nesting_depth(&self) -> usize {
@@ -171,6 +292,67 @@ impl DynSolType {
         }
     }
 
+    /// Calls `f` on this type and all of its nested types, parents before
+    /// children.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use alloy_dyn_abi::DynSolType;
+    ///
+    /// let ty: DynSolType = "(uint256,bool[2])".parse()?;
+    /// let mut types = Vec::new();
+    /// ty.visit(|ty| types.push(ty.to_string()));
+    /// assert_eq!(types, ["(uint256,bool[2])", "uint256", "bool[2]", "bool"]);
+    /// # Ok::<_, alloy_dyn_abi::Error>(())
+    /// ```
+    pub fn visit(&self, mut f: impl FnMut(&Self)) {
+        self.visit_inner(&mut f)
+    }
+
+    fn visit_inner<F: FnMut(&Self)>(&self, f: &mut F) {
+        f(self);
+        match self {
+            Self::Array(inner) | Self::FixedArray(inner, _) => inner.visit_inner(f),
+            as_tuple!(Self tuple) => tuple.iter().for_each(|ty| ty.visit_inner(f)),
+            _ => {}
+        }
+    }
+
+    /// Transforms this type bottom-up: nested types are mapped first, then `f`
+    /// is called on the type rebuilt from the mapped children.
+    ///
+    /// Array sizes, and custom struct names and property names, are preserved.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use alloy_dyn_abi::DynSolType;
+    ///
+    /// let ty: DynSolType = "(uint256,(uint256[],bool)[3])".parse()?;
+    /// let mapped = ty.map(&|ty| match ty {
+    ///     DynSolType::Uint(256) => DynSolType::Uint(128),
+    ///     ty => ty.clone(),
+    /// });
+    /// assert_eq!(mapped.to_string(), "(uint128,(uint128[],bool)[3])");
+    /// # Ok::<_, alloy_dyn_abi::Error>(())
+    /// ```
+    pub fn map<F: Fn(&Self) -> Self>(&self, f: &F) -> Self {
+        let ty = match self {
+            Self::Array(inner) => Self::Array(Box::new(inner.map(f))),
+            Self::FixedArray(inner, size) => Self::FixedArray(Box::new(inner.map(f)), *size),
+            Self::Tuple(tuple) => Self::Tuple(tuple.iter().map(|ty| ty.map(f)).collect()),
+            #[cfg(feature = "eip712")]
+            Self::CustomStruct { name, prop_names, tuple } => Self::CustomStruct {
+                name: name.clone(),
+                prop_names: prop_names.clone(),
+                tuple: tuple.iter().map(|ty| ty.map(f)).collect(),
+            },
+            ty => ty.clone(),
+        };
+        f(&ty)
+    }
+
     /// Fallible cast to the contents of a variant.
     #[inline]
     pub fn as_tuple(&self) -> Option<&[Self]> {
@@ -261,6 +443,64 @@ impl DynSolType {
         }
     }
 
+    /// Returns the zero value of this type.
+    ///
+    /// Numbers, addresses, function pointers and fixed bytes are zero, booleans are
+    /// `false`, `bytes`, `string` and dynamic arrays are empty, and fixed arrays and
+    /// tuples are recursively filled with the zero values of their elements.
+    ///
+    /// The returned value always [`matches`](Self::matches) this type.
+    ///
+    /// # Errors
+    ///
+    /// Fixed arrays are allocated up front, so this returns an error instead of
+    /// panicking or aborting if the total size of the value cannot be allocated,
+    /// e.g. for `uint256[9999999999]`. Note that the amount of memory allocated is
+    /// still proportional to the number of elements of the type.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use alloy_dyn_abi::{DynSolType, DynSolValue};
+    /// use alloy_primitives::{Address, U256};
+    ///
+    /// let ty: DynSolType = "(uint256,address[],bool[2])".parse()?;
+    /// assert_eq!(
+    ///     ty.default_value()?,
+    ///     DynSolValue::Tuple(vec![
+    ///         DynSolValue::Uint(U256::ZERO, 256),
+    ///         DynSolValue::Array(vec![]),
+    ///         DynSolValue::FixedArray(vec![false.into(), false.into()]),
+    ///     ])
+    /// );
+    /// # Ok::<_, alloy_dyn_abi::Error>(())
+    /// ```
+    pub fn default_value(&self) -> Result<DynSolValue> {
+        Ok(match self {
+            Self::Bool => DynSolValue::Bool(false),
+            Self::Int(size) => DynSolValue::Int(I256::ZERO, *size),
+            Self::Uint(size) => DynSolValue::Uint(U256::ZERO, *size),
+            Self::FixedBytes(size) => DynSolValue::FixedBytes(Word::ZERO, *size),
+            Self::Address => DynSolValue::Address(Address::ZERO),
+            Self::Function => DynSolValue::Function(Function::ZERO),
+            Self::Bytes => DynSolValue::Bytes(Vec::new()),
+            Self::String => DynSolValue::String(String::new()),
+            Self::Array(_) => DynSolValue::Array(Vec::new()),
+            Self::FixedArray(ty, size) => {
+                DynSolValue::FixedArray(try_vec![ty.default_value()?; *size]?)
+            }
+            Self::Tuple(tuple) => {
+                DynSolValue::Tuple(tuple.iter().map(Self::default_value).collect::<Result<_>>()?)
+            }
+            #[cfg(feature = "eip712")]
+            Self::CustomStruct { name, prop_names, tuple } => DynSolValue::CustomStruct {
+                name: name.clone(),
+                prop_names: prop_names.clone(),
+                tuple: tuple.iter().map(Self::default_value).collect::<Result<_>>()?,
+            },
+        })
+    }
+
     /// Dynamic detokenization.
     // This should not fail when using a token created by `Self::empty_dyn_token`.
     #[allow(clippy::unnecessary_to_owned)] // https://github.com/rust-lang/rust-clippy/issues/8148
@@ -573,11 +813,12 @@ impl DynSolType {
             Self::Bytes |
             Self::String |
             Self::Array(_) => 1,
-            // fixed-seq types are the sum of their components
-            Self::FixedArray(v, size) => size * v.minimum_words(),
-            Self::Tuple(tuple) => tuple.iter().map(|ty| ty.minimum_words()).sum(),
+            // fixed-seq types are the sum of their components. These saturate
+            // as user-provided sizes may be arbitrarily large
+            Self::FixedArray(v, size) => size.saturating_mul(v.minimum_words()),
+            Self::Tuple(tuple) => tuple.iter().fold(0, |acc, ty| acc.saturating_add(ty.minimum_words())),
             #[cfg(feature = "eip712")]
-            Self::CustomStruct { tuple, ..} => tuple.iter().map(|ty| ty.minimum_words()).sum(),
+            Self::CustomStruct { tuple, ..} => tuple.iter().fold(0, |acc, ty| acc.saturating_add(ty.minimum_words())),
         }
     }
 
@@ -595,12 +836,19 @@ impl DynSolType {
             return Ok(self.zero_sized_value().expect("checked"));
         }
 
+        // Adds the type name to decoding errors
+        let with_type = |error| match error {
+            Error::SolTypes(e) => Error::SolTypes(e.with_decode_type(self.sol_type_name())),
+            e => e,
+        };
+
         if decoder.remaining_words() < self.minimum_words() {
-            return Err(Error::SolTypes(alloy_sol_types::Error::Overrun));
+            let error = decoder.error_at(decoder.offset(), alloy_sol_types::Error::Overrun);
+            return Err(with_type(error.into()));
         }
 
         let mut token = self.empty_dyn_token()?;
-        f(&mut token, decoder)?;
+        f(&mut token, decoder).map_err(with_type)?;
         let value = self.detokenize(token).expect("invalid empty_dyn_token");
         debug_assert!(
             self.matches(&value),
@@ -654,6 +902,16 @@ mod tests {
     use super::*;
     use alloy_primitives::{hex, Address};
 
+    /// The error returned when the input overruns while decoding `ty` at `offset`.
+    fn overrun(offset: usize, ty: &str) -> Result<DynSolValue> {
+        Err(alloy_sol_types::Error::Decode {
+            offset,
+            ty: Some(ty.to_string().into()),
+            error: Box::new(alloy_sol_types::Error::Overrun),
+        }
+        .into())
+    }
+
     #[test]
     fn dynamically_encodes() {
         let word1 =
@@ -725,6 +983,7 @@ re-enc: {re_enc}
 
     encoder_tests! {
         address("address", "0000000000000000000000001111111111111111111111111111111111111111"),
+        function("function", "1111111111111111111111111111111111111111222222220000000000000000"),
 
         dynamic_array_of_addresses("address[]", "
             0000000000000000000000000000000000000000000000000000000000000020
@@ -1030,6 +1289,80 @@ re-enc: {re_enc}
         assert_eq!(value, Ok(DynSolValue::Array(vec![])));
     }
 
+    #[test]
+    fn map_identity() {
+        for s in ["uint8", "bytes32[]", "(address,(bool,string)[2][])[][3]", "()", "(string[1])"] {
+            let ty: DynSolType = s.parse().unwrap();
+            assert_eq!(ty.map(&Clone::clone), ty, "{s}");
+        }
+    }
+
+    #[test]
+    fn map_substitutes_nested() {
+        let ty: DynSolType = "(uint256,(uint256[],bytes)[2],uint256[][3],uint8)".parse().unwrap();
+        let mapped = ty.map(&|ty| match ty {
+            DynSolType::Uint(256) => DynSolType::Address,
+            ty => ty.clone(),
+        });
+        assert_eq!(mapped.to_string(), "(address,(address[],bytes)[2],address[][3],uint8)");
+
+        // `f` sees the already mapped children
+        let flattened = ty.map(&|ty| match ty {
+            DynSolType::Array(inner) if **inner == DynSolType::Address => DynSolType::Bytes,
+            DynSolType::Uint(256) => DynSolType::Address,
+            ty => ty.clone(),
+        });
+        assert_eq!(flattened.to_string(), "(address,(bytes,bytes)[2],bytes[3],uint8)");
+    }
+
+    #[test]
+    #[cfg(feature = "eip712")]
+    fn map_custom_struct() {
+        let ty = DynSolType::CustomStruct {
+            name: "S".into(),
+            prop_names: vec!["a".into(), "b".into()],
+            tuple: vec![
+                DynSolType::Uint(256),
+                DynSolType::FixedArray(Box::new(DynSolType::Bool), 2),
+            ],
+        };
+        let mapped = ty.map(&|ty| match ty {
+            DynSolType::Bool => DynSolType::Uint(8),
+            ty => ty.clone(),
+        });
+        assert_eq!(
+            mapped,
+            DynSolType::CustomStruct {
+                name: "S".into(),
+                prop_names: vec!["a".into(), "b".into()],
+                tuple: vec![
+                    DynSolType::Uint(256),
+                    DynSolType::FixedArray(Box::new(DynSolType::Uint(8)), 2)
+                ],
+            }
+        );
+    }
+
+    #[test]
+    fn visit_order() {
+        let ty: DynSolType = "(uint8,(bool,string)[],address[2])".parse().unwrap();
+        let mut types = Vec::new();
+        ty.visit(|ty| types.push(ty.to_string()));
+        assert_eq!(
+            types,
+            [
+                "(uint8,(bool,string)[],address[2])",
+                "uint8",
+                "(bool,string)[]",
+                "(bool,string)",
+                "bool",
+                "string",
+                "address[2]",
+                "address",
+            ]
+        );
+    }
+
     #[test]
     #[cfg_attr(miri, ignore = "takes too long")]
     fn recursive_dos() {
@@ -1063,14 +1396,153 @@ re-enc: {re_enc}
         // Used to eat 60 gb of memory.
         let my_type: DynSolType = "uint32[1][]".parse().unwrap();
         let decoded = my_type.abi_decode(&hex::decode(payload).unwrap());
-        assert_eq!(decoded, Err(alloy_sol_types::Error::Overrun.into()))
+        assert_eq!(decoded, overrun(64, "uint32[1][]"))
+    }
+
+    #[test]
+    fn default_value() {
+        let types = [
+            "bool",
+            "int8",
+            "uint256",
+            "bytes7",
+            "bytes32",
+            "address",
+            "function",
+            "bytes",
+            "string",
+            "uint256[]",
+            "string[3]",
+            "()",
+            "(uint8,(bytes,address[2]),string)[]",
+            "((bool,(int24,bytes1)[2]),string[][1])",
+        ];
+        for s in types {
+            let ty: DynSolType = s.parse().unwrap();
+            let value = ty.default_value().unwrap();
+            assert!(ty.matches(&value), "{s}: {value:?}");
+
+            let encoded = value.abi_encode_params();
+            assert_eq!(ty.abi_decode_params(&encoded), Ok(value), "{s}");
+        }
+
+        let ty: DynSolType = "(bytes4,string)".parse().unwrap();
+        assert_eq!(
+            ty.default_value(),
+            Ok(DynSolValue::Tuple(vec![
+                DynSolValue::FixedBytes(Word::ZERO, 4),
+                DynSolValue::String(String::new())
+            ]))
+        );
+    }
+
+    #[test]
+    fn default_value_too_large() {
+        for s in [format!("uint256[{}]", usize::MAX), format!("(bool,string[{}][2])", usize::MAX)] {
+            let ty: DynSolType = s.parse().unwrap();
+            assert!(ty.default_value().is_err(), "{s}");
+        }
+    }
+
+    #[test]
+    fn parse_deeply_nested() {
+        let nested = |n: usize| format!("{}uint256{}", "(".repeat(n), ",)".repeat(n));
+
+        let ty = DynSolType::parse(&nested(64)).unwrap();
+        assert_eq!(ty.nesting_depth(), 64);
+        let e = DynSolType::parse(&nested(1000)).unwrap_err();
+        assert!(e.to_string().contains("nesting depth exceeds the maximum of 64"), "{e}");
+        let e = DynSolType::parse_with_resolver(&nested(1000), &|_| None).unwrap_err();
+        assert!(e.to_string().contains("nesting depth exceeds the maximum of 64"), "{e}");
+
+        assert!(DynSolType::parse_with_max_depth(&nested(100), 100).is_ok());
+        assert!(DynSolType::parse_with_max_depth(&nested(100), 99).is_err());
     }
 
     #[test]
     fn fixed_array_dos() {
         let t = "uint32[9999999999]".parse::<DynSolType>().unwrap();
         let decoded = t.abi_decode(&[]);
-        assert_eq!(decoded, Err(alloy_sol_types::Error::Overrun.into()))
+        assert_eq!(decoded, overrun(0, "uint32[9999999999]"))
+    }
+
+    #[test]
+    fn fixed_array_size_overflow() {
+        let s = format!("uint256[{}][2]", usize::MAX);
+        let t = s.parse::<DynSolType>().unwrap();
+        assert_eq!(t.minimum_words(), usize::MAX);
+        let decoded = t.abi_decode(&[0; 64]);
+        assert_eq!(decoded, overrun(0, &s));
+    }
+
+    #[test]
+    fn malicious_lengths_and_offsets() {
+        let word = |b: u8| [b; 32];
+        let max = word(0xff);
+        let mut offset_32 = [0u8; 32];
+        offset_32[31] = 0x20;
+
+        // array length of 2^256-1, for element types of various minimum sizes
+        for s in ["uint256[]", "uint256[2][]", "(uint256,address,bytes)[]", "string[]"] {
+            let ty: DynSolType = s.parse().unwrap();
+            let data = [offset_32, max].concat();
+            assert_eq!(ty.abi_decode(&data), overrun(64, s), "{s}");
+            let data = [offset_32, max, word(0)].concat();
+            assert_eq!(ty.abi_decode(&data), overrun(64, s), "{s}");
+        }
+
+        // `bytes` and `string` lengths of 2^256-1
+        for s in ["bytes", "string"] {
+            let ty: DynSolType = s.parse().unwrap();
+            let data = [offset_32, max].concat();
+            assert_eq!(ty.abi_decode(&data), overrun(64, s), "{s}");
+        }
+
+        // offsets pointing far past the end of the buffer
+        for s in ["uint256[]", "bytes", "(uint256,bytes)", "uint256[][2]"] {
+            let ty: DynSolType = s.parse().unwrap();
+            let data = [max, word(0)].concat();
+            let err = ty.abi_decode(&data).unwrap_err();
+            let Error::SolTypes(err) = err else { panic!("{s}: {err:?}") };
+            assert_eq!(*err.root(), alloy_sol_types::Error::Overrun, "{s}");
+        }
+
+        // pseudo-random garbage must never panic or allocate unboundedly
+        let types: Vec<DynSolType> = [
+            "uint256[]",
+            "bytes[]",
+            "(uint8,bytes,address[])[]",
+            "string[2][]",
+            "((bool,bytes)[],uint256[3])",
+            "uint256[][][]",
+        ]
+        .iter()
+        .map(|s| s.parse().unwrap())
+        .collect();
+        let mut state = 0x2545_f491_4f6c_dd1d_u64;
+        let mut next = move || {
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+            state
+        };
+        for _ in 0..1000 {
+            let mut data = vec![0u8; 32 * (1 + next() as usize % 8)];
+            for chunk in data.chunks_mut(32) {
+                // mix small, plausible words with fully random ones
+                match next() % 4 {
+                    0 => chunk[31] = (next() % 8 * 32) as u8,
+                    1 => chunk[31] = next() as u8,
+                    2 => chunk.iter_mut().for_each(|b| *b = next() as u8),
+                    _ => chunk.fill(0xff),
+                }
+            }
+            for ty in &types {
+                if let Ok(value) = ty.abi_decode(&data) {
+                    assert!(ty.matches(&value));
+                }
+            }
+        }
     }
 
     macro_rules! packed_tests {
@@ -1103,6 +1575,7 @@ re-enc: {re_enc}
         }
 
         let packed = value.abi_encode_packed();
+        assert_eq!(value.abi_packed_encoded_size(), packed.len(), "packed size mismatch");
         assert!(
             packed == expected,
             "
@@ -1117,6 +1590,8 @@ expected: {expected}",
 
     packed_tests! {
         address("address", "1111111111111111111111111111111111111111", "1111111111111111111111111111111111111111"),
+        function("function", "111111111111111111111111111111111111111122222222", "111111111111111111111111111111111111111122222222"),
+        function_tuple("(function,bool)", "(111111111111111111111111111111111111111122222222, true)", "11111111111111111111111111111111111111112222222201"),
 
         bool_false("bool", "false", "00"),
         bool_true("bool", "true", "01"),
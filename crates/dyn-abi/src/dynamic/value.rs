@@ -1,8 +1,14 @@
 use super::ty::as_tuple;
-use crate::{DynSolType, DynToken, Word};
-use alloc::{borrow::Cow, boxed::Box, string::String, vec::Vec};
-use alloy_primitives::{Address, Function, I256, U256};
+use crate::{DynSolType, DynToken, Error, Result, Word};
+use alloc::{
+    borrow::Cow,
+    boxed::Box,
+    string::{String, ToString},
+    vec::Vec,
+};
+use alloy_primitives::{hex, ruint::UintTryFrom, Address, Function, I256, U256};
 use alloy_sol_types::{abi::Encoder, utils::words_for_len};
+use core::fmt;
 
 #[cfg(feature = "eip712")]
 macro_rules! as_fixed_seq {
@@ -57,7 +63,18 @@ macro_rules! as_fixed_seq {
 /// );
 /// # Ok::<(), alloy_dyn_abi::Error>(())
 /// ```
+///
+/// # Serde
+///
+/// With the `serde` feature, both [`DynSolValue`] and [`DynSolType`] implement
+/// `Serialize` and `Deserialize` as externally tagged enums. In binary formats
+/// such as bincode or CBOR, words and byte strings are serialized as raw bytes.
+///
+/// Values do not carry their type: an empty array of `uint256` and an empty
+/// array of `string` are both `Array(vec![])`. Serialize the [`DynSolType`]
+/// alongside the value if it has to be recovered.
 #[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum DynSolValue {
     /// A boolean.
     Bool(bool),
@@ -73,6 +90,7 @@ pub enum DynSolValue {
     Function(Function),
 
     /// A dynamic-length byte array.
+    #[cfg_attr(feature = "serde", serde(with = "serde_bytes"))]
     Bytes(Vec<u8>),
     /// A string.
     String(String),
@@ -189,7 +207,220 @@ impl From<U256> for DynSolValue {
     }
 }
 
+macro_rules! impl_try_from_value {
+    ($($t:ty => $expected:literal, $variant:ident($($value:pat),+) => $e:expr;)+) => {$(
+        impl TryFrom<DynSolValue> for $t {
+            type Error = Error;
+
+            #[doc = concat!("Extracts the value if it is a `", $expected, "`.")]
+            #[inline]
+            fn try_from(value: DynSolValue) -> Result<Self> {
+                match value {
+                    DynSolValue::$variant($($value),+) => Ok($e),
+                    value => Err(Error::TypeMismatch {
+                        expected: $expected.into(),
+                        actual: value.sol_type_name().unwrap_or_else(|| "<none>".into()).into_owned(),
+                    }),
+                }
+            }
+        }
+    )+};
+}
+
+impl_try_from_value! {
+    U256 => "uint", Uint(value, _) => value;
+    Address => "address", Address(value) => value;
+    String => "string", String(value) => value;
+    Vec<u8> => "bytes", Bytes(value) => value;
+}
+
+/// Formats the value as a Solidity-like literal.
+///
+/// Addresses are checksummed, byte arrays and function pointers are `0x`-prefixed hex strings,
+/// strings are quoted, arrays are delimited by `[]` and tuples by `()`.
+///
+/// Integers are formatted in decimal; use the alternate flag (`{:#}`) to format them as
+/// `0x`-prefixed hex instead.
+///
+/// # Examples
+///
+/// ```
+/// use alloy_dyn_abi::DynSolValue;
+///
+/// let value = DynSolValue::Tuple(vec![
+///     DynSolValue::from(255u8),
+///     DynSolValue::Array(vec![true.into(), false.into()]),
+///     DynSolValue::String("foo".into()),
+/// ]);
+/// assert_eq!(value.to_string(), r#"(255, [true, false], "foo")"#);
+/// assert_eq!(format!("{value:#}"), r#"(0xff, [true, false], "foo")"#);
+/// ```
+impl fmt::Display for DynSolValue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Bool(b) => write!(f, "{b}"),
+            Self::Int(i, _) if f.alternate() => {
+                let (sign, abs) = i.into_sign_and_abs();
+                write!(f, "{sign}{abs:#x}")
+            }
+            Self::Int(i, _) => write!(f, "{i}"),
+            Self::Uint(u, _) if f.alternate() => write!(f, "{u:#x}"),
+            Self::Uint(u, _) => write!(f, "{u}"),
+            Self::FixedBytes(word, size) => {
+                f.write_str(&hex::encode_prefixed(&word[..(*size).min(32)]))
+            }
+            Self::Address(address) => f.write_str(address.to_checksum_buffer(None).as_str()),
+            Self::Function(function) => f.write_str(&hex::encode_prefixed(function)),
+            Self::Bytes(bytes) => f.write_str(&hex::encode_prefixed(bytes)),
+            Self::String(s) => write!(f, "{s:?}"),
+            Self::Array(values) | Self::FixedArray(values) => {
+                f.write_str("[")?;
+                fmt_list(values, f)?;
+                f.write_str("]")
+            }
+            Self::Tuple(values) => {
+                f.write_str("(")?;
+                fmt_list(values, f)?;
+                if values.len() == 1 {
+                    f.write_str(",")?;
+                }
+                f.write_str(")")
+            }
+            #[cfg(feature = "eip712")]
+            Self::CustomStruct { name, prop_names, tuple } => {
+                write!(f, "{name}({{")?;
+                for (i, (prop_name, value)) in prop_names.iter().zip(tuple).enumerate() {
+                    if i > 0 {
+                        f.write_str(", ")?;
+                    }
+                    write!(f, "{prop_name}: ")?;
+                    fmt::Display::fmt(value, f)?;
+                }
+                f.write_str("})")
+            }
+        }
+    }
+}
+
+/// Formats a comma-separated list of values, forwarding the formatter's flags.
+fn fmt_list(values: &[DynSolValue], f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    for (i, value) in values.iter().enumerate() {
+        if i > 0 {
+            f.write_str(", ")?;
+        }
+        fmt::Display::fmt(value, f)?;
+    }
+    Ok(())
+}
+
 impl DynSolValue {
+    /// Creates a new `uint<size>` value.
+    ///
+    /// See [`checked_uint`](Self::checked_uint) for a non-panicking version.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `size` is not a multiple of 8 in `8..=256`, or if `value` does not fit in
+    /// `size` bits.
+    ///
+    /// # Examples
+    ///
+    /// Building the arguments of a `transfer(address,uint256)` call:
+    ///
+    /// ```
+    /// use alloy_dyn_abi::DynSolValue;
+    /// use alloy_primitives::{address, U256};
+    ///
+    /// let args = DynSolValue::tuple([
+    ///     DynSolValue::address(address!("d8dA6BF26964aF9D7eEd9e03E53415D37aA96045")),
+    ///     DynSolValue::uint(5, 256),
+    /// ]);
+    /// assert_eq!(
+    ///     args,
+    ///     DynSolValue::Tuple(vec![
+    ///         DynSolValue::Address(address!("d8dA6BF26964aF9D7eEd9e03E53415D37aA96045")),
+    ///         DynSolValue::Uint(U256::from(5), 256),
+    ///     ]),
+    /// );
+    /// let calldata = args.abi_encode_params();
+    /// assert_eq!(calldata.len(), 64);
+    /// ```
+    #[inline]
+    #[track_caller]
+    pub fn uint<T>(value: T, size: usize) -> Self
+    where
+        U256: UintTryFrom<T>,
+    {
+        match Self::checked_uint(value, size) {
+            Ok(value) => value,
+            Err(e) => panic!("{e}"),
+        }
+    }
+
+    /// Creates a new `uint<size>` value, checking that `size` is a valid integer size and that
+    /// `value` fits in it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use alloy_dyn_abi::DynSolValue;
+    /// use alloy_primitives::U256;
+    ///
+    /// assert_eq!(DynSolValue::checked_uint(255, 8), Ok(DynSolValue::Uint(U256::from(255), 8)));
+    /// assert!(DynSolValue::checked_uint(256, 8).is_err());
+    /// assert!(DynSolValue::checked_uint(1, 7).is_err());
+    /// assert!(DynSolValue::checked_uint(1, 264).is_err());
+    /// assert!(DynSolValue::checked_uint(-1, 256).is_err());
+    /// ```
+    #[inline]
+    pub fn checked_uint<T>(value: T, size: usize) -> Result<Self>
+    where
+        U256: UintTryFrom<T>,
+    {
+        if size == 0 || size > 256 || size % 8 != 0 {
+            return Err(parser::Error::invalid_size(format_args!("uint{size}")).into());
+        }
+        let value = U256::uint_try_from(value).map_err(|e| Error::custom(e.to_string()))?;
+        let value = Self::Uint(value, size);
+        value.check_int_ranges()?;
+        Ok(value)
+    }
+
+    /// Creates a new `address` value.
+    #[inline]
+    pub const fn address(address: Address) -> Self {
+        Self::Address(address)
+    }
+
+    /// Creates a new `string` value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use alloy_dyn_abi::DynSolValue;
+    ///
+    /// assert_eq!(DynSolValue::string("hi"), DynSolValue::String("hi".to_string()));
+    /// ```
+    #[inline]
+    pub fn string(s: impl Into<String>) -> Self {
+        Self::String(s.into())
+    }
+
+    /// Creates a new tuple value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use alloy_dyn_abi::DynSolValue;
+    ///
+    /// let value = DynSolValue::tuple([DynSolValue::string("hi"), true.into()]);
+    /// assert_eq!(value.to_string(), r#"("hi", true)"#);
+    /// ```
+    #[inline]
+    pub fn tuple(values: impl Into<Vec<Self>>) -> Self {
+        Self::Tuple(values.into())
+    }
+
     /// The Solidity type. This returns the Solidity type corresponding to this
     /// value, if it is known. A type will not be known if the value contains
     /// an empty sequence, e.g. `T[0]`.
@@ -548,6 +779,98 @@ impl DynSolValue {
         ty.matches(self)
     }
 
+    /// Compares two values for equality, ignoring the declared sizes of
+    /// integers and fixed-size byte arrays.
+    ///
+    /// The derived [`PartialEq`] implementation is strict: `Uint(5, 8)` and
+    /// `Uint(5, 256)` are different values. This is a looser comparison meant
+    /// for user-facing checks:
+    /// - `Int`s are equal if their values are equal, regardless of size, and likewise for `Uint`s;
+    /// - an `Int` is never equal to a `Uint`, even if they have the same magnitude;
+    /// - `FixedBytes` are equal if their words are equal, regardless of size;
+    /// - arrays, fixed arrays, tuples and custom structs are compared element-wise with this
+    ///   method;
+    /// - all other values are compared with [`PartialEq`].
+    ///
+    /// Values of different variants are never equal.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use alloy_dyn_abi::DynSolValue;
+    /// use alloy_primitives::{I256, U256};
+    ///
+    /// let a = DynSolValue::Uint(U256::from(5), 8);
+    /// let b = DynSolValue::Uint(U256::from(5), 256);
+    /// assert_ne!(a, b);
+    /// assert!(a.eq_loose(&b));
+    ///
+    /// assert!(!a.eq_loose(&DynSolValue::Int(I256::try_from(5).unwrap(), 8)));
+    /// ```
+    pub fn eq_loose(&self, other: &Self) -> bool {
+        fn eq_seq(a: &[DynSolValue], b: &[DynSolValue]) -> bool {
+            a.len() == b.len() && a.iter().zip(b).all(|(a, b)| a.eq_loose(b))
+        }
+
+        match (self, other) {
+            (Self::Int(a, _), Self::Int(b, _)) => a == b,
+            (Self::Uint(a, _), Self::Uint(b, _)) => a == b,
+            (Self::FixedBytes(a, _), Self::FixedBytes(b, _)) => a == b,
+            (Self::Array(a), Self::Array(b))
+            | (Self::FixedArray(a), Self::FixedArray(b))
+            | (Self::Tuple(a), Self::Tuple(b)) => eq_seq(a, b),
+            #[cfg(feature = "eip712")]
+            (
+                Self::CustomStruct { name: a_name, prop_names: a_props, tuple: a },
+                Self::CustomStruct { name: b_name, prop_names: b_props, tuple: b },
+            ) => a_name == b_name && a_props == b_props && eq_seq(a, b),
+            (a, b) => a == b,
+        }
+    }
+
+    /// Checks that every integer in this value fits in its declared bit size.
+    ///
+    /// Encoding does not truncate integers, so an out-of-range value such as
+    /// `Uint(U256::MAX, 8)` would produce a word that is invalid for its type. This is checked
+    /// by the type-checked encoding functions, like
+    /// [`JsonAbiExt::abi_encode_input`](crate::JsonAbiExt::abi_encode_input) and
+    /// [`DynSolCall::abi_encode_input`](crate::DynSolCall::abi_encode_input), but not by
+    /// [`abi_encode`](Self::abi_encode) and friends.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use alloy_dyn_abi::DynSolValue;
+    /// use alloy_primitives::{I256, U256};
+    ///
+    /// assert!(DynSolValue::Uint(U256::from(255), 8).check_int_ranges().is_ok());
+    /// assert!(DynSolValue::Uint(U256::from(256), 8).check_int_ranges().is_err());
+    /// assert!(DynSolValue::Int(I256::MINUS_ONE, 8).check_int_ranges().is_ok());
+    /// ```
+    pub fn check_int_ranges(&self) -> Result<()> {
+        let fits = match self {
+            // The value fits if all the bits above `size` are unset.
+            &Self::Uint(n, size) => size >= 256 || n >> size == U256::ZERO,
+            // The value fits if all the bits above `size - 1` are equal to the sign bit.
+            &Self::Int(n, size) => {
+                let raw = if n.is_negative() { !n.into_raw() } else { n.into_raw() };
+                size >= 256 || (size > 0 && raw >> (size - 1) == U256::ZERO)
+            }
+            Self::Array(values) | as_fixed_seq!(values) => {
+                return values.iter().try_for_each(Self::check_int_ranges)
+            }
+            _ => true,
+        };
+        if fits {
+            Ok(())
+        } else {
+            Err(Error::IntOutOfRange {
+                ty: self.sol_type_name().unwrap_or_default().into_owned(),
+                value: self.to_string(),
+            })
+        }
+    }
+
     /// Returns the number of words this type uses in the head of the ABI blob.
     #[inline]
     pub(crate) fn head_words(&self) -> usize {
@@ -725,7 +1048,8 @@ impl DynSolValue {
     /// See [`abi_encode_packed`](Self::abi_encode_packed) for more details.
     pub fn abi_packed_encoded_size(&self) -> usize {
         match self {
-            Self::Address(_) | Self::Function(_) => 20,
+            Self::Address(_) => 20,
+            Self::Function(_) => 24,
             Self::Bool(_) => 1,
             Self::String(s) => s.len(),
             Self::Bytes(b) => b.len(),
@@ -817,3 +1141,318 @@ impl DynSolValue {
         self.as_fixed_seq().map(Self::encode_seq)
     }
 }
+
+/// ABI-encodes the given values as a function's parameter sequence.
+///
+/// This is the dynamic equivalent of
+/// [`SolValue::abi_encode_params`](alloy_sol_types::SolValue::abi_encode_params)
+/// on a tuple of the values: each element of the slice is one parameter. Unlike
+/// [`DynSolValue::abi_encode_params`], a single tuple is not flattened, but encoded as a
+/// single parameter, with its offset if it is dynamic.
+///
+/// An empty slice encodes to empty bytes.
+///
+/// # Examples
+///
+/// ```
+/// use alloy_dyn_abi::{abi_encode_params, DynSolValue};
+/// use alloy_primitives::{hex, Address, U256};
+///
+/// // Encoding for `function foo(address, uint256)`
+/// let encoded = abi_encode_params(&[Address::ZERO.into(), U256::from(1).into()]);
+/// assert_eq!(encoded.len(), 64);
+///
+/// // Encoding for `function bar((uint256,string))`
+/// let arg = DynSolValue::Tuple(vec![U256::from(1).into(), DynSolValue::String("a".into())]);
+/// assert_eq!(
+///     abi_encode_params(&[arg]),
+///     hex!(
+///         "0000000000000000000000000000000000000000000000000000000000000020" // offset
+///         "0000000000000000000000000000000000000000000000000000000000000001" // .0
+///         "0000000000000000000000000000000000000000000000000000000000000040" // .1 offset
+///         "0000000000000000000000000000000000000000000000000000000000000001" // .1 length
+///         "6100000000000000000000000000000000000000000000000000000000000000" // .1 data
+///     )
+/// );
+///
+/// // Encoding for `function baz()`
+/// assert!(abi_encode_params(&[]).is_empty());
+/// ```
+#[inline]
+pub fn abi_encode_params(values: &[DynSolValue]) -> Vec<u8> {
+    DynSolValue::encode_seq(values)
+}
+
+/// Serializes `Vec<u8>` like [`alloy_primitives::Bytes`]: as a hex string in
+/// human-readable formats, and as raw bytes otherwise.
+#[cfg(feature = "serde")]
+mod serde_bytes {
+    use alloc::vec::Vec;
+    use alloy_primitives::hex;
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    pub(super) fn serialize<S: Serializer>(bytes: &[u8], serializer: S) -> Result<S::Ok, S::Error> {
+        if serializer.is_human_readable() {
+            hex::serialize(bytes, serializer)
+        } else {
+            serializer.serialize_bytes(bytes)
+        }
+    }
+
+    pub(super) fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Vec<u8>, D::Error> {
+        alloy_primitives::Bytes::deserialize(deserializer).map(Into::into)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_primitives::{address, b256};
+
+    #[test]
+    fn display() {
+        let value = DynSolValue::Tuple(vec![
+            DynSolValue::Address(address!("5aaeb6053f3e94c9b9a09f33669435e7ef1beaed")),
+            DynSolValue::Int(I256::try_from(-16).unwrap(), 8),
+            DynSolValue::Array(vec![
+                DynSolValue::Tuple(vec![
+                    DynSolValue::Uint(U256::from(1337), 256),
+                    DynSolValue::FixedBytes(
+                        b256!("1234000000000000000000000000000000000000000000000000000000000000"),
+                        2,
+                    ),
+                ]),
+                DynSolValue::Tuple(vec![DynSolValue::Bytes(vec![0xde, 0xad])]),
+            ]),
+            DynSolValue::FixedArray(vec![]),
+            DynSolValue::String("a \"quoted\" string".into()),
+        ]);
+        assert_eq!(
+            value.to_string(),
+            r#"(0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed, -16, [(1337, 0x1234), (0xdead,)], [], "a \"quoted\" string")"#
+        );
+        assert_eq!(
+            format!("{value:#}"),
+            r#"(0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed, -0x10, [(0x539, 0x1234), (0xdead,)], [], "a \"quoted\" string")"#
+        );
+    }
+
+    #[test]
+    fn eq_loose() {
+        let uint = |n: u64, size| DynSolValue::Uint(U256::from(n), size);
+        let int = |n: i64, size| DynSolValue::Int(I256::try_from(n).unwrap(), size);
+        let word = b256!("1234000000000000000000000000000000000000000000000000000000000000");
+
+        assert!(uint(5, 8).eq_loose(&uint(5, 256)));
+        assert!(!uint(5, 8).eq_loose(&uint(6, 8)));
+        assert!(int(-5, 8).eq_loose(&int(-5, 256)));
+        assert!(!int(-5, 8).eq_loose(&int(5, 8)));
+        assert!(!uint(5, 8).eq_loose(&int(5, 8)));
+        assert!(!int(5, 256).eq_loose(&uint(5, 256)));
+
+        assert!(DynSolValue::FixedBytes(word, 2).eq_loose(&DynSolValue::FixedBytes(word, 32)));
+        assert!(!DynSolValue::FixedBytes(word, 2).eq_loose(&DynSolValue::FixedBytes(Word::ZERO, 2)));
+        assert!(DynSolValue::Bytes(vec![1, 2]).eq_loose(&DynSolValue::Bytes(vec![1, 2])));
+        assert!(!DynSolValue::Bytes(vec![1, 2]).eq_loose(&DynSolValue::Bytes(vec![1, 2, 0])));
+        assert!(!DynSolValue::Bytes(word[..2].to_vec()).eq_loose(&DynSolValue::FixedBytes(word, 2)));
+
+        assert!(
+            DynSolValue::Array(vec![uint(1, 8)]).eq_loose(&DynSolValue::Array(vec![uint(1, 16)]))
+        );
+        assert!(!DynSolValue::Array(vec![uint(1, 8)]).eq_loose(&DynSolValue::Array(vec![])));
+        assert!(!DynSolValue::Array(vec![uint(1, 8)])
+            .eq_loose(&DynSolValue::FixedArray(vec![uint(1, 8)])));
+        assert!(DynSolValue::FixedArray(vec![int(1, 8)])
+            .eq_loose(&DynSolValue::FixedArray(vec![int(1, 16)])));
+        assert!(DynSolValue::Tuple(vec![DynSolValue::Bool(true), uint(1, 8)])
+            .eq_loose(&DynSolValue::Tuple(vec![DynSolValue::Bool(true), uint(1, 16)])));
+        assert!(!DynSolValue::Tuple(vec![DynSolValue::Bool(true), uint(1, 8)])
+            .eq_loose(&DynSolValue::Tuple(vec![DynSolValue::Bool(false), uint(1, 8)])));
+
+        // everything else is strict
+        assert!(DynSolValue::String("a".into()).eq_loose(&DynSolValue::String("a".into())));
+        assert!(!DynSolValue::String("a".into()).eq_loose(&DynSolValue::Bytes(b"a".to_vec())));
+        assert!(DynSolValue::Address(Address::ZERO).eq_loose(&DynSolValue::Address(Address::ZERO)));
+        assert!(!DynSolValue::Bool(false).eq_loose(&uint(0, 8)));
+    }
+
+    #[test]
+    #[cfg(feature = "eip712")]
+    fn eq_loose_custom_struct() {
+        let s = |size, name: &str| DynSolValue::CustomStruct {
+            name: name.into(),
+            prop_names: vec!["a".into()],
+            tuple: vec![DynSolValue::Uint(U256::from(1), size)],
+        };
+        assert!(s(8, "S").eq_loose(&s(256, "S")));
+        assert!(!s(8, "S").eq_loose(&s(8, "T")));
+        assert!(!s(8, "S").eq_loose(&DynSolValue::Tuple(vec![DynSolValue::Uint(U256::from(1), 8)])));
+    }
+
+    #[test]
+    #[cfg(feature = "eip712")]
+    fn display_custom_struct() {
+        let value = DynSolValue::CustomStruct {
+            name: "Person".into(),
+            prop_names: vec!["name".into(), "age".into()],
+            tuple: vec![DynSolValue::String("Bob".into()), DynSolValue::Uint(U256::from(42), 8)],
+        };
+        assert_eq!(value.to_string(), r#"Person({name: "Bob", age: 42})"#);
+    }
+
+    #[test]
+    fn try_from_value() {
+        let addr = address!("5aaeb6053f3e94c9b9a09f33669435e7ef1beaed");
+        assert_eq!(U256::try_from(DynSolValue::Uint(U256::from(42), 8)), Ok(U256::from(42)));
+        assert_eq!(Address::try_from(DynSolValue::Address(addr)), Ok(addr));
+        assert_eq!(String::try_from(DynSolValue::String("hi".into())), Ok("hi".into()));
+        assert_eq!(Vec::<u8>::try_from(DynSolValue::Bytes(vec![1, 2])), Ok(vec![1, 2]));
+
+        let amount: Result<U256> = DynSolValue::Int(I256::ONE, 256).try_into();
+        assert_eq!(
+            amount,
+            Err(Error::TypeMismatch { expected: "uint".into(), actual: "int256".into() })
+        );
+        assert_eq!(
+            String::try_from(DynSolValue::Bytes(b"hi".to_vec())),
+            Err(Error::TypeMismatch { expected: "string".into(), actual: "bytes".into() })
+        );
+        assert_eq!(
+            Vec::<u8>::try_from(DynSolValue::FixedBytes(Default::default(), 32)),
+            Err(Error::TypeMismatch { expected: "bytes".into(), actual: "bytes32".into() })
+        );
+        assert_eq!(
+            Address::try_from(DynSolValue::Uint(U256::ZERO, 160)),
+            Err(Error::TypeMismatch { expected: "address".into(), actual: "uint160".into() })
+        );
+    }
+
+    #[test]
+    fn check_int_ranges() {
+        let uint = |n: U256, size| DynSolValue::Uint(n, size).check_int_ranges().is_ok();
+        assert!(uint(U256::from(255), 8));
+        assert!(!uint(U256::from(256), 8));
+        assert!(uint(U256::MAX >> 8, 248));
+        assert!(!uint(U256::MAX >> 7, 248));
+        assert!(uint(U256::MAX, 256));
+
+        let int = |n: I256, size| DynSolValue::Int(n, size).check_int_ranges().is_ok();
+        let n = |n: i64| I256::try_from(n).unwrap();
+        assert!(int(n(127), 8));
+        assert!(int(n(-128), 8));
+        assert!(!int(n(128), 8));
+        assert!(!int(n(-129), 8));
+        assert!(int(I256::MAX, 256));
+        assert!(int(I256::MIN, 256));
+        assert!(!int(I256::MIN, 248));
+    }
+
+    #[test]
+    fn encode_params() {
+        use alloy_sol_types::SolValue;
+
+        assert!(abi_encode_params(&[]).is_empty());
+
+        // a single dynamic parameter
+        let s = String::from("hello");
+        assert_eq!(abi_encode_params(&[s.clone().into()]), (s.clone(),).abi_encode_params());
+        assert_eq!(
+            abi_encode_params(&[s.clone().into()]),
+            DynSolValue::from(s.clone()).abi_encode_params()
+        );
+
+        // a single tuple parameter is not flattened
+        let tuple = (U256::from(1), s.clone());
+        let value = DynSolValue::Tuple(vec![U256::from(1).into(), s.clone().into()]);
+        assert_eq!(abi_encode_params(core::slice::from_ref(&value)), (tuple,).abi_encode_params());
+        assert_ne!(abi_encode_params(core::slice::from_ref(&value)), value.abi_encode_params());
+
+        // multiple parameters
+        let values = [U256::from(1).into(), s.clone().into(), Address::ZERO.into()];
+        assert_eq!(
+            abi_encode_params(&values),
+            (U256::from(1), s, Address::ZERO).abi_encode_params()
+        );
+    }
+
+    #[test]
+    fn uint_constructors() {
+        for size in (8..=256).step_by(8) {
+            let max = if size == 256 { U256::MAX } else { (U256::from(1) << size) - U256::from(1) };
+            assert_eq!(DynSolValue::uint(max, size), DynSolValue::Uint(max, size));
+            assert_eq!(DynSolValue::uint(0, size), DynSolValue::Uint(U256::ZERO, size));
+            if size < 256 {
+                let err = DynSolValue::checked_uint(max + U256::from(1), size).unwrap_err();
+                assert!(matches!(err, Error::IntOutOfRange { .. }), "{err:?}");
+            }
+        }
+
+        for size in [0, 1, 7, 12, 255, 257, 264] {
+            assert_eq!(
+                DynSolValue::checked_uint(0, size),
+                Err(parser::Error::invalid_size(format_args!("uint{size}")).into())
+            );
+        }
+
+        assert_eq!(DynSolValue::uint(u64::MAX, 64), DynSolValue::from(u64::MAX));
+        assert!(DynSolValue::checked_uint(-1, 256).is_err());
+    }
+
+    #[test]
+    #[should_panic = "integer out of range"]
+    fn uint_out_of_range() {
+        let _ = DynSolValue::uint(256, 8);
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn serde_roundtrip() {
+        let values = [
+            DynSolValue::Bool(true),
+            DynSolValue::Int(I256::MINUS_ONE, 24),
+            DynSolValue::Uint(U256::MAX, 256),
+            DynSolValue::FixedBytes(Word::repeat_byte(0x11), 7),
+            DynSolValue::Address(Address::repeat_byte(0x22)),
+            DynSolValue::Function(Function::repeat_byte(0x33)),
+            DynSolValue::Bytes(vec![]),
+            DynSolValue::Bytes(vec![0xde, 0xad, 0xbe, 0xef]),
+            DynSolValue::String(String::new()),
+            DynSolValue::String("hello".into()),
+            DynSolValue::Array(vec![]),
+            DynSolValue::Array(vec![DynSolValue::Array(vec![]), DynSolValue::Bool(false).into()]),
+            DynSolValue::FixedArray(vec![]),
+            DynSolValue::FixedArray(vec![DynSolValue::Uint(U256::from(1), 8)]),
+            DynSolValue::Tuple(vec![]),
+            DynSolValue::Tuple(vec![
+                DynSolValue::String("a".into()),
+                DynSolValue::Tuple(vec![DynSolValue::Bytes(vec![1])]),
+            ]),
+            #[cfg(feature = "eip712")]
+            DynSolValue::CustomStruct {
+                name: "Mail".into(),
+                prop_names: vec!["from".into(), "contents".into()],
+                tuple: vec![DynSolValue::Address(Address::ZERO), DynSolValue::String("hi".into())],
+            },
+        ];
+        for value in values {
+            let bin = bincode::serialize(&value).unwrap();
+            assert_eq!(bincode::deserialize::<DynSolValue>(&bin).unwrap(), value);
+            let json = serde_json::to_string(&value).unwrap();
+            assert_eq!(serde_json::from_str::<DynSolValue>(&json).unwrap(), value, "{json}");
+        }
+
+        // words are serialized as raw bytes, not as hex strings
+        let value = DynSolValue::FixedBytes(Word::ZERO, 32);
+        assert!(bincode::serialize(&value).unwrap().len() < 64);
+
+        // the type is needed to recover what an empty array is made of
+        for ty in ["uint256[]", "string[2][]", "(bool,bytes)[]", "function"] {
+            let ty: DynSolType = ty.parse().unwrap();
+            let pair = (ty, DynSolValue::Array(vec![]));
+            let bin = bincode::serialize(&pair).unwrap();
+            assert_eq!(bincode::deserialize::<(DynSolType, DynSolValue)>(&bin).unwrap(), pair);
+        }
+    }
+}
@@ -1,4 +1,8 @@
-use alloc::{borrow::Cow, string::String};
+use alloc::{
+    borrow::Cow,
+    boxed::Box,
+    string::{String, ToString},
+};
 use alloy_primitives::{Selector, B256};
 use alloy_sol_types::Error as SolTypesError;
 use core::fmt;
@@ -14,7 +18,6 @@ pub type Result<T, E = Error> = core::result::Result<T, E>;
 #[derive(Clone, Debug, PartialEq)]
 pub enum Error {
     /// Unknown type referenced from another type.
-    #[cfg(feature = "eip712")]
     MissingType(String),
     /// Detected circular dep during typegraph resolution.
     #[cfg(feature = "eip712")]
@@ -30,6 +33,13 @@ pub enum Error {
         /// The actual type.
         actual: String,
     },
+    /// Integer value does not fit in its declared size during encoding.
+    IntOutOfRange {
+        /// The declared type.
+        ty: String,
+        /// The value.
+        value: String,
+    },
     /// Length mismatch during encoding.
     EncodeLengthMismatch {
         /// The expected length.
@@ -62,6 +72,9 @@ pub enum Error {
         actual: B256,
     },
 
+    /// A JSON ABI parameter could not be resolved.
+    InvalidParam(Box<InvalidParamError>),
+
     /// [`hex`] error.
     Hex(hex::FromHexError),
     /// [`alloy_sol_type_parser`] error.
@@ -106,6 +119,7 @@ impl std::error::Error for Error {
             Self::Hex(e) => Some(e),
             Self::TypeParser(e) => Some(e),
             Self::SolTypes(e) => Some(e),
+            Self::InvalidParam(e) => Some(&**e),
             _ => None,
         }
     }
@@ -114,7 +128,6 @@ impl std::error::Error for Error {
 impl fmt::Display for Error {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
-            #[cfg(feature = "eip712")]
             Self::MissingType(name) => write!(f, "missing type in type resolution: {name}"),
             #[cfg(feature = "eip712")]
             Self::CircularDependency(dep) => write!(f, "circular dependency: {dep}"),
@@ -125,6 +138,9 @@ impl fmt::Display for Error {
                 f,
                 "type mismatch: expected type {expected:?}, got value with type {actual:?}",
             ),
+            Self::IntOutOfRange { ty, value } => {
+                write!(f, "integer out of range: {value} does not fit in type {ty:?}")
+            }
             &Self::EncodeLengthMismatch { expected, actual } => {
                 write!(f, "encode length mismatch: expected {expected} types, got {actual}",)
             }
@@ -138,6 +154,7 @@ impl fmt::Display for Error {
             Self::SelectorMismatch { expected, actual } => {
                 write!(f, "selector mismatch: expected {expected}, got {actual}",)
             }
+            Self::InvalidParam(e) => e.fmt(f),
             Self::Hex(e) => e.fmt(f),
             Self::TypeParser(e) => e.fmt(f),
             Self::SolTypes(e) => e.fmt(f),
@@ -163,7 +180,15 @@ impl Error {
         Self::InvalidPropertyDefinition(def.into())
     }
 
-    #[cfg(feature = "eip712")]
+    pub(crate) fn invalid_param(param: &alloy_json_abi::Param, error: Self) -> Self {
+        Self::InvalidParam(Box::new(InvalidParamError {
+            name: param.name.clone(),
+            ty: param.ty.clone(),
+            internal_type: param.internal_type().map(ToString::to_string),
+            error,
+        }))
+    }
+
     pub(crate) fn missing_type(name: &str) -> Self {
         Self::MissingType(name.into())
     }
@@ -173,3 +198,40 @@ impl Error {
         Self::CircularDependency(dep.into())
     }
 }
+
+/// A JSON ABI parameter that could not be resolved, returned in
+/// [`Error::InvalidParam`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct InvalidParamError {
+    /// The parameter's name.
+    pub name: String,
+    /// The parameter's type string.
+    pub ty: String,
+    /// The parameter's internal type, if any.
+    pub internal_type: Option<String>,
+    /// The underlying error.
+    pub error: Error,
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for InvalidParamError {
+    #[inline]
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        Some(&self.error)
+    }
+}
+
+impl fmt::Display for InvalidParamError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let Self { name, ty, internal_type, error } = self;
+        f.write_str("invalid parameter")?;
+        if !name.is_empty() {
+            write!(f, " {name:?}")?;
+        }
+        write!(f, " with type {ty:?}")?;
+        if let Some(internal_type) = internal_type {
+            write!(f, " (internal type {internal_type:?})")?;
+        }
+        write!(f, ": {error}")
+    }
+}
@@ -170,6 +170,7 @@ fn encode_typeck(params: &[Param], values: &[DynSolValue]) -> Result<Vec<u8>> {
                 actual: value.sol_type_name().unwrap_or_else(|| "<none>".into()).into_owned(),
             });
         }
+        value.check_int_ranges()?;
     }
 
     Ok(abi_encode(values))
@@ -284,4 +285,38 @@ mod tests {
         );
         assert_eq!(hex::encode(expected), hex::encode(result));
     }
+
+    #[test]
+    fn int_out_of_range() {
+        let func = Function::parse("f(uint8,int8)").unwrap();
+        let encode = |a: u64, b: i64| {
+            func.abi_encode_input(&[
+                DynSolValue::Uint(U256::from(a), 8),
+                DynSolValue::Int(alloy_primitives::I256::try_from(b).unwrap(), 8),
+            ])
+        };
+
+        assert!(encode(255, 127).is_ok());
+        assert!(encode(0, -128).is_ok());
+        assert_eq!(
+            encode(256, 0),
+            Err(CrateError::IntOutOfRange { ty: "uint8".into(), value: "256".into() })
+        );
+        assert_eq!(
+            encode(0, 128),
+            Err(CrateError::IntOutOfRange { ty: "int8".into(), value: "128".into() })
+        );
+        assert_eq!(
+            encode(0, -129),
+            Err(CrateError::IntOutOfRange { ty: "int8".into(), value: "-129".into() })
+        );
+
+        // Nested values are checked too.
+        let func = Function::parse("g((uint8[],bool))").unwrap();
+        let input = DynSolValue::Tuple(vec![
+            DynSolValue::Array(vec![DynSolValue::Uint(U256::MAX, 8)]),
+            DynSolValue::Bool(true),
+        ]);
+        assert!(func.abi_encode_input(&[input]).is_err());
+    }
 }
@@ -81,7 +81,7 @@ mod tests {
 
     use super::*;
     use alloy_json_abi::EventParam;
-    use alloy_primitives::{address, b256, bytes, hex, keccak256, Signed};
+    use alloy_primitives::{address, b256, bytes, hex, keccak256, Signed, U256};
 
     #[test]
     fn empty() {
@@ -207,4 +207,36 @@ mod tests {
         correct_event.decode_log(&log, false).unwrap();
         correct_event.decode_log(&log, true).unwrap();
     }
+
+    #[test]
+    fn decode_transfer_by_topic() {
+        let abi = alloy_json_abi::JsonAbi::parse([
+            "event Approval(address indexed owner, address indexed spender, uint256 value)",
+            "event Transfer(address indexed from, address indexed to, uint256 value)",
+        ])
+        .unwrap();
+
+        // An ERC-20 `Transfer` of 1000 USDC.
+        let log = LogData::new_unchecked(
+            vec![
+                b256!("ddf252ad1be2c89b69c2b068fc378daa952ba7f163c4a11628f55a4df523b3ef"),
+                b256!("00000000000000000000000028c6c06298d514db089934071355e5743bf21d60"),
+                b256!("000000000000000000000000a9d1e08c7793af67e9d92fe308d5697fb81d3e43"),
+            ],
+            bytes!("000000000000000000000000000000000000000000000000000000003b9aca00"),
+        );
+
+        let event = abi.event_by_topic(log.topics()[0]).unwrap();
+        assert_eq!(event.name, "Transfer");
+
+        let decoded = event.decode_log(&log, true).unwrap();
+        assert_eq!(
+            decoded.indexed,
+            [
+                DynSolValue::Address(address!("28C6c06298d514Db089934071355E5743bf21d60")),
+                DynSolValue::Address(address!("A9D1e08C7793af67e9d92fe308d5697FB81d3E43")),
+            ]
+        );
+        assert_eq!(decoded.body, [DynSolValue::Uint(U256::from(1_000_000_000u64), 256)]);
+    }
 }
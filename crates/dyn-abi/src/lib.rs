@@ -28,12 +28,12 @@ mod coerce;
 
 mod dynamic;
 pub use dynamic::{
-    DecodedError, DecodedEvent, DynSolCall, DynSolError, DynSolEvent, DynSolReturns, DynSolType,
-    DynSolValue, DynToken,
+    abi_encode_params, DecodedError, DecodedEvent, DynSolCall, DynSolError, DynSolEvent,
+    DynSolReturns, DynSolType, DynSolValue, DynToken,
 };
 
 mod error;
-pub use error::{Error, Result};
+pub use error::{Error, InvalidParamError, Result};
 
 mod ext;
 pub use ext::{ErrorExt, EventExt, FunctionExt, JsonAbiExt};
@@ -2,7 +2,7 @@
 //!
 //! This is a simple representation of Solidity type grammar.
 
-use crate::{DynSolCall, DynSolType, Result};
+use crate::{DynSolCall, DynSolType, Error, Result};
 use alloc::vec::Vec;
 use alloy_json_abi::{EventParam, Function, Param};
 use parser::{ParameterSpecifier, Parameters, RootType, TupleSpecifier, TypeSpecifier, TypeStem};
@@ -75,6 +75,13 @@ impl Specifier<DynSolType> for RootType<'_> {
                 let (s, is_uint) =
                     if let Some(s) = name.strip_prefix('u') { (s, true) } else { (name, false) };
 
+                if is_fixed_point(s) {
+                    return Err(parser::Error::new(format_args!(
+                        "fixed-point types are not supported: {name}"
+                    ))
+                    .into());
+                }
+
                 if let Some(sz) = s.strip_prefix("int") {
                     if let Ok(sz) = sz.parse() {
                         if sz != 0 && sz <= 256 && sz % 8 == 0 {
@@ -94,6 +101,15 @@ impl Specifier<DynSolType> for RootType<'_> {
     }
 }
 
+/// Returns `true` if `s` is `fixed` or `fixedMxN`, without the `u` prefix of `ufixed`.
+fn is_fixed_point(s: &str) -> bool {
+    let Some(sz) = s.strip_prefix("fixed") else { return false };
+    sz.is_empty()
+        || sz.split_once('x').map_or(false, |(m, n)| {
+            [m, n].iter().all(|s| !s.is_empty() && s.bytes().all(|b| b.is_ascii_digit()))
+        })
+}
+
 impl Specifier<DynSolType> for TupleSpecifier<'_> {
     #[inline]
     fn resolve(&self) -> Result<DynSolType> {
@@ -180,8 +196,10 @@ fn resolve_param(
 ) -> Result<DynSolType> {
     let ty = TypeSpecifier::parse(ty)?;
 
-    // type is simple, and we can resolve it via the specifier
-    if components.is_empty() {
+    // type is simple, and we can resolve it via the specifier.
+    // `tuple` without components is the empty tuple, which is not a valid type string
+    let is_tuple = matches!(&ty.stem, TypeStem::Root(root) if root.span() == "tuple");
+    if components.is_empty() && !is_tuple {
         return ty.resolve();
     }
 
@@ -206,6 +224,29 @@ fn resolve_param(
     Ok(resolved.array_wrap_from_iter(ty.sizes))
 }
 
+/// Resolves a type specifier, calling `resolver` for root types that are not elementary types.
+pub(crate) fn resolve_with<F>(ty: &TypeSpecifier<'_>, resolver: &F) -> Result<DynSolType>
+where
+    F: Fn(&str) -> Option<DynSolType>,
+{
+    let stem = match &ty.stem {
+        TypeStem::Root(root) => root.resolve().or_else(|e| {
+            let name = root.span();
+            resolver(name).ok_or_else(|| match e {
+                // not an elementary type name, so it must be a custom type
+                Error::TypeParser(e) if e == parser::Error::invalid_type_string(name) => {
+                    Error::missing_type(name)
+                }
+                e => e,
+            })
+        })?,
+        TypeStem::Tuple(tuple) => DynSolType::Tuple(
+            tuple.types.iter().map(|ty| resolve_with(ty, resolver)).collect::<Result<_>>()?,
+        ),
+    };
+    Ok(stem.array_wrap_from_iter(ty.sizes.iter().copied()))
+}
+
 fn tuple<T: Specifier<DynSolType>>(slice: &[T]) -> Result<Vec<DynSolType>> {
     let mut types = Vec::with_capacity(slice.len());
     for ty in slice {
@@ -239,6 +280,7 @@ deref_impls! {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::InvalidParamError;
     use alloc::boxed::Box;
 
     fn parse(s: &str) -> Result<DynSolType> {
@@ -317,6 +359,28 @@ mod tests {
         assert_eq!(parse("bytes32"), Ok(DynSolType::FixedBytes(32)));
     }
 
+    #[test]
+    fn it_normalizes_aliases() {
+        assert_eq!(parse("uint"), parse("uint256"));
+        assert_eq!(parse("int"), parse("int256"));
+        assert_eq!(parse("byte"), parse("bytes1"));
+        assert_eq!(parse("byte"), Ok(DynSolType::FixedBytes(1)));
+        assert_eq!(parse("(uint,int[2],byte[])"), parse("(uint256,int256[2],bytes1[])"));
+    }
+
+    #[test]
+    fn it_rejects_fixed_point_types() {
+        for ty in ["fixed", "ufixed", "fixed128x18", "ufixed8x1", "ufixed256x80[]", "(bool,fixed)"]
+        {
+            let err = parse(ty).unwrap_err().to_string();
+            assert!(err.contains("fixed-point types are not supported"), "{ty}: {err}");
+        }
+        for ty in ["fixedx18", "fixed128x", "fixed128", "fixedpoint"] {
+            let err = parse(ty).unwrap_err().to_string();
+            assert!(err.contains("invalid type string"), "{ty}: {err}");
+        }
+    }
+
     #[test]
     fn it_parses_complex_solidity_types() {
         assert_eq!(parse("uint256[]"), Ok(DynSolType::Array(Box::new(DynSolType::Uint(256)))));
@@ -368,4 +432,131 @@ mod tests {
             Ok(DynSolType::Array(Box::new(DynSolType::Uint(8))))
         );
     }
+
+    #[test]
+    fn parse_with_resolver() {
+        let order = DynSolType::Tuple(vec![DynSolType::Address, DynSolType::Uint(256)]);
+        let resolver = |name: &str| match name {
+            "Order" => Some(order.clone()),
+            "Fill" => Some(DynSolType::Tuple(vec![order.clone(), DynSolType::Bool])),
+            _ => None,
+        };
+        let parse = |s| DynSolType::parse_with_resolver(s, &resolver);
+
+        assert_eq!(parse("uint256"), Ok(DynSolType::Uint(256)));
+        assert_eq!(parse("Order"), Ok(order.clone()));
+        assert_eq!(parse("Order[2][]").unwrap().sol_type_name(), "(address,uint256)[2][]");
+        assert_eq!(
+            parse("(Fill,Order[],bytes)").unwrap().sol_type_name(),
+            "(((address,uint256),bool),(address,uint256)[],bytes)"
+        );
+
+        assert_eq!(parse("Missing"), Err(Error::MissingType("Missing".into())));
+        assert_eq!(parse("(uint256,Missing[])"), Err(Error::MissingType("Missing".into())));
+        // elementary types with invalid sizes are not passed through as missing types
+        assert_eq!(parse("uint7"), Err(parser::Error::invalid_size("uint7").into()));
+    }
+
+    #[test]
+    fn json_abi_tuple_params() {
+        let param = |json: &str| {
+            let param: Param = serde_json::from_str(json).unwrap();
+            DynSolType::from_json_abi_param(&param)
+        };
+
+        let fill = r#"{
+            "name": "fills",
+            "type": "tuple[][2]",
+            "internalType": "struct Exchange.Fill[][2]",
+            "components": [
+                {
+                    "name": "order",
+                    "type": "tuple",
+                    "internalType": "struct Exchange.Order",
+                    "components": [
+                        { "name": "maker", "type": "address", "internalType": "address" },
+                        { "name": "amounts", "type": "uint256[]", "internalType": "uint256[]" }
+                    ]
+                },
+                {
+                    "name": "legs",
+                    "type": "tuple[3]",
+                    "internalType": "struct Exchange.Leg[3]",
+                    "components": [{ "name": "data", "type": "bytes", "internalType": "bytes" }]
+                }
+            ]
+        }"#;
+        assert_eq!(param(fill).unwrap().sol_type_name(), "((address,uint256[]),(bytes,)[3])[][2]");
+
+        let order = DynSolType::Tuple(vec![DynSolType::Address, DynSolType::Bool]);
+        let components = r#"[{ "name": "a", "type": "address" }, { "name": "b", "type": "bool" }]"#;
+        let with_type = |ty: &str| {
+            param(&format!(r#"{{ "name": "", "type": "{ty}", "components": {components} }}"#))
+        };
+        assert_eq!(with_type("tuple"), Ok(order.clone()));
+        assert_eq!(with_type("tuple[]"), Ok(DynSolType::Array(Box::new(order.clone()))));
+        assert_eq!(with_type("tuple[4]"), Ok(DynSolType::FixedArray(Box::new(order.clone()), 4)));
+        assert_eq!(
+            with_type("tuple[][2]"),
+            Ok(DynSolType::FixedArray(Box::new(DynSolType::Array(Box::new(order))), 2))
+        );
+
+        assert_eq!(param(r#"{ "name": "", "type": "tuple" }"#), Ok(DynSolType::Tuple(vec![])));
+        assert_eq!(
+            param(r#"{ "name": "", "type": "tuple[]", "components": [] }"#),
+            Ok(DynSolType::Array(Box::new(DynSolType::Tuple(vec![]))))
+        );
+        assert_eq!(
+            param(r#"{ "name": "", "type": "uint256[2]" }"#).unwrap().sol_type_name(),
+            "uint256[2]"
+        );
+    }
+
+    #[test]
+    fn json_abi_param_errors() {
+        let param: Param = serde_json::from_str(
+            r#"{ "name": "amount", "type": "uint257", "internalType": "Token.Amount" }"#,
+        )
+        .unwrap();
+        let err = DynSolType::try_from(&param).unwrap_err();
+        let inner = param.resolve().unwrap_err();
+        assert_eq!(
+            err,
+            Error::InvalidParam(Box::new(InvalidParamError {
+                name: "amount".into(),
+                ty: "uint257".into(),
+                internal_type: Some("Token.Amount".into()),
+                error: inner.clone(),
+            }))
+        );
+        assert_eq!(
+            err.to_string(),
+            format!(
+                r#"invalid parameter "amount" with type "uint257" (internal type "Token.Amount"): {inner}"#
+            )
+        );
+        assert_eq!(DynSolType::from_json_abi_param(&param), Err(err));
+
+        // errors in components are reported with the outer parameter
+        let param: Param = serde_json::from_str(
+            r#"{ "name": "", "type": "tuple[]", "components": [{ "name": "a", "type": "int9" }] }"#,
+        )
+        .unwrap();
+        let err = DynSolType::try_from(&param).unwrap_err();
+        assert!(err.to_string().starts_with(r#"invalid parameter with type "tuple[]": "#), "{err}");
+        assert!(err.to_string().contains("int9"), "{err}");
+        assert!(std::error::Error::source(&err).is_some());
+
+        // malformed type with no components
+        let param = Param {
+            ty: "tuple(uint256".into(),
+            name: "x".into(),
+            components: vec![],
+            internal_type: None,
+        };
+        let err = DynSolType::try_from(&param).unwrap_err();
+        assert!(err
+            .to_string()
+            .starts_with(r#"invalid parameter "x" with type "tuple(uint256": "#));
+    }
 }
@@ -1,9 +1,9 @@
 use crate::{
     to_sol::{SolPrinter, ToSolConfig},
-    AbiItem, Constructor, Error, Event, Fallback, Function, Receive,
+    AbiItem, Constructor, Error, Event, Fallback, Function, Receive, StateMutability,
 };
-use alloc::{collections::btree_map, string::String, vec::Vec};
-use alloy_primitives::Bytes;
+use alloc::{borrow::Cow, collections::btree_map, string::String, vec::Vec};
+use alloy_primitives::{Bytes, B256};
 use btree_map::BTreeMap;
 use core::{fmt, iter, iter::Flatten};
 use serde::{
@@ -62,6 +62,140 @@ impl<'a> FromIterator<AbiItem<'a>> for JsonAbi {
     }
 }
 
+/// Formats the ABI as compact [Human-Readable ABI] signatures, one item per line.
+///
+/// Parameter names are omitted. Functions include their state mutability and return types, and
+/// events include their `indexed` markers and `anonymous` attribute.
+///
+/// Items are grouped in the order: constructor, fallback, receive, functions, events, errors.
+/// Each group is sorted lexicographically, so the output is stable regardless of the order of
+/// the original JSON.
+///
+/// Use [`to_sol`](JsonAbi::to_sol) to generate a full Solidity interface instead.
+///
+/// [Human-Readable ABI]: https://docs.ethers.org/v5/api/utils/abi/formats/#abi-formats--human-readable-abi
+///
+/// # Examples
+///
+/// ```
+/// use alloy_json_abi::{JsonAbi, StateMutability};
+///
+/// let mut abi = JsonAbi::parse([
+///     "function transfer(address to, uint256 amount) returns (bool)",
+///     "function balanceOf(address owner) returns (uint256)",
+///     "event Transfer(address indexed from, address indexed to, uint256 value)",
+/// ])?;
+/// abi.function_mut("balanceOf").unwrap()[0].state_mutability = StateMutability::View;
+/// assert_eq!(
+///     abi.to_string(),
+///     "\
+/// function balanceOf(address) view returns (uint256)
+/// function transfer(address,uint256) returns (bool)
+/// event Transfer(address indexed,address indexed,uint256)"
+/// );
+/// # Ok::<(), alloy_json_abi::parser::Error>(())
+/// ```
+impl fmt::Display for JsonAbi {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fn params(params: &[crate::Param], out: &mut String) {
+            out.push('(');
+            for (i, param) in params.iter().enumerate() {
+                if i > 0 {
+                    out.push(',');
+                }
+                param.selector_type_raw(out);
+            }
+            out.push(')');
+        }
+
+        fn mutability(state_mutability: crate::StateMutability, out: &mut String) {
+            if let Some(s) = state_mutability.as_str() {
+                out.push(' ');
+                out.push_str(s);
+            }
+        }
+
+        fn sorted(mut lines: Vec<String>) -> Vec<String> {
+            lines.sort_unstable();
+            lines
+        }
+
+        let constructor = self.constructor.iter().map(|c| {
+            let mut s = String::from("constructor");
+            params(&c.inputs, &mut s);
+            mutability(c.state_mutability, &mut s);
+            s
+        });
+        let fallback = self.fallback.iter().map(|fb| {
+            let mut s = String::from("fallback()");
+            mutability(fb.state_mutability, &mut s);
+            s
+        });
+        let receive = self.receive.iter().map(|r| {
+            let mut s = String::from("receive()");
+            mutability(r.state_mutability, &mut s);
+            s
+        });
+        let functions = sorted(
+            self.functions()
+                .map(|function| {
+                    let mut s = String::from("function ");
+                    s.push_str(&function.name);
+                    params(&function.inputs, &mut s);
+                    mutability(function.state_mutability, &mut s);
+                    if !function.outputs.is_empty() {
+                        s.push_str(" returns ");
+                        params(&function.outputs, &mut s);
+                    }
+                    s
+                })
+                .collect(),
+        );
+        let events = sorted(
+            self.events()
+                .map(|event| {
+                    let mut s = String::from("event ");
+                    s.push_str(&event.name);
+                    s.push('(');
+                    for (i, param) in event.inputs.iter().enumerate() {
+                        if i > 0 {
+                            s.push(',');
+                        }
+                        param.selector_type_raw(&mut s);
+                        if param.indexed {
+                            s.push_str(" indexed");
+                        }
+                    }
+                    s.push(')');
+                    if event.anonymous {
+                        s.push_str(" anonymous");
+                    }
+                    s
+                })
+                .collect(),
+        );
+        let errors = sorted(
+            self.errors()
+                .map(|error| {
+                    let mut s = String::from("error ");
+                    s.push_str(&error.name);
+                    params(&error.inputs, &mut s);
+                    s
+                })
+                .collect(),
+        );
+
+        let lines = constructor.chain(fallback).chain(receive).chain(functions).chain(events);
+        for (i, line) in lines.chain(errors).enumerate() {
+            if i > 0 {
+                f.write_str("\n")?;
+            }
+            f.write_str(&line)?;
+        }
+        Ok(())
+    }
+}
+
 impl JsonAbi {
     /// Creates an empty ABI object.
     #[inline]
@@ -197,6 +331,10 @@ impl JsonAbi {
     ///
     /// Note that enums are going to be identical to `uint8` UDVTs, since no
     /// other information about enums is present in the ABI.
+    ///
+    /// Parameter and struct field names that would be rejected by the `sol!` macro are
+    /// renamed: keywords, like `type`, are suffixed with `_`, and duplicate names are
+    /// suffixed with `_<n>`.
     #[inline]
     pub fn to_sol(&self, name: &str, config: Option<ToSolConfig>) -> String {
         let mut out = String::new();
@@ -246,6 +384,74 @@ impl JsonAbi {
         }
     }
 
+    /// Retains only the items specified by the predicate.
+    ///
+    /// The predicate is called once for every item, in the same order as
+    /// [`items`](Self::items), and the items for which it returns `false` are
+    /// removed.
+    ///
+    /// This only operates on the top-level items of the ABI. Structs are not
+    /// items: they are only referenced by the `internalType` of parameters, so
+    /// [`to_sol`](Self::to_sol) generates the structs that the remaining items
+    /// use, and drops the ones that only removed items used.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use alloy_json_abi::{AbiItem, JsonAbi};
+    ///
+    /// let mut abi = JsonAbi::parse([
+    ///     "function transfer(address to, uint256 amount) returns (bool)",
+    ///     "event Transfer(address indexed from, address indexed to, uint256 amount)",
+    /// ])?;
+    /// abi.retain(|item| matches!(item, AbiItem::Function(_)));
+    /// assert_eq!(abi.len(), 1);
+    /// assert!(abi.events.is_empty());
+    /// # Ok::<(), alloy_json_abi::parser::Error>(())
+    /// ```
+    pub fn retain<F: FnMut(AbiItem<'_>) -> bool>(&mut self, mut f: F) {
+        fn retain_map<T>(map: &mut BTreeMap<String, Vec<T>>, mut f: impl FnMut(&T) -> bool) {
+            map.retain(|_, items| {
+                items.retain(&mut f);
+                !items.is_empty()
+            });
+        }
+
+        self.constructor =
+            self.constructor.take().filter(|c| f(AbiItem::Constructor(Cow::Borrowed(c))));
+        self.fallback = self.fallback.take().filter(|x| f(AbiItem::Fallback(Cow::Borrowed(x))));
+        self.receive = self.receive.take().filter(|r| f(AbiItem::Receive(Cow::Borrowed(r))));
+        retain_map(&mut self.functions, |x| f(AbiItem::Function(Cow::Borrowed(x))));
+        retain_map(&mut self.events, |x| f(AbiItem::Event(Cow::Borrowed(x))));
+        retain_map(&mut self.errors, |x| f(AbiItem::Error(Cow::Borrowed(x))));
+    }
+
+    /// Retains only the functions specified by the predicate. Other items are
+    /// kept.
+    ///
+    /// See [`retain`](Self::retain) for more details.
+    pub fn retain_functions<F: FnMut(&Function) -> bool>(&mut self, mut f: F) {
+        self.retain(|item| match item {
+            AbiItem::Function(function) => f(&function),
+            _ => true,
+        });
+    }
+
+    /// Removes all `view` and `pure` functions, keeping only the functions that
+    /// can modify the state. Other items are kept.
+    ///
+    /// Functions declared with the legacy `constant` modifier are `view`
+    /// functions.
+    ///
+    /// See [`retain`](Self::retain) for more details.
+    #[must_use]
+    pub fn without_view_functions(mut self) -> Self {
+        self.retain_functions(|function| {
+            !matches!(function.state_mutability, StateMutability::Pure | StateMutability::View)
+        });
+        self
+    }
+
     /// Returns an immutable reference to the constructor.
     #[inline]
     pub const fn constructor(&self) -> Option<&Constructor> {
@@ -318,6 +524,30 @@ impl JsonAbi {
         self.events.values_mut().flatten()
     }
 
+    /// Returns the event whose selector is the given log topic (`topics[0]`).
+    ///
+    /// Anonymous events are skipped, as they don't emit their selector as the
+    /// first topic. Events can only share a selector if they share their
+    /// signature, so the first matching event is returned.
+    ///
+    /// This computes every event's selector; use
+    /// [`events_by_selector`](Self::events_by_selector) to look up many logs.
+    pub fn event_by_topic(&self, topic: B256) -> Option<&Event> {
+        self.events().find(|event| !event.anonymous && event.selector() == topic)
+    }
+
+    /// Returns a map of all the non-anonymous events, keyed by their selector,
+    /// which is the first topic of the logs they emit.
+    ///
+    /// See [`event_by_topic`](Self::event_by_topic) for more details.
+    pub fn events_by_selector(&self) -> BTreeMap<B256, &Event> {
+        let mut map = BTreeMap::new();
+        for event in self.events().filter(|event| !event.anonymous) {
+            map.entry(event.selector()).or_insert(event);
+        }
+        map
+    }
+
     /// Returns an iterator over immutable references to the errors.
     #[inline]
     pub fn errors(&self) -> FlattenValues<'_, Error> {
@@ -49,6 +49,10 @@ pub(crate) struct SolPrinter<'a> {
     /// This is set to `true` when printing functions so that we emit valid Solidity.
     emit_param_location: bool,
 
+    /// The parameter names already emitted in the current parameter list, used to rename
+    /// duplicates.
+    param_names: Vec<String>,
+
     /// Configuration.
     config: ToSolConfig,
 }
@@ -72,7 +76,7 @@ impl DerefMut for SolPrinter<'_> {
 impl<'a> SolPrinter<'a> {
     #[inline]
     pub(crate) fn new(s: &'a mut String, config: ToSolConfig) -> Self {
-        Self { s, emit_param_location: false, config }
+        Self { s, emit_param_location: false, param_names: Vec::new(), config }
     }
 
     #[inline]
@@ -264,6 +268,7 @@ impl ToSol for It<'_> {
                 out.push_str("struct ");
                 out.push_str(self.name);
                 out.push_str(" {\n");
+                out.param_names.clear();
                 for component in components {
                     out.indent();
                     out.indent();
@@ -421,6 +426,8 @@ impl<IN: ToSol> ToSol for AbiFunction<'_, IN> {
         ) {
             out.emit_param_location = true;
         }
+        // inputs and outputs share the same scope
+        out.param_names.clear();
 
         out.push_str(self.kw.as_str());
         if let Some(name) = self.name {
@@ -567,6 +574,116 @@ fn param(
     }
     if !name.is_empty() {
         out.push(' ');
-        out.push_str(name);
+        param_name(name, out);
+    }
+}
+
+/// Pushes a parameter name, renaming it if it would not be accepted as an identifier, or if it
+/// has already been used in the current parameter list.
+///
+/// Solc and Vyper may emit names that are not valid Solidity identifiers, like `type`, as well
+/// as duplicate names, like multiple `out`s for the return values of a Vyper function.
+fn param_name(name: &str, out: &mut SolPrinter<'_>) {
+    let mut name = if RESERVED.contains(&name) { format!("{name}_") } else { name.into() };
+    if out.param_names.contains(&name) {
+        let mut i = 1;
+        while out.param_names.contains(&format!("{name}_{i}")) {
+            i += 1;
+        }
+        name = format!("{name}_{i}");
     }
+    out.push_str(&name);
+    out.param_names.push(name);
 }
+
+/// Solidity keywords and reserved words, plus identifiers that cannot be used as Rust
+/// identifiers, even raw ones.
+static RESERVED: &[&str] = &[
+    "abstract",
+    "after",
+    "alias",
+    "anonymous",
+    "apply",
+    "as",
+    "assembly",
+    "auto",
+    "break",
+    "calldata",
+    "case",
+    "catch",
+    "constant",
+    "constructor",
+    "continue",
+    "contract",
+    "copyof",
+    "crate",
+    "default",
+    "define",
+    "delete",
+    "do",
+    "else",
+    "emit",
+    "enum",
+    "event",
+    "external",
+    "fallback",
+    "false",
+    "final",
+    "for",
+    "function",
+    "if",
+    "immutable",
+    "implements",
+    "import",
+    "in",
+    "indexed",
+    "inline",
+    "interface",
+    "internal",
+    "is",
+    "let",
+    "library",
+    "macro",
+    "mapping",
+    "match",
+    "memory",
+    "modifier",
+    "mutable",
+    "new",
+    "null",
+    "of",
+    "override",
+    "partial",
+    "payable",
+    "pragma",
+    "private",
+    "promise",
+    "public",
+    "pure",
+    "receive",
+    "reference",
+    "relocatable",
+    "return",
+    "returns",
+    "sealed",
+    "self",
+    "Self",
+    "sizeof",
+    "static",
+    "storage",
+    "struct",
+    "super",
+    "supports",
+    "switch",
+    "true",
+    "try",
+    "type",
+    "typedef",
+    "typeof",
+    "unchecked",
+    "using",
+    "var",
+    "view",
+    "virtual",
+    "while",
+];
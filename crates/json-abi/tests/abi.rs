@@ -1,4 +1,5 @@
 use alloy_json_abi::{AbiItem, EventParam, JsonAbi, Param};
+use alloy_primitives::{b256, B256};
 use pretty_assertions::assert_eq;
 use std::{
     collections::HashMap,
@@ -91,8 +92,6 @@ fn to_sol_test(path: &str, abi: &JsonAbi, run_solc: bool) {
         name,
         // https://github.com/alloy-rs/core/issues/349
         |"ZeroXExchange"| "GaugeController" | "DoubleExponentInterestSetter" | "NamelessParams"
-        // UniswapV1Exchange has return values with the same name.
-        | "UniswapV1Exchange"
     ) {
         return;
     }
@@ -201,6 +200,14 @@ fn test_events(abi: &JsonAbi) {
         .for_each(|(name, freq)| {
             assert_eq!(abi.event(&name).unwrap().len(), freq);
         });
+
+    let by_selector = abi.events_by_selector();
+    for event in abi.events().filter(|e| !e.anonymous) {
+        let selector = event.selector();
+        assert_eq!(abi.event_by_topic(selector).unwrap().selector(), selector);
+        assert_eq!(by_selector[&selector].selector(), selector);
+    }
+    assert!(by_selector.values().all(|e| !e.anonymous));
 }
 
 fn test_event_param(param: &EventParam) {
@@ -297,3 +304,77 @@ fn parse_unlinked_contract() {
     let err = res.unwrap_err();
     assert!(err.to_string().contains("expected bytecode, found unlinked bytecode with placeholder: 7233c33f2e1e35848c685b0eb24649959e"));
 }
+
+#[test]
+fn event_by_topic() {
+    let abi = JsonAbi::parse([
+        "event Transfer(address indexed from, address indexed to, uint256 value)",
+        "event Approval(address indexed owner, address indexed spender, uint256 value)",
+        "event Anon(address indexed from, uint256 value) anonymous",
+    ])
+    .unwrap();
+
+    let transfer = b256!("ddf252ad1be2c89b69c2b068fc378daa952ba7f163c4a11628f55a4df523b3ef");
+    assert_eq!(abi.event_by_topic(transfer).unwrap().name, "Transfer");
+
+    let anon = abi.event("Anon").unwrap()[0].selector();
+    assert_eq!(abi.event_by_topic(anon), None);
+    assert_eq!(abi.event_by_topic(B256::ZERO), None);
+
+    let by_selector = abi.events_by_selector();
+    assert_eq!(by_selector.len(), 2);
+    assert_eq!(by_selector[&transfer].name, "Transfer");
+    assert!(!by_selector.contains_key(&anon));
+}
+
+#[test]
+fn display() {
+    let json = r#"[
+        {"type": "error", "name": "Unauthorized", "inputs": [{"name": "account", "type": "address"}]},
+        {"type": "event", "name": "Log", "anonymous": true, "inputs": [
+            {"name": "data", "type": "bytes", "indexed": false}
+        ]},
+        {"type": "event", "name": "Transfer", "anonymous": false, "inputs": [
+            {"name": "from", "type": "address", "indexed": true},
+            {"name": "to", "type": "address", "indexed": true},
+            {"name": "value", "type": "uint256", "indexed": false}
+        ]},
+        {"type": "function", "name": "transfer", "stateMutability": "nonpayable",
+            "inputs": [{"name": "to", "type": "address"}, {"name": "amount", "type": "uint256"}],
+            "outputs": [{"name": "", "type": "bool"}]},
+        {"type": "function", "name": "transfer", "stateMutability": "payable",
+            "inputs": [{"name": "order", "type": "tuple", "components": [
+                {"name": "to", "type": "address"}, {"name": "amounts", "type": "uint256[]"}
+            ]}],
+            "outputs": []},
+        {"type": "function", "name": "balanceOf", "stateMutability": "view",
+            "inputs": [{"name": "owner", "type": "address"}],
+            "outputs": [{"name": "", "type": "uint256"}]},
+        {"type": "function", "name": "decimals", "stateMutability": "pure",
+            "inputs": [], "outputs": [{"name": "", "type": "uint8"}]},
+        {"type": "receive", "stateMutability": "payable"},
+        {"type": "fallback", "stateMutability": "nonpayable"},
+        {"type": "constructor", "stateMutability": "nonpayable",
+            "inputs": [{"name": "name", "type": "string"}]}
+    ]"#;
+    let abi: JsonAbi = serde_json::from_str(json).unwrap();
+    let expected = "\
+constructor(string)
+fallback()
+receive() payable
+function balanceOf(address) view returns (uint256)
+function decimals() pure returns (uint8)
+function transfer((address,uint256[])) payable
+function transfer(address,uint256) returns (bool)
+event Log(bytes) anonymous
+event Transfer(address indexed,address indexed,uint256)
+error Unauthorized(address)";
+    assert_eq!(abi.to_string(), expected);
+
+    // the output does not depend on the order of the items
+    let mut items: Vec<AbiItem<'_>> = serde_json::from_str(json).unwrap();
+    items.reverse();
+    assert_eq!(items.into_iter().collect::<JsonAbi>().to_string(), expected);
+
+    assert_eq!(JsonAbi::new().to_string(), "");
+}
@@ -22,7 +22,7 @@ interface UniswapV1Exchange {
     function getTokenToEthInputPrice(uint256 tokens_sold) external view returns (uint256 out);
     function getTokenToEthOutputPrice(uint256 eth_bought) external view returns (uint256 out);
     function name() external view returns (bytes32 out);
-    function removeLiquidity(uint256 amount, uint256 min_eth, uint256 min_tokens, uint256 deadline) external returns (uint256 out, uint256 out);
+    function removeLiquidity(uint256 amount, uint256 min_eth, uint256 min_tokens, uint256 deadline) external returns (uint256 out, uint256 out_1);
     function setup(address token_addr) external;
     function symbol() external view returns (bytes32 out);
     function tokenAddress() external view returns (address out);
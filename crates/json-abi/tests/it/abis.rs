@@ -1,5 +1,5 @@
 use alloy_json_abi::{
-    Constructor, Error, Event, EventParam, Fallback, Function, JsonAbi, Param, Receive,
+    AbiItem, Constructor, Error, Event, EventParam, Fallback, Function, JsonAbi, Param, Receive,
     StateMutability,
 };
 use std::collections::BTreeMap;
@@ -595,3 +595,76 @@ fn fallback() {
 
     assert_ser_de!(JsonAbi, deserialized);
 }
+
+#[test]
+fn retain_mutating_functions() {
+    let abi: JsonAbi = serde_json::from_str(include_str!("../abi/ZRXToken.json")).unwrap();
+    assert!(abi.function("balanceOf").is_some());
+    let n_events = abi.events.len();
+    assert_ne!(n_events, 0);
+
+    let mutating = abi.clone().without_view_functions();
+    let names = mutating.functions.keys().map(String::as_str).collect::<Vec<_>>();
+    assert_eq!(names, ["approve", "transfer", "transferFrom"]);
+    assert!(mutating.functions().all(|f| f.state_mutability == StateMutability::NonPayable));
+    assert_eq!(mutating.events.len(), n_events);
+    assert_eq!(mutating.constructor, abi.constructor);
+
+    let mut only_functions = mutating.clone();
+    only_functions.retain(|item| matches!(item, AbiItem::Function(_)));
+    assert_eq!(only_functions.functions, mutating.functions);
+    assert!(only_functions.events.is_empty());
+    assert!(only_functions.constructor.is_none());
+    assert_eq!(only_functions.len(), 3);
+}
+
+#[test]
+fn retain_drops_unused_structs() {
+    let json = r#"[
+        {
+            "type": "function",
+            "name": "getOrder",
+            "inputs": [{ "name": "id", "type": "uint256" }],
+            "outputs": [
+                {
+                    "name": "order",
+                    "type": "tuple",
+                    "internalType": "struct Order",
+                    "components": [
+                        { "name": "maker", "type": "address" },
+                        { "name": "amount", "type": "uint256" }
+                    ]
+                }
+            ],
+            "stateMutability": "view"
+        },
+        {
+            "type": "function",
+            "name": "fill",
+            "inputs": [{ "name": "id", "type": "uint256" }],
+            "outputs": [],
+            "stateMutability": "nonpayable"
+        },
+        {
+            "type": "function",
+            "name": "overloaded",
+            "inputs": [{ "name": "a", "type": "uint256" }],
+            "outputs": [],
+            "stateMutability": "view"
+        },
+        {
+            "type": "function",
+            "name": "overloaded",
+            "inputs": [{ "name": "a", "type": "address" }],
+            "outputs": [],
+            "stateMutability": "payable"
+        }
+    ]"#;
+    let mut abi: JsonAbi = serde_json::from_str(json).unwrap();
+    assert!(abi.to_sol("Exchange", None).contains("struct Order"));
+
+    abi.retain_functions(|f| f.state_mutability != StateMutability::View);
+    assert_eq!(abi.functions.keys().collect::<Vec<_>>(), ["fill", "overloaded"]);
+    assert_eq!(abi.function("overloaded").unwrap().len(), 1);
+    assert!(!abi.to_sol("Exchange", None).contains("struct Order"));
+}
@@ -96,3 +96,98 @@ pub type StorageValue = U256;
 /// Solidity contract functions are addressed using the first four bytes of the
 /// Keccak-256 hash of their signature.
 pub type Selector = FixedBytes<4>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::UintSlice;
+    use ruint::FromUintError;
+
+    // Narrowing conversions are provided by `ruint` through `TryFrom<Uint>`.
+    #[test]
+    fn u256_try_into_primitives() {
+        macro_rules! boundaries {
+            ($($t:ty),+) => {$(
+                let max = U256::from(<$t>::MAX);
+                assert_eq!(<$t>::try_from(max), Ok(<$t>::MAX));
+                assert_eq!(<$t>::try_from(&max), Ok(<$t>::MAX));
+                assert_eq!(<$t>::try_from(U256::ZERO), Ok(0));
+
+                let over = max + U256::from(1u64);
+                assert_eq!(<$t>::try_from(over), Err(FromUintError::Overflow(256, 0, <$t>::MAX)));
+                let res: Result<$t, _> = U256::MAX.try_into();
+                assert_eq!(res, Err(FromUintError::Overflow(256, <$t>::MAX, <$t>::MAX)));
+            )+};
+        }
+
+        boundaries!(u8, u16, u32, u64, u128, usize);
+    }
+
+    // Slice conversions are provided by `ruint`. Big endian slices longer than 32 bytes are
+    // rejected, even when the extra bytes are zero.
+    //
+    // Note that `ruint` 1.12 only rejects little endian slices of 40 bytes or more, and
+    // `try_from_le_slice` panics on lengths between 33 and 39. `UintSlice::checked_from_le_slice`
+    // rejects all of them.
+    #[test]
+    fn u256_from_slice() {
+        assert_eq!(U256::try_from_be_slice(&[]), Some(U256::ZERO));
+        assert_eq!(U256::try_from_be_slice(&[1, 2]), Some(U256::from(0x0102u64)));
+        assert_eq!(U256::try_from_le_slice(&[1, 2]), Some(U256::from(0x0201u64)));
+        assert_eq!(U256::from_be_slice(&[1, 2]), U256::from(0x0102u64));
+        assert_eq!(U256::from_le_slice(&[1, 2]), U256::from(0x0201u64));
+
+        assert_eq!(U256::try_from_be_slice(&[0xff; 32]), Some(U256::MAX));
+        assert_eq!(U256::try_from_le_slice(&[0xff; 32]), Some(U256::MAX));
+        assert_eq!(U256::from_be_slice(&[0xff; 32]), U256::MAX);
+        assert_eq!(U256::from_le_slice(&[0xff; 32]), U256::MAX);
+
+        assert_eq!(U256::try_from_be_slice(&[0xff; 33]), None);
+        assert_eq!(U256::try_from_be_slice(&[0; 33]), None);
+        assert_eq!(U256::try_from_le_slice(&[0xff; 40]), None);
+        assert_eq!(U256::try_from_le_slice(&[0; 40]), None);
+        assert_eq!(U256::checked_from_le_slice(&[0xff; 33]), None);
+        assert_eq!(U256::checked_from_le_slice(&[0; 33]), None);
+        assert_eq!(I256::try_from_le_slice(&[0; 33]), None);
+    }
+
+    #[test]
+    #[should_panic = "Value too large for Uint"]
+    fn u256_from_be_slice_overflow() {
+        let _ = U256::from_be_slice(&[0xff; 33]);
+    }
+
+    // Integer logarithms are provided by `ruint` with the `std` feature.
+    #[test]
+    #[cfg(feature = "std")]
+    fn u256_log() {
+        assert_eq!(U256::ZERO.checked_log2(), None);
+        assert_eq!(U256::ZERO.checked_log10(), None);
+        assert_eq!(U256::ZERO.checked_log(U256::from(3u64)), None);
+        assert_eq!(U256::from(8u64).checked_log(U256::ZERO), None);
+        assert_eq!(U256::from(8u64).checked_log(U256::from(1u64)), None);
+        assert_eq!(U256::MAX.checked_log2(), Some(255));
+        assert_eq!(U256::MAX.checked_log10(), Some(77));
+
+        for i in 1..256usize {
+            let pow = U256::from(1u64) << i;
+            assert_eq!(pow.checked_log2(), Some(i), "2^{i}");
+            assert_eq!((pow - U256::from(1u64)).checked_log2(), Some(i - 1), "2^{i} - 1");
+            assert_eq!((pow + U256::from(1u64)).checked_log2(), Some(i), "2^{i} + 1");
+        }
+
+        let ten = U256::from(10u64);
+        for i in 1..78usize {
+            let pow = ten.pow(U256::from(i as u64));
+            assert_eq!(pow.checked_log10(), Some(i), "10^{i}");
+            assert_eq!((pow - U256::from(1u64)).checked_log10(), Some(i - 1), "10^{i} - 1");
+            assert_eq!((pow + U256::from(1u64)).checked_log10(), Some(i), "10^{i} + 1");
+            assert_eq!(pow.checked_log(ten), Some(i));
+        }
+
+        // floor semantics for an arbitrary base
+        assert_eq!(U256::from(80u64).checked_log(U256::from(3u64)), Some(3));
+        assert_eq!(U256::from(81u64).checked_log(U256::from(3u64)), Some(4));
+        assert_eq!(U256::from(2u64).checked_log(U256::from(3u64)), Some(0));
+    }
+}
@@ -42,6 +42,48 @@ impl fmt::Display for AddressError {
     }
 }
 
+/// Error type for [ICAP](Address::from_icap) address parsing.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum IcapError {
+    /// The country code is not `XE`.
+    InvalidCountryCode,
+
+    /// The check digits are not decimal digits.
+    InvalidCheckDigits,
+
+    /// The address is in the unsupported "indirect" format.
+    Indirect,
+
+    /// The address has neither the "direct" nor the "basic" format's length.
+    InvalidLength,
+
+    /// The address contains a character that is not alphanumeric.
+    InvalidCharacter,
+
+    /// Invalid ISO 13616 checksum.
+    InvalidChecksum,
+
+    /// The encoded value does not fit in an address.
+    Overflow,
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for IcapError {}
+
+impl fmt::Display for IcapError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::InvalidCountryCode => "Invalid ICAP address: expected the `XE` country code",
+            Self::InvalidCheckDigits => "Invalid ICAP address: invalid check digits",
+            Self::Indirect => "Invalid ICAP address: indirect ICAP is not supported",
+            Self::InvalidLength => "Invalid ICAP address: invalid length",
+            Self::InvalidCharacter => "Invalid ICAP address: invalid character",
+            Self::InvalidChecksum => "Bad ICAP checksum",
+            Self::Overflow => "Invalid ICAP address: address is out of range",
+        })
+    }
+}
+
 wrap_fixed_bytes!(
     // we implement Display with the checksum, so we don't derive it
     extra_derives: [],
@@ -56,6 +98,9 @@ wrap_fixed_bytes!(
     /// ([`to_checksum`]).
     /// Use [`Debug`] to display the raw bytes without the checksum.
     ///
+    /// Addresses are ordered as big-endian unsigned integers, which matches
+    /// Solidity's `<` on `address`, as used when sorting the tokens of a pair.
+    ///
     /// [EIP-55]: https://eips.ethereum.org/EIPS/eip-55
     /// [`Debug`]: fmt::Debug
     /// [`Display`]: fmt::Display
@@ -152,6 +197,35 @@ impl Address {
         FixedBytes(word)
     }
 
+    /// Parses an Ethereum address from a hex string, with or without the `0x` prefix, in any
+    /// case.
+    ///
+    /// Unlike [`parse_checksummed`](Self::parse_checksummed), the [EIP-55] checksum is not
+    /// verified, so mixed-case inputs are accepted as is.
+    ///
+    /// [EIP-55]: https://eips.ethereum.org/EIPS/eip-55
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the input is not valid hex, has an odd length, or does not decode to
+    /// exactly 20 bytes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use alloy_primitives::{address, Address};
+    /// let expected = address!("d8da6bf26964af9d7eed9e03e53415d37aa96045");
+    /// assert_eq!(Address::from_str_lenient("d8da6bf26964af9d7eed9e03e53415d37aa96045"), Ok(expected));
+    /// assert_eq!(
+    ///     Address::from_str_lenient("0xD8DA6BF26964AF9D7EED9E03E53415D37AA96045"),
+    ///     Ok(expected)
+    /// );
+    /// ```
+    #[inline]
+    pub fn from_str_lenient(s: &str) -> Result<Self, hex::FromHexError> {
+        FixedBytes::from_str_lenient(s).map(Self)
+    }
+
     /// Parse an Ethereum address, verifying its [EIP-55] checksum.
     ///
     /// You can optionally specify an [EIP-155 chain ID] to check the address
@@ -290,6 +364,90 @@ impl Address {
         buf
     }
 
+    /// Encodes an Ethereum address in the [ICAP] format, an IBAN-compatible
+    /// base-36 encoding with an ISO 13616 checksum.
+    ///
+    /// Addresses which fit in 30 base-36 characters, which includes all
+    /// addresses with a leading zero byte, are encoded in the "direct" format,
+    /// which is a valid IBAN. Other addresses need 31 characters, and are
+    /// encoded in the "basic" format, which is not IBAN-compatible.
+    ///
+    /// [ICAP]: https://github.com/ethereum/wiki/wiki/Inter-exchange-Client-Address-Protocol-(ICAP)
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use alloy_primitives::{address, Address};
+    /// let address = address!("00c5496aee77c1ba1f0854206a26dda82a81d6d8");
+    /// assert_eq!(address.to_icap(), "XE7338O073KYGTWWZN0F2WZ0R8PX5ZPPZS");
+    /// ```
+    pub fn to_icap(&self) -> String {
+        let mut bban = [b'0'; 31];
+        let mut value: U160 = (*self).into();
+        let base = U160::from(36u64);
+        let mut i = bban.len();
+        while !value.is_zero() {
+            let (quotient, digit) = value.div_rem(base);
+            i -= 1;
+            bban[i] = ICAP_ALPHABET[digit.as_limbs()[0] as usize];
+            value = quotient;
+        }
+        let bban = if bban[0] == b'0' { &bban[1..] } else { &bban[..] };
+        let check = 98 - icap_mod97(bban.iter().chain(b"XE00")).unwrap();
+        // SAFETY: `bban` only contains ASCII alphanumeric characters.
+        format!("XE{check:02}{}", unsafe { str::from_utf8_unchecked(bban) })
+    }
+
+    /// Parses an Ethereum address from the [ICAP] "direct" or "basic" format,
+    /// verifying its checksum. See [`to_icap`](Self::to_icap) for more details.
+    ///
+    /// The "indirect" format, which identifies an account through an
+    /// institution and client identifier rather than encoding the address, is
+    /// not supported.
+    ///
+    /// [ICAP]: https://github.com/ethereum/wiki/wiki/Inter-exchange-Client-Address-Protocol-(ICAP)
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use alloy_primitives::{address, Address};
+    /// let address = Address::from_icap("XE7338O073KYGTWWZN0F2WZ0R8PX5ZPPZS").unwrap();
+    /// assert_eq!(address, address!("00c5496aee77c1ba1f0854206a26dda82a81d6d8"));
+    ///
+    /// assert!(Address::from_icap("XE7438O073KYGTWWZN0F2WZ0R8PX5ZPPZS").is_err());
+    /// ```
+    pub fn from_icap(s: &str) -> Result<Self, IcapError> {
+        let s = s.as_bytes();
+        if s.len() < 4 || !s[..2].eq_ignore_ascii_case(b"XE") {
+            return Err(IcapError::InvalidCountryCode);
+        }
+        if !s[2..4].iter().all(u8::is_ascii_digit) {
+            return Err(IcapError::InvalidCheckDigits);
+        }
+        let bban = &s[4..];
+        match bban.len() {
+            30 | 31 => {}
+            16 => return Err(IcapError::Indirect),
+            _ => return Err(IcapError::InvalidLength),
+        }
+        match icap_mod97(bban.iter().chain(&s[..4])) {
+            Some(1) => {}
+            Some(_) => return Err(IcapError::InvalidChecksum),
+            None => return Err(IcapError::InvalidCharacter),
+        }
+
+        let base = U160::from(36u64);
+        let mut value = U160::ZERO;
+        for &c in bban {
+            let digit = U160::from(icap_digit(c).unwrap());
+            value = value
+                .checked_mul(base)
+                .and_then(|value| value.checked_add(digit))
+                .ok_or(IcapError::Overflow)?;
+        }
+        Ok(Self::from(value))
+    }
+
     #[allow(clippy::wrong_self_convention)]
     fn to_checksum_inner(&self, buf: &mut [u8; 42], chain_id: Option<u64>) {
         buf[0] = b'0';
@@ -478,6 +636,30 @@ impl Address {
 #[derive(Clone)]
 pub struct AddressChecksumBuffer(MaybeUninit<[u8; 42]>);
 
+const ICAP_ALPHABET: &[u8; 36] = b"0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZ";
+
+/// Returns the value of an ICAP base-36 character, case-insensitively.
+#[inline]
+const fn icap_digit(c: u8) -> Option<u8> {
+    match c {
+        b'0'..=b'9' => Some(c - b'0'),
+        b'A'..=b'Z' => Some(c - b'A' + 10),
+        b'a'..=b'z' => Some(c - b'a' + 10),
+        _ => None,
+    }
+}
+
+/// Computes the ISO 7064 mod 97-10 remainder of the given characters, where
+/// letters are expanded to two decimal digits.
+fn icap_mod97<'a>(chars: impl IntoIterator<Item = &'a u8>) -> Option<u8> {
+    let mut rem = 0u32;
+    for &c in chars {
+        let digit = icap_digit(c)? as u32;
+        rem = if digit < 10 { rem * 10 + digit } else { rem * 100 + digit } % 97;
+    }
+    Some(rem as u8)
+}
+
 impl fmt::Debug for AddressChecksumBuffer {
     #[inline]
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -542,7 +724,59 @@ impl AddressChecksumBuffer {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::hex;
+    use crate::{hex, FixedBytesLengthError};
+
+    #[test]
+    fn ord() {
+        // Solidity: `token0 < token1` compares `uint160(token0) < uint160(token1)`
+        let weth = address!("C02aaA39b223FE8D0A0e5C4F27eAD9083C756Cc2");
+        let usdc = address!("A0b86991c6218b36c1d19D4a2e9Eb0cE3606eB48");
+        let dai = address!("6B175474E89094C44Da98b954EedeAC495271d0F");
+        let high = Address::left_padding_from(&[1, 0]);
+        let low = Address::with_last_byte(0xff);
+
+        let mut addresses = vec![weth, Address::ZERO, usdc, high, dai, low];
+        addresses.sort();
+        assert_eq!(addresses, [Address::ZERO, low, high, dai, usdc, weth]);
+        assert!(addresses
+            .windows(2)
+            .all(|w| U160::from_be_bytes(w[0].0 .0) < U160::from_be_bytes(w[1].0 .0)));
+    }
+
+    #[test]
+    fn is_zero() {
+        const _: () = assert!(Address::ZERO.const_is_zero());
+        const _: () = assert!(Address::ZERO.is_zero());
+        assert!(Address::ZERO.is_zero());
+        assert!(!Address::with_last_byte(1).is_zero());
+        assert!(!Address::with_last_byte(1).const_is_zero());
+    }
+
+    #[test]
+    fn try_from_len() {
+        let expected = address!("0102030405060708090a0b0c0d0e0f1011121314");
+        let bytes = expected.to_vec();
+        assert_eq!(Address::try_from(&bytes[..]).unwrap(), expected);
+        assert_eq!(Address::try_from(bytes.clone()).unwrap(), expected);
+
+        assert!(Address::try_from(&bytes[1..]).is_err());
+        assert_eq!(
+            Address::try_from_slice(&bytes[1..]),
+            Err(FixedBytesLengthError::TooShort { expected: 20, actual: 19 })
+        );
+        assert_eq!(
+            Address::try_from(bytes[1..].to_vec()),
+            Err(FixedBytesLengthError::TooShort { expected: 20, actual: 19 })
+        );
+
+        // e.g. a left-padded ABI word
+        let word = expected.into_word();
+        assert!(Address::try_from(&word[..]).is_err());
+        assert_eq!(
+            Address::try_from(word.to_vec()),
+            Err(FixedBytesLengthError::TooLong { expected: 20, actual: 32 })
+        );
+    }
 
     #[test]
     fn parse() {
@@ -557,6 +791,137 @@ mod tests {
         );
     }
 
+    #[test]
+    fn parse_lenient() {
+        let expected = address!("d8da6bf26964af9d7eed9e03e53415d37aa96045");
+        for s in [
+            "d8da6bf26964af9d7eed9e03e53415d37aa96045",
+            "0xd8da6bf26964af9d7eed9e03e53415d37aa96045",
+            "D8DA6BF26964AF9D7EED9E03E53415D37AA96045",
+            "0xD8DA6BF26964AF9D7EED9E03E53415D37AA96045",
+            // the checksum is not validated
+            "0xd8dA6BF26964aF9D7eEd9e03E53415D37aA96045",
+            "d8Da6bf26964af9d7eed9e03e53415d37aa96045",
+        ] {
+            assert_eq!(Address::from_str_lenient(s), Ok(expected), "{s}");
+        }
+        assert!(
+            Address::parse_checksummed("d8Da6bf26964af9d7eed9e03e53415d37aa96045", None).is_err()
+        );
+
+        assert_eq!(
+            Address::from_str_lenient("d8da6bf26964af9d7eed9e03e53415d37aa9604"),
+            Err(hex::FromHexError::OddLength)
+        );
+        assert_eq!(
+            Address::from_str_lenient("0xd8da6bf26964af9d7eed9e03e53415d37aa9604"),
+            Err(hex::FromHexError::OddLength)
+        );
+        assert_eq!(
+            Address::from_str_lenient("d8da6bf26964af9d7eed9e03e53415d37aa960"),
+            Err(hex::FromHexError::InvalidStringLength)
+        );
+        assert!(Address::from_str_lenient("").is_err());
+        assert!(Address::from_str_lenient("0x").is_err());
+        assert!(Address::from_str_lenient("g8da6bf26964af9d7eed9e03e53415d37aa96045").is_err());
+    }
+
+    #[test]
+    fn from_slice() {
+        let bytes = hex!("0102030405060708090a0b0c0d0e0f1011121314");
+        let address = Address::from_slice(&bytes);
+        assert_eq!(address, bytes);
+        assert_eq!(Address::try_from_slice(&bytes).unwrap(), address);
+        assert_eq!(address.to_vec(), bytes.to_vec());
+
+        // A 32-byte word is not silently truncated; use `from_word` for that.
+        let word = address.into_word();
+        assert!(Address::try_from_slice(word.as_slice()).is_err());
+        assert!(Address::try_from_slice(&bytes[..19]).is_err());
+        assert_eq!(Address::from_word(word), address);
+    }
+
+    #[test]
+    #[should_panic = "cannot convert a slice of length 32 to Address"]
+    fn from_slice_word() {
+        Address::from_slice(&[0; 32]);
+    }
+
+    #[test]
+    fn test_addresses() {
+        assert_eq!(
+            Address::repeat_byte(0x11),
+            address!("1111111111111111111111111111111111111111")
+        );
+        assert_eq!(
+            Address::with_last_byte(0x2a),
+            address!("000000000000000000000000000000000000002a")
+        );
+        assert_ne!(Address::with_last_byte(1), Address::with_last_byte(2));
+
+        assert_eq!(Address::left_padding_from(&[]), Address::ZERO);
+        assert_eq!(Address::left_padding_from(&[0x2a]), Address::with_last_byte(0x2a));
+        assert_eq!(
+            Address::left_padding_from(&[0x12, 0x34]),
+            address!("0000000000000000000000000000000000001234")
+        );
+        assert_eq!(Address::left_padding_from(&[0x11; 20]), Address::repeat_byte(0x11));
+        assert_eq!(
+            Address::right_padding_from(&[0x12, 0x34]),
+            address!("1234000000000000000000000000000000000000")
+        );
+    }
+
+    #[test]
+    #[should_panic = "slice is too large. Expected <=20 bytes, got 21"]
+    fn left_padding_from_too_large() {
+        Address::left_padding_from(&[0; 21]);
+    }
+
+    #[test]
+    fn icap() {
+        let address = address!("00c5496aee77c1ba1f0854206a26dda82a81d6d8");
+        let icap = "XE7338O073KYGTWWZN0F2WZ0R8PX5ZPPZS";
+        assert_eq!(address.to_icap(), icap);
+        assert_eq!(Address::from_icap(icap).unwrap(), address);
+        assert_eq!(Address::from_icap(&icap.to_lowercase()).unwrap(), address);
+
+        // direct format for addresses with a leading zero byte, basic otherwise
+        let addresses = [
+            Address::ZERO,
+            Address::with_last_byte(1),
+            address!("00ffffffffffffffffffffffffffffffffffffff"),
+            address!("d8da6bf26964af9d7eed9e03e53415d37aa96045"),
+            Address::repeat_byte(0xff),
+        ];
+        for address in addresses {
+            let icap = address.to_icap();
+            let expected_len = if address[0] == 0 { 34 } else { 35 };
+            assert_eq!(icap.len(), expected_len, "{address}: {icap}");
+            assert!(icap.starts_with("XE"));
+            assert_eq!(Address::from_icap(&icap).unwrap(), address, "{icap}");
+        }
+        assert_eq!(Address::ZERO.to_icap(), "XE50000000000000000000000000000000");
+
+        let err = |s: &str| Address::from_icap(s).unwrap_err();
+        assert_eq!(err("XE7438O073KYGTWWZN0F2WZ0R8PX5ZPPZS"), IcapError::InvalidChecksum);
+        assert_eq!(err("XE7338O073KYGTWWZN0F2WZ0R8PX5ZPPZT"), IcapError::InvalidChecksum);
+        assert_eq!(err("XE81ETHXREGGAVOFYORK"), IcapError::Indirect);
+        assert_eq!(err("GB82WEST12345698765432"), IcapError::InvalidCountryCode);
+        assert_eq!(err("XE73"), IcapError::InvalidLength);
+        assert_eq!(err("XEAB38O073KYGTWWZN0F2WZ0R8PX5ZPPZS"), IcapError::InvalidCheckDigits);
+        assert_eq!(err("XE7338O073KYGTWWZN0F2WZ0R8PX5ZPP-S"), IcapError::InvalidCharacter);
+        assert_eq!(
+            err("XE81ETHXREGGAVOFYORK").to_string(),
+            "Invalid ICAP address: indirect ICAP is not supported"
+        );
+
+        // 31 base-36 characters can encode values larger than 160 bits
+        let bban = "Z".repeat(31);
+        let check = 98 - icap_mod97(bban.as_bytes().iter().chain(b"XE00")).unwrap();
+        assert_eq!(err(&format!("XE{check:02}{bban}")), IcapError::Overflow);
+    }
+
     // https://eips.ethereum.org/EIPS/eip-55
     #[test]
     fn checksum() {
@@ -9,6 +9,10 @@ use hex::FromHex;
 /// rlp encoding, decoding, and other type-level attributes for fixed-length
 /// byte arrays.
 ///
+/// Ordering compares the bytes lexicographically, which is the same as
+/// comparing them as big-endian unsigned integers, like Solidity's `<` on
+/// `bytesN` and `uintN`.
+///
 /// Users looking to prevent type-confusion between byte arrays of different
 /// lengths should use the [`wrap_fixed_bytes!`](crate::wrap_fixed_bytes) macro
 /// to create a new fixed-length byte array type.
@@ -64,6 +68,10 @@ impl<const N: usize> From<&mut [u8; N]> for FixedBytes<N> {
 
 /// Tries to create a `FixedBytes<N>` by copying from a slice `&[u8]`. Succeeds
 /// if `slice.len() == N`.
+///
+/// This returns core's [`TryFromSliceError`](core::array::TryFromSliceError),
+/// like the `[u8; N]` conversion. Use [`FixedBytes::try_from_slice`] for an
+/// error that says whether the slice is too short or too long.
 impl<const N: usize> TryFrom<&[u8]> for FixedBytes<N> {
     type Error = core::array::TryFromSliceError;
 
@@ -108,6 +116,71 @@ impl<'a, const N: usize> TryFrom<&'a mut [u8]> for &'a mut FixedBytes<N> {
     }
 }
 
+/// Tries to create a `FixedBytes<N>` from a byte vector `Vec<u8>`. Succeeds
+/// if `vec.len() == N`.
+///
+/// Unlike `TryFrom<&[u8]>`, this returns a [`FixedBytesLengthError`], like
+/// [`FixedBytes::try_from_slice`].
+impl<const N: usize> TryFrom<alloc::vec::Vec<u8>> for FixedBytes<N> {
+    type Error = FixedBytesLengthError;
+
+    #[inline]
+    fn try_from(vec: alloc::vec::Vec<u8>) -> Result<Self, Self::Error> {
+        Self::try_from_slice(&vec)
+    }
+}
+
+/// Error returned when converting a byte buffer of the wrong length into a
+/// fixed-length byte array.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum FixedBytesLengthError {
+    /// The buffer is shorter than the byte array.
+    TooShort {
+        /// The length of the byte array.
+        expected: usize,
+        /// The length of the buffer.
+        actual: usize,
+    },
+    /// The buffer is longer than the byte array.
+    TooLong {
+        /// The length of the byte array.
+        expected: usize,
+        /// The length of the buffer.
+        actual: usize,
+    },
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for FixedBytesLengthError {}
+
+impl fmt::Display for FixedBytesLengthError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            Self::TooShort { expected, actual } => {
+                write!(f, "too few bytes: expected {expected}, got {actual}")
+            }
+            Self::TooLong { expected, actual } => {
+                write!(f, "too many bytes: expected {expected}, got {actual}")
+            }
+        }
+    }
+}
+
+impl FixedBytesLengthError {
+    /// Checks that a buffer of length `actual` fits exactly into a byte array
+    /// of length `expected`.
+    #[inline]
+    const fn check(expected: usize, actual: usize) -> Result<(), Self> {
+        if actual < expected {
+            Err(Self::TooShort { expected, actual })
+        } else if actual > expected {
+            Err(Self::TooLong { expected, actual })
+        } else {
+            Ok(())
+        }
+    }
+}
+
 // Ideally this would be:
 // `impl<const N: usize> From<FixedBytes<N>> for Uint<N * 8>`
 // `impl<const N: usize> From<Uint<N / 8>> for FixedBytes<N>`
@@ -172,6 +245,23 @@ fixed_bytes_uint_conversions! {
 
 }
 
+// Conversions from narrower unsigned integers into a 32-byte word, e.g. for storage slots.
+macro_rules! b256_from_uint {
+    ($($int:ty),* $(,)?) => {$(
+        impl From<$int> for aliases::B256 {
+            /// Converts an unsigned integer into a 32-byte word by left-padding its big-endian
+            /// bytes with zeros. This is equivalent to converting through
+            /// [`U256`](aliases::U256).
+            #[inline]
+            fn from(value: $int) -> Self {
+                Self::from(aliases::U256::from(value))
+            }
+        }
+    )*};
+}
+
+b256_from_uint!(u64, u128);
+
 impl<const N: usize> From<FixedBytes<N>> for [u8; N] {
     #[inline]
     fn from(s: FixedBytes<N>) -> Self {
@@ -332,6 +422,14 @@ impl<const N: usize> rand::distributions::Distribution<FixedBytes<N>>
     }
 }
 
+#[cfg(feature = "subtle")]
+impl<const N: usize> subtle::ConstantTimeEq for FixedBytes<N> {
+    #[inline]
+    fn ct_eq(&self, other: &Self) -> subtle::Choice {
+        Self::ct_eq(self, other)
+    }
+}
+
 impl<const N: usize> FixedBytes<N> {
     /// Array of Zero bytes.
     pub const ZERO: Self = Self([0u8; N]);
@@ -358,6 +456,32 @@ impl<const N: usize> FixedBytes<N> {
         Self([byte; N])
     }
 
+    /// Parses a hex string, with or without the `0x` prefix, in any case.
+    ///
+    /// This is the same as the [`FromStr`](str::FromStr) implementation, but makes accepting bare
+    /// hex explicit at the call site.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the input is not valid hex, has an odd length, or does not decode to
+    /// exactly `N` bytes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use alloy_primitives::{b256, B256};
+    /// let expected = b256!("c0ffee000000000000000000000000000000000000000000000000000000beef");
+    /// let hex = "c0ffee000000000000000000000000000000000000000000000000000000beef";
+    /// assert_eq!(B256::from_str_lenient(hex), Ok(expected));
+    /// assert_eq!(B256::from_str_lenient(&format!("0x{hex}")), Ok(expected));
+    /// assert_eq!(B256::from_str_lenient(&hex.to_uppercase()), Ok(expected));
+    /// assert!(B256::from_str_lenient(&hex[1..]).is_err());
+    /// ```
+    #[inline]
+    pub fn from_str_lenient(s: &str) -> Result<Self, hex::FromHexError> {
+        hex::decode_to_array(s).map(Self)
+    }
+
     /// Returns the size of this byte array (`N`).
     #[inline(always)]
     pub const fn len_bytes() -> usize {
@@ -459,7 +583,7 @@ impl<const N: usize> FixedBytes<N> {
 
     /// Create a new [`FixedBytes`] from the given slice `src`.
     ///
-    /// For a fallible version, use the `TryFrom<&[u8]>` implementation.
+    /// For a fallible version, use [`try_from_slice`](Self::try_from_slice).
     ///
     /// # Note
     ///
@@ -477,6 +601,24 @@ impl<const N: usize> FixedBytes<N> {
         }
     }
 
+    /// Tries to create a new [`FixedBytes`] from the given slice `src`.
+    ///
+    /// This is the same as the `TryFrom<&[u8]>` implementation, except for the
+    /// error type.
+    ///
+    /// # Note
+    ///
+    /// The given bytes are interpreted in big endian order.
+    ///
+    /// # Errors
+    ///
+    /// If the length of `src` and the number of bytes in `Self` do not match.
+    #[inline]
+    pub fn try_from_slice(src: &[u8]) -> Result<Self, FixedBytesLengthError> {
+        FixedBytesLengthError::check(N, src.len())?;
+        Ok(Self::from_slice(src))
+    }
+
     /// Create a new [`FixedBytes`] from the given slice `src`, left-padding it
     /// with zeroes if necessary.
     ///
@@ -554,10 +696,21 @@ impl<const N: usize> FixedBytes<N> {
         true
     }
 
+    /// Constant-time equality.
+    ///
+    /// The time taken by this function depends only on `N`, and not on the
+    /// contents of either value. Prefer this over `==` when comparing secrets,
+    /// such as MACs or secret hashes.
+    #[cfg(feature = "subtle")]
+    #[inline]
+    pub fn ct_eq(&self, other: &Self) -> subtle::Choice {
+        subtle::ConstantTimeEq::ct_eq(&self.0[..], &other.0[..])
+    }
+
     /// Returns `true` if no bits are set.
     #[inline]
-    pub fn is_zero(&self) -> bool {
-        *self == Self::ZERO
+    pub const fn is_zero(&self) -> bool {
+        self.const_is_zero()
     }
 
     /// Returns `true` if no bits are set.
@@ -599,6 +752,17 @@ impl<const N: usize> FixedBytes<N> {
         ret
     }
 
+    /// Computes the bitwise NOT of a `FixedBytes`.
+    pub const fn bit_not(self) -> Self {
+        let mut ret = Self::ZERO;
+        let mut i = 0;
+        while i < N {
+            ret.0[i] = !self.0[i];
+            i += 1;
+        }
+        ret
+    }
+
     fn fmt_hex<const UPPER: bool>(&self, f: &mut fmt::Formatter<'_>, prefix: bool) -> fmt::Result {
         let mut buf = hex::Buffer::<N, true>::new();
         let s = if UPPER { buf.format_upper(self) } else { buf.format(self) };
@@ -607,10 +771,132 @@ impl<const N: usize> FixedBytes<N> {
     }
 }
 
+impl FixedBytes<32> {
+    /// Converts the bytes into four `u64` limbs, **least** significant limb
+    /// first.
+    ///
+    /// The bytes are interpreted as a big-endian 256-bit number, like in the
+    /// `U256` conversions. Each limb holds the numeric value of its 8 bytes,
+    /// so the result is the same as `U256::from_be_bytes(self.0).into_limbs()`.
+    #[inline]
+    pub const fn into_limbs_le(self) -> [u64; 4] {
+        let be = self.into_limbs_be();
+        [be[3], be[2], be[1], be[0]]
+    }
+
+    /// Converts the bytes into four `u64` limbs, **most** significant limb
+    /// first.
+    ///
+    /// The bytes are interpreted as a big-endian 256-bit number, like in the
+    /// `U256` conversions. Each limb holds the numeric value of its 8 bytes,
+    /// i.e. limb `i` is `u64::from_be_bytes(self[i * 8..(i + 1) * 8])`.
+    #[inline]
+    pub const fn into_limbs_be(self) -> [u64; 4] {
+        let mut limbs = [0u64; 4];
+        let mut i = 0;
+        while i < 32 {
+            limbs[i / 8] = (limbs[i / 8] << 8) | self.0[i] as u64;
+            i += 1;
+        }
+        limbs
+    }
+
+    /// Creates a new [`FixedBytes`] from four `u64` limbs, **least**
+    /// significant limb first.
+    ///
+    /// This is the inverse of [`into_limbs_le`](Self::into_limbs_le), and is
+    /// the same as `B256::from(U256::from_limbs(limbs))`.
+    #[inline]
+    pub const fn from_limbs_le(limbs: [u64; 4]) -> Self {
+        Self::from_limbs_be([limbs[3], limbs[2], limbs[1], limbs[0]])
+    }
+
+    /// Creates a new [`FixedBytes`] from four `u64` limbs, **most**
+    /// significant limb first.
+    ///
+    /// This is the inverse of [`into_limbs_be`](Self::into_limbs_be). Each
+    /// limb is written as 8 big-endian bytes.
+    #[inline]
+    pub const fn from_limbs_be(limbs: [u64; 4]) -> Self {
+        let mut bytes = [0u8; 32];
+        let mut i = 0;
+        while i < 4 {
+            let limb = limbs[i].to_be_bytes();
+            let mut j = 0;
+            while j < 8 {
+                bytes[i * 8 + j] = limb[j];
+                j += 1;
+            }
+            i += 1;
+        }
+        Self(bytes)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn ord() {
+        let a = FixedBytes::<32>::with_last_byte(0xff);
+        let b = FixedBytes::<32>::left_padding_from(&[1, 0]);
+        assert!(a < b);
+        assert_eq!(
+            a.cmp(&b),
+            aliases::U256::from_be_bytes(a.0).cmp(&aliases::U256::from_be_bytes(b.0))
+        );
+        assert!(FixedBytes::<4>::ZERO < FixedBytes::<4>::repeat_byte(1));
+    }
+
+    #[test]
+    fn is_zero() {
+        const _: () = assert!(FixedBytes::<32>::ZERO.const_is_zero());
+        const _: () = assert!(FixedBytes::<32>::ZERO.is_zero());
+        const _: () = assert!(!FixedBytes::<4>::repeat_byte(1).is_zero());
+        assert!(FixedBytes::<32>::ZERO.is_zero());
+        assert!(FixedBytes::<0>::ZERO.is_zero());
+        assert!(!FixedBytes::<32>::with_last_byte(1).is_zero());
+        assert!(!FixedBytes::<4>::repeat_byte(0x80).const_is_zero());
+    }
+
+    #[test]
+    fn try_from_len() {
+        use crate::B256;
+
+        let bytes = [0xab; 32];
+        assert_eq!(B256::try_from(&bytes[..]).unwrap(), B256::repeat_byte(0xab));
+        assert_eq!(B256::try_from(bytes.to_vec()).unwrap(), B256::repeat_byte(0xab));
+
+        assert!(B256::try_from(&bytes[..31]).is_err());
+        assert_eq!(
+            B256::try_from_slice(&bytes[..31]),
+            Err(FixedBytesLengthError::TooShort { expected: 32, actual: 31 })
+        );
+        assert_eq!(
+            B256::try_from(vec![0; 31]),
+            Err(FixedBytesLengthError::TooShort { expected: 32, actual: 31 })
+        );
+        assert_eq!(
+            B256::try_from(Vec::new()),
+            Err(FixedBytesLengthError::TooShort { expected: 32, actual: 0 })
+        );
+
+        assert!(B256::try_from(&[0; 33][..]).is_err());
+        assert_eq!(
+            B256::try_from_slice(&[0; 33]),
+            Err(FixedBytesLengthError::TooLong { expected: 32, actual: 33 })
+        );
+        assert_eq!(
+            B256::try_from(vec![0; 33]),
+            Err(FixedBytesLengthError::TooLong { expected: 32, actual: 33 })
+        );
+        assert_eq!(
+            B256::try_from(vec![0; 33]).unwrap_err().to_string(),
+            "too many bytes: expected 32, got 33"
+        );
+    }
+
     macro_rules! test_fmt {
         ($($fmt:literal, $hex:literal => $expected:literal;)+) => {$(
             assert_eq!(
@@ -664,6 +950,31 @@ mod tests {
         }
     }
 
+    #[test]
+    #[cfg(feature = "subtle")]
+    fn ct_eq() {
+        let a = fixed_bytes!("0123456789abcdef");
+        assert!(bool::from(a.ct_eq(&fixed_bytes!("0123456789abcdef"))));
+        assert!(!bool::from(a.ct_eq(&fixed_bytes!("0123456789abcdee"))));
+        assert!(!bool::from(a.ct_eq(&fixed_bytes!("1123456789abcdef"))));
+        assert!(bool::from(FixedBytes::<0>::ZERO.ct_eq(&FixedBytes::ZERO)));
+    }
+
+    #[test]
+    fn from_str_lenient() {
+        let expected = FixedBytes::<4>::new([0xde, 0xad, 0xbe, 0xef]);
+        for s in ["deadbeef", "0xdeadbeef", "DEADBEEF", "0xDeAdBeEf"] {
+            assert_eq!(FixedBytes::<4>::from_str_lenient(s), Ok(expected), "{s}");
+            assert_eq!(s.parse::<FixedBytes<4>>(), Ok(expected), "{s}");
+        }
+        assert_eq!(FixedBytes::<4>::from_str_lenient("deadbee"), Err(hex::FromHexError::OddLength));
+        assert_eq!(
+            FixedBytes::<4>::from_str_lenient("0xdeadbeefff"),
+            Err(hex::FromHexError::InvalidStringLength)
+        );
+        assert_eq!(FixedBytes::<0>::from_str_lenient(""), Ok(FixedBytes::ZERO));
+    }
+
     #[test]
     fn left_padding_from() {
         assert_eq!(FixedBytes::<4>::left_padding_from(&[0x01, 0x23]), fixed_bytes!("00000123"));
@@ -695,4 +1006,131 @@ mod tests {
     fn right_padding_from_too_large() {
         FixedBytes::<4>::right_padding_from(&[0x01, 0x23, 0x45, 0x67, 0x89]);
     }
+
+    #[test]
+    fn b256_from_uint() {
+        use aliases::{B256, U256};
+
+        assert_eq!(B256::from(0u64), B256::ZERO);
+        assert_eq!(
+            B256::from(0x0123456789abcdefu64),
+            fixed_bytes!("0000000000000000000000000000000000000000000000000123456789abcdef")
+        );
+        assert_eq!(
+            B256::from(u128::MAX),
+            fixed_bytes!("00000000000000000000000000000000ffffffffffffffffffffffffffffffff")
+        );
+
+        for x in [0u64, 1, 0xff, 0x0123456789abcdef, u64::MAX] {
+            assert_eq!(B256::from(x), B256::from(U256::from(x)));
+            assert_eq!(U256::from_be_bytes(B256::from(x).0), U256::from(x));
+        }
+        for x in [0u128, 1, u64::MAX as u128 + 1, u128::MAX] {
+            assert_eq!(B256::from(x), B256::from(U256::from(x)));
+            assert_eq!(U256::from_be_bytes(B256::from(x).0), U256::from(x));
+        }
+    }
+
+    #[test]
+    fn limbs() {
+        use aliases::{B256, U256};
+
+        let b = fixed_bytes!("000102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f");
+        let be = [0x0001020304050607, 0x08090a0b0c0d0e0f, 0x1011121314151617, 0x18191a1b1c1d1e1f];
+        let le = [be[3], be[2], be[1], be[0]];
+        assert_eq!(b.into_limbs_be(), be);
+        assert_eq!(b.into_limbs_le(), le);
+        assert_eq!(FixedBytes::from_limbs_be(be), b);
+        assert_eq!(FixedBytes::from_limbs_le(le), b);
+
+        // consistent with `U256`'s limb layout
+        let u: U256 = b.into();
+        assert_eq!(u.as_limbs(), &le);
+        assert_eq!(FixedBytes::from_limbs_le(*u.as_limbs()), b);
+        assert_eq!(B256::from(U256::from_limbs(le)), b);
+
+        for b in [B256::ZERO, B256::repeat_byte(0xff), B256::with_last_byte(1)] {
+            let u: U256 = b.into();
+            assert_eq!(b.into_limbs_le(), u.into_limbs());
+            assert_eq!(FixedBytes::from_limbs_le(b.into_limbs_le()), b);
+            assert_eq!(FixedBytes::from_limbs_be(b.into_limbs_be()), b);
+        }
+        assert_eq!(B256::with_last_byte(1).into_limbs_le(), [1, 0, 0, 0]);
+        assert_eq!(B256::with_last_byte(1).into_limbs_be(), [0, 0, 0, 1]);
+    }
+
+    #[test]
+    fn bitwise_ops() {
+        use aliases::B256;
+
+        let a = fixed_bytes!("0123456789abcdef0123456789abcdef0123456789abcdef0123456789abcdef");
+        let b = fixed_bytes!("ff00ff00f0f0f0f00f0f0f0faaaaaaaa5555555500000000ffffffff12345678");
+
+        let bytewise = |f: fn(u8, u8) -> u8| {
+            let mut out = B256::ZERO;
+            for i in 0..32 {
+                out[i] = f(a[i], b[i]);
+            }
+            out
+        };
+        let and = bytewise(|x, y| x & y);
+        let or = bytewise(|x, y| x | y);
+        let xor = bytewise(|x, y| x ^ y);
+        let not = bytewise(|x, _| !x);
+
+        assert_eq!(a & b, and);
+        assert_eq!(a | b, or);
+        assert_eq!(a ^ b, xor);
+        assert_eq!(!a, not);
+
+        assert_eq!(a.bit_and(b), and);
+        assert_eq!(a.bit_or(b), or);
+        assert_eq!(a.bit_xor(b), xor);
+        assert_eq!(a.bit_not(), not);
+
+        let mut c = a;
+        c &= b;
+        assert_eq!(c, and);
+        let mut c = a;
+        c |= b;
+        assert_eq!(c, or);
+        let mut c = a;
+        c ^= b;
+        assert_eq!(c, xor);
+
+        // masking
+        assert_eq!(a & !b | a & b, a);
+        assert_eq!(a ^ b ^ b, a);
+        assert!((a | b).covers(&a));
+        assert!((a | b).const_covers(b));
+
+        // any length
+        assert_eq!(!FixedBytes::<0>::ZERO, FixedBytes::<0>::ZERO);
+        assert_eq!(!FixedBytes::<3>::ZERO, FixedBytes([0xff; 3]));
+        assert_eq!(FixedBytes([0x0f; 5]).bit_not(), FixedBytes([0xf0; 5]));
+
+        const MASK: B256 = B256::repeat_byte(0x0f).bit_and(B256::with_last_byte(0xff)).bit_not();
+        assert_eq!(MASK, !B256::with_last_byte(0x0f));
+    }
+
+    #[test]
+    fn b256_u256_big_endian() {
+        use aliases::{B256, U256};
+
+        let one = fixed_bytes!("0000000000000000000000000000000000000000000000000000000000000001");
+        // `Uint::from` is an inherent method, so go through `Into` for the trait impl.
+        let value: U256 = one.into();
+        assert_eq!(value, U256::from(1u64));
+        assert_eq!(B256::from(U256::from(1u64)), one);
+
+        let mut le_one = B256::ZERO;
+        le_one[0] = 1;
+        let value: U256 = le_one.into();
+        assert_eq!(value, U256::from(1u64) << 248);
+
+        for word in [B256::ZERO, one, le_one, B256::repeat_byte(0xab), B256::repeat_byte(0xff)] {
+            let value: U256 = word.into();
+            assert_eq!(B256::from(value), word);
+        }
+    }
 }
@@ -114,6 +114,16 @@ macro_rules! wrap_fixed_bytes {
             }
         }
 
+        impl $crate::private::TryFrom<$crate::private::Vec<u8>> for $name {
+            type Error = $crate::FixedBytesLengthError;
+
+            #[inline]
+            fn try_from(vec: $crate::private::Vec<u8>) -> Result<Self, Self::Error> {
+                <$crate::FixedBytes<$n> as $crate::private::TryFrom<$crate::private::Vec<u8>>>::try_from(vec)
+                    .map(Self)
+            }
+        }
+
         impl<'a> $crate::private::TryFrom<&'a [u8]> for &'a $name {
             type Error = $crate::private::core::array::TryFromSliceError;
 
@@ -210,12 +220,24 @@ macro_rules! wrap_fixed_bytes {
                 $n
             }
 
+            /// Returns `true` if no bits are set.
+            #[inline]
+            pub const fn is_zero(&self) -> bool {
+                self.0.is_zero()
+            }
+
+            /// Returns `true` if no bits are set.
+            #[inline]
+            pub const fn const_is_zero(&self) -> bool {
+                self.0.const_is_zero()
+            }
+
             $crate::impl_getrandom!();
             $crate::impl_rand!();
 
             /// Create a new byte array from the given slice `src`.
             ///
-            /// For a fallible version, use the `TryFrom<&[u8]>` implementation.
+            /// For a fallible version, use [`try_from_slice`](Self::try_from_slice).
             ///
             /// # Note
             ///
@@ -233,6 +255,25 @@ macro_rules! wrap_fixed_bytes {
                 }
             }
 
+            /// Tries to create a new byte array from the given slice `src`.
+            ///
+            /// This is the same as the `TryFrom<&[u8]>` implementation, except
+            /// for the error type, which says whether `src` is too short or too long.
+            ///
+            /// # Note
+            ///
+            /// The given bytes are interpreted in big endian order.
+            ///
+            /// # Errors
+            ///
+            /// If the length of `src` and the number of bytes in `Self` do not match.
+            #[inline]
+            pub fn try_from_slice(
+                src: &[u8],
+            ) -> $crate::private::Result<Self, $crate::FixedBytesLengthError> {
+                $crate::FixedBytes::try_from_slice(src).map(Self)
+            }
+
             /// Create a new byte array from the given slice `src`, left-padding it
             /// with zeroes if necessary.
             ///
@@ -242,7 +283,8 @@ macro_rules! wrap_fixed_bytes {
             ///
             /// # Panics
             ///
-            /// Panics if `src.len() > N`.
+            /// Panics if `src` is longer than this type, e.g. more than 20 bytes
+            /// for `Address`. The input is never truncated.
             #[inline]
             #[track_caller]
             pub fn left_padding_from(value: &[u8]) -> Self {
@@ -258,7 +300,8 @@ macro_rules! wrap_fixed_bytes {
             ///
             /// # Panics
             ///
-            /// Panics if `src.len() > N`.
+            /// Panics if `src` is longer than this type, e.g. more than 20 bytes
+            /// for `Address`. The input is never truncated.
             #[inline]
             #[track_caller]
             pub fn right_padding_from(value: &[u8]) -> Self {
@@ -296,6 +339,11 @@ macro_rules! wrap_fixed_bytes {
             pub const fn bit_xor(self, rhs: Self) -> Self {
                 Self(self.0.bit_xor(rhs.0))
             }
+
+            /// Computes the bitwise NOT of a `FixedBytes`.
+            pub const fn bit_not(self) -> Self {
+                Self(self.0.bit_not())
+            }
         }
     };
 }
@@ -2,13 +2,13 @@
 mod macros;
 
 mod address;
-pub use address::{Address, AddressChecksumBuffer, AddressError};
+pub use address::{Address, AddressChecksumBuffer, AddressError, IcapError};
 
 mod bloom;
 pub use bloom::{Bloom, BloomInput, BLOOM_BITS_PER_ITEM, BLOOM_SIZE_BITS, BLOOM_SIZE_BYTES};
 
 mod fixed;
-pub use fixed::FixedBytes;
+pub use fixed::{FixedBytes, FixedBytesLengthError};
 
 mod function;
 pub use function::Function;
@@ -16,6 +16,11 @@ impl<const N: usize> Serialize for FixedBytes<N> {
     }
 }
 
+/// Returns an "invalid length" error for input that has `len` instead of `expected` `unit`s.
+fn len_error<E: de::Error>(len: usize, expected: usize, unit: &str) -> E {
+    E::invalid_length(len, &format!("exactly {expected} {unit}").as_str())
+}
+
 impl<'de, const N: usize> Deserialize<'de> for FixedBytes<N> {
     fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
         struct FixedVisitor<const N: usize>;
@@ -33,27 +38,35 @@ impl<'de, const N: usize> Deserialize<'de> for FixedBytes<N> {
             }
 
             fn visit_bytes<E: de::Error>(self, v: &[u8]) -> Result<Self::Value, E> {
-                FixedBytes::try_from(v).map_err(de::Error::custom)
+                <[u8; N]>::try_from(v).map(FixedBytes).map_err(|_| len_error(v.len(), N, "bytes"))
             }
 
             fn visit_seq<A: de::SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
-                let len_error =
-                    |i| de::Error::invalid_length(i, &format!("exactly {N} bytes").as_str());
                 let mut bytes = [0u8; N];
 
                 for (i, byte) in bytes.iter_mut().enumerate() {
-                    *byte = seq.next_element()?.ok_or_else(|| len_error(i))?;
+                    *byte = seq.next_element()?.ok_or_else(|| len_error(i, N, "bytes"))?;
                 }
 
-                if let Ok(Some(_)) = seq.next_element::<u8>() {
-                    return Err(len_error(N + 1));
+                // count the remaining elements, whatever their type, for the error message
+                let mut len = N;
+                while seq.next_element::<de::IgnoredAny>()?.is_some() {
+                    len += 1;
+                }
+                if len != N {
+                    return Err(len_error(len, N, "bytes"));
                 }
 
                 Ok(FixedBytes(bytes))
             }
 
             fn visit_str<E: de::Error>(self, v: &str) -> Result<Self::Value, E> {
-                <FixedBytes<N> as hex::FromHex>::from_hex(v).map_err(de::Error::custom)
+                // check the length up front, so that the error says how long the string was
+                let digits = v.strip_prefix("0x").unwrap_or(v);
+                if digits.len() != N * 2 {
+                    return Err(len_error(digits.len(), N * 2, "hex digits"));
+                }
+                <FixedBytes<N> as hex::FromHex>::from_hex(digits).map_err(de::Error::custom)
             }
         }
 
@@ -109,4 +122,41 @@ mod tests {
         let bin = bincode::serialize(&bytes).unwrap();
         assert_eq!(bincode::deserialize::<FixedBytes<12>>(&bin).unwrap(), bytes);
     }
+
+    #[test]
+    fn serde_hex_prefix() {
+        let bytes = FixedBytes([0xab, 0xcd]);
+        assert_eq!(serde_json::from_str::<FixedBytes<2>>("\"0xabcd\"").unwrap(), bytes);
+        assert_eq!(serde_json::from_str::<FixedBytes<2>>("\"abcd\"").unwrap(), bytes);
+    }
+
+    #[test]
+    fn serde_wrong_length() {
+        let long = format!("\"0x{}\"", "ab".repeat(32));
+        let e = serde_json::from_str::<FixedBytes<20>>(&long).unwrap_err().to_string();
+        assert!(e.contains("invalid length 64, expected exactly 40 hex digits"), "{e}");
+
+        let short = format!("\"{}\"", "ab".repeat(19));
+        let e = serde_json::from_str::<FixedBytes<20>>(&short).unwrap_err().to_string();
+        assert!(e.contains("invalid length 38, expected exactly 40 hex digits"), "{e}");
+
+        let odd = format!("\"0x{}a\"", "ab".repeat(19));
+        assert!(serde_json::from_str::<FixedBytes<20>>(&odd).is_err());
+
+        let e = serde_json::from_str::<FixedBytes<2>>("[1, 2, \"3\"]").unwrap_err().to_string();
+        assert!(e.contains("invalid length 3, expected exactly 2 bytes"), "{e}");
+        let e = serde_json::from_str::<FixedBytes<2>>("[1]").unwrap_err().to_string();
+        assert!(e.contains("invalid length 1, expected exactly 2 bytes"), "{e}");
+    }
+
+    #[test]
+    fn bincode_wrong_length() {
+        let bin = bincode::serialize(&FixedBytes([1u8; 32])).unwrap();
+        let e = bincode::deserialize::<FixedBytes<20>>(&bin).unwrap_err().to_string();
+        assert!(e.contains("invalid length 32, expected exactly 20 bytes"), "{e}");
+
+        let bin = bincode::serialize(&FixedBytes([1u8; 4])).unwrap();
+        let e = bincode::deserialize::<FixedBytes<20>>(&bin).unwrap_err().to_string();
+        assert!(e.contains("invalid length 4, expected exactly 20 bytes"), "{e}");
+    }
 }
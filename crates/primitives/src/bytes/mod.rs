@@ -16,6 +16,24 @@ mod serde;
 mod ssz;
 
 /// Wrapper type around [`bytes::Bytes`] to support "0x" prefixed hex strings.
+///
+/// # Examples
+///
+/// ```
+/// use alloy_primitives::Bytes;
+///
+/// let bytes = Bytes::from(vec![0x01, 0x02, 0x03]);
+/// assert_eq!(bytes, Bytes::from([0x01, 0x02, 0x03]));
+/// assert_eq!(bytes, Bytes::from(&[0x01, 0x02, 0x03][..]));
+/// assert_eq!(bytes, Bytes::from(vec![0x01, 0x02, 0x03].into_boxed_slice()));
+///
+/// let mut sum = 0;
+/// for byte in &bytes {
+///     sum += *byte;
+/// }
+/// assert_eq!(sum, 6);
+/// assert_eq!(bytes.into_iter().collect::<Vec<u8>>(), [0x01, 0x02, 0x03]);
+/// ```
 #[derive(Clone, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[repr(transparent)]
 pub struct Bytes(pub bytes::Bytes);
@@ -165,6 +183,9 @@ impl<const N: usize> From<&'static [u8; N]> for Bytes {
     }
 }
 
+/// Borrows the slice without copying.
+///
+/// Use [`Bytes::copy_from_slice`] to create a `Bytes` from a non-`'static` slice.
 impl From<&'static [u8]> for Bytes {
     #[inline]
     fn from(value: &'static [u8]) -> Self {
@@ -317,7 +338,41 @@ impl Bytes {
         Self(bytes::Bytes::copy_from_slice(data))
     }
 
+    /// Returns `true` if the bytes are empty, which is the zero value of
+    /// `Bytes`.
+    ///
+    /// This is the same as [`is_empty`](bytes::Bytes::is_empty). Unlike
+    /// [`FixedBytes::is_zero`], non-empty bytes are never zero, even if all of
+    /// them are `0`.
+    ///
+    /// [`FixedBytes::is_zero`]: crate::FixedBytes::is_zero
+    #[inline]
+    pub fn is_zero(&self) -> bool {
+        self.is_empty()
+    }
+
     /// Returns a slice of self for the provided range.
+    ///
+    /// This is an O(1) operation that does not copy: the returned `Bytes`
+    /// shares the underlying memory with `self`, like with
+    /// [`bytes::Bytes::slice`]. An empty range returns an empty `Bytes` that
+    /// does not reference the original memory.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `begin > end` or `end > self.len()`, like
+    /// [`bytes::Bytes::slice`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use alloy_primitives::Bytes;
+    ///
+    /// let b = Bytes::from_static(b"hello world");
+    /// assert_eq!(b.slice(..5), Bytes::from_static(b"hello"));
+    /// assert_eq!(b.slice(6..), Bytes::from_static(b"world"));
+    /// assert!(b.slice(3..3).is_empty());
+    /// ```
     #[inline]
     pub fn slice(&self, range: impl RangeBounds<usize>) -> Self {
         Self(self.0.slice(range))
@@ -342,6 +397,29 @@ impl Bytes {
     pub fn split_to(&mut self, at: usize) -> Self {
         Self(self.0.split_to(at))
     }
+
+    /// Constant-time equality.
+    ///
+    /// Values of differing lengths are never equal. The time taken by this
+    /// function depends only on the lengths of both values: the common prefix
+    /// is always compared in full, so it does not leak how many of its bytes
+    /// match. The lengths themselves are considered public.
+    #[cfg(feature = "subtle")]
+    #[inline]
+    pub fn ct_eq(&self, other: &Self) -> subtle::Choice {
+        use subtle::ConstantTimeEq;
+        let len = self.len().min(other.len());
+        let len_eq = (self.len() as u64).ct_eq(&(other.len() as u64));
+        len_eq & self[..len].ct_eq(&other[..len])
+    }
+}
+
+#[cfg(feature = "subtle")]
+impl subtle::ConstantTimeEq for Bytes {
+    #[inline]
+    fn ct_eq(&self, other: &Self) -> subtle::Choice {
+        Self::ct_eq(self, other)
+    }
 }
 
 #[cfg(feature = "arbitrary")]
@@ -384,6 +462,14 @@ impl proptest::arbitrary::Arbitrary for Bytes {
 mod tests {
     use super::*;
 
+    #[test]
+    fn is_zero() {
+        assert!(Bytes::new().is_zero());
+        assert!(Bytes::from_static(&[]).is_zero());
+        assert!(!Bytes::from_static(&[0, 0]).is_zero());
+        assert!(!Bytes::from_static(&[0, 1]).is_zero());
+    }
+
     #[test]
     fn parse() {
         let expected = Bytes::from_static(&[0x12, 0x13, 0xab, 0xcd]);
@@ -404,4 +490,42 @@ mod tests {
         assert_eq!(format!("{b:X}"), "0x0123456789ABCDEF");
         assert_eq!(format!("{b:#X}"), "0x0123456789ABCDEF");
     }
+
+    #[test]
+    fn slice_shares_memory() {
+        let b = Bytes::from(vec![1, 2, 3, 4, 5]);
+        let slice = b.slice(1..4);
+        assert_eq!(slice, [2, 3, 4][..]);
+        assert_eq!(slice.as_ptr(), b[1..].as_ptr());
+        assert_eq!(b.slice(..).as_ptr(), b.as_ptr());
+
+        let empty = b.slice(2..2);
+        assert!(empty.is_empty());
+        assert_eq!(empty, Bytes::new());
+    }
+
+    #[test]
+    #[should_panic]
+    fn slice_out_of_bounds() {
+        let _ = Bytes::from(vec![1, 2, 3]).slice(1..4);
+    }
+
+    #[test]
+    #[should_panic]
+    fn slice_reversed() {
+        #[allow(clippy::reversed_empty_ranges)]
+        let _ = Bytes::from(vec![1, 2, 3]).slice(2..1);
+    }
+
+    #[test]
+    #[cfg(feature = "subtle")]
+    fn ct_eq() {
+        let a = Bytes::from_static(b"secret");
+        assert!(bool::from(a.ct_eq(&Bytes::from_static(b"secret"))));
+        assert!(!bool::from(a.ct_eq(&Bytes::from_static(b"secreT"))));
+        assert!(!bool::from(a.ct_eq(&Bytes::from_static(b"secret!"))));
+        assert!(!bool::from(a.ct_eq(&Bytes::from_static(b"secre"))));
+        assert!(!bool::from(a.ct_eq(&Bytes::new())));
+        assert!(bool::from(Bytes::new().ct_eq(&Bytes::new())));
+    }
 }
@@ -0,0 +1,136 @@
+use crate::Uint;
+use alloc::string::{String, ToString};
+use core::fmt;
+
+/// Explicit decimal string conversions for unsigned integers, such as
+/// [`U256`](crate::U256).
+///
+/// [`Uint`]'s [`FromStr`](core::str::FromStr) implementation also accepts
+/// `0x`, `0o` and `0b` prefixed strings, which makes it ambiguous for input
+/// that is meant to be decimal. These methods only ever parse decimal digits.
+///
+/// # Examples
+///
+/// ```
+/// use alloy_primitives::{UintDecimal, U256};
+///
+/// let value = U256::from_dec_str("0010")?;
+/// assert_eq!(value, U256::from(10));
+/// assert_eq!(value.to_dec_string(), "10");
+///
+/// // `FromStr` parses this as hex
+/// assert_eq!("0x10".parse::<U256>()?, U256::from(16));
+/// assert!(U256::from_dec_str("0x10").is_err());
+/// # Ok::<_, Box<dyn std::error::Error>>(())
+/// ```
+pub trait UintDecimal: Sized {
+    /// Convert from a decimal string.
+    ///
+    /// Leading zeros are accepted, but signs are not.
+    fn from_dec_str(value: &str) -> Result<Self, ParseDecimalError>;
+
+    /// Convert to a decimal string.
+    fn to_dec_string(&self) -> String;
+}
+
+impl<const BITS: usize, const LIMBS: usize> UintDecimal for Uint<BITS, LIMBS> {
+    #[inline]
+    fn from_dec_str(value: &str) -> Result<Self, ParseDecimalError> {
+        if value.is_empty() {
+            return Err(ParseDecimalError::Empty);
+        }
+        Self::from_str_radix(value, 10).map_err(ParseDecimalError::Ruint)
+    }
+
+    #[inline]
+    fn to_dec_string(&self) -> String {
+        self.to_string()
+    }
+}
+
+/// The error type that is returned by [`UintDecimal::from_dec_str`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ParseDecimalError {
+    /// Error that occurs when an invalid digit is encountered while parsing,
+    /// or when the number does not fit in the target integer.
+    Ruint(ruint::ParseError),
+
+    /// Error that occurs when the string is empty.
+    Empty,
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ParseDecimalError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Ruint(err) => Some(err),
+            Self::Empty => None,
+        }
+    }
+}
+
+impl fmt::Display for ParseDecimalError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Ruint(e) => e.fmt(f),
+            Self::Empty => f.write_str("cannot parse integer from empty string"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{I256, U256};
+    use ruint::{BaseConvertError, ParseError};
+
+    #[test]
+    fn uint_dec_str() {
+        assert_eq!(U256::from_dec_str("0"), Ok(U256::ZERO));
+        assert_eq!(U256::from_dec_str("000123"), Ok(U256::from(123)));
+        assert_eq!(U256::from_dec_str(&U256::MAX.to_dec_string()), Ok(U256::MAX));
+        assert_eq!(U256::MAX.to_dec_string(), U256::MAX.to_string());
+
+        assert_eq!(U256::from_dec_str(""), Err(ParseDecimalError::Empty));
+        assert_eq!(
+            U256::from_dec_str("+1"),
+            Err(ParseDecimalError::Ruint(ParseError::InvalidDigit('+')))
+        );
+        assert!(U256::from_dec_str("-1").is_err());
+        assert_eq!(
+            U256::from_dec_str(&alloc::format!("{}0", U256::MAX)),
+            Err(ParseDecimalError::Ruint(ParseError::BaseConvertError(BaseConvertError::Overflow)))
+        );
+
+        // decimal only, unlike `FromStr`
+        assert_eq!("0x10".parse::<U256>(), Ok(U256::from(16)));
+        assert_eq!(
+            U256::from_dec_str("0x10"),
+            Err(ParseDecimalError::Ruint(ParseError::BaseConvertError(
+                BaseConvertError::InvalidDigit(33, 10)
+            )))
+        );
+        assert!(U256::from_dec_str("ff").is_err());
+        assert!(U256::from_dec_str("0b1").is_err());
+    }
+
+    #[test]
+    fn int_dec_str() {
+        let twelve = I256::try_from(12).unwrap();
+        assert_eq!(I256::from_dec_str("12"), Ok(twelve));
+        assert_eq!(I256::from_dec_str("+0012"), Ok(twelve));
+        assert_eq!(I256::from_dec_str("-0012"), Ok(-twelve));
+        assert_eq!(I256::from_dec_str("-0"), Ok(I256::ZERO));
+        assert_eq!(I256::from_dec_str(&I256::MIN.to_dec_string()), Ok(I256::MIN));
+        assert_eq!(I256::from_dec_str(&I256::MAX.to_dec_string()), Ok(I256::MAX));
+        assert_eq!((-twelve).to_dec_string(), "-12");
+        assert_eq!(twelve.to_dec_string(), "12");
+
+        assert!(I256::from_dec_str("--1").is_err());
+
+        // decimal only, unlike `from_hex_str`
+        assert_eq!(I256::from_hex_str("-0x10"), Ok(-I256::try_from(16).unwrap()));
+        assert!(I256::from_dec_str("-0x10").is_err());
+        assert!(I256::from_dec_str("ff").is_err());
+    }
+}
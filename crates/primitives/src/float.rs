@@ -0,0 +1,117 @@
+use crate::{BigIntConversionError, Uint};
+
+/// Explicit floating point conversions for unsigned integers, such as
+/// [`U256`](crate::U256).
+///
+/// [`Uint`]'s `TryFrom<f64>` implementation rounds fractional values to the
+/// nearest integer. [`try_from_f64`](Self::try_from_f64) instead rejects any
+/// value that is not exactly an integer in range.
+///
+/// # Examples
+///
+/// ```
+/// use alloy_primitives::{UintFloat, U256};
+///
+/// assert_eq!(U256::try_from_f64(1e18), Ok(U256::from(10u64.pow(18))));
+/// assert!(U256::try_from_f64(1.5).is_err());
+/// assert!(U256::try_from_f64(-1.0).is_err());
+///
+/// assert_eq!(U256::from(10u64.pow(18)).to_f64_lossy(), 1e18);
+/// ```
+pub trait UintFloat: Sized {
+    /// Approximates `self` as a double precision float.
+    ///
+    /// Precision is lost for values above 2^53. Values too large to be
+    /// represented saturate to positive infinity, but this can only happen for
+    /// integers wider than 1024 bits: [`U256::MAX`](crate::U256::MAX) converts to
+    /// a finite value close to 2^256.
+    fn to_f64_lossy(&self) -> f64;
+
+    /// Converts a double precision float to an unsigned integer, returning an
+    /// error if the float is NaN, infinite, negative, has a fractional part, or is
+    /// out of range.
+    ///
+    /// Negative zero is converted to zero.
+    fn try_from_f64(value: f64) -> Result<Self, BigIntConversionError>;
+}
+
+impl<const BITS: usize, const LIMBS: usize> UintFloat for Uint<BITS, LIMBS> {
+    #[inline]
+    fn to_f64_lossy(&self) -> f64 {
+        f64::from(self)
+    }
+
+    #[inline]
+    fn try_from_f64(value: f64) -> Result<Self, BigIntConversionError> {
+        if !value.is_finite() || value.fract() != 0.0 || value < 0.0 {
+            return Err(BigIntConversionError);
+        }
+        if value == 0.0 {
+            return Ok(Self::ZERO);
+        }
+
+        // `TryFrom<f64>` rounds by adding 0.5, which is not exact above 2^52, so the integer is
+        // rebuilt from the float's mantissa and exponent: `value = mantissa * 2^(exponent - 1075)`.
+        let bits = value.to_bits();
+        let exponent = ((bits >> 52) & 0x7ff) as usize;
+        let mantissa = (bits & ((1 << 52) - 1)) | (1 << 52);
+        if exponent < 1075 {
+            // `value` is an integer, so the shifted out bits are all zero
+            Self::try_from(mantissa >> (1075 - exponent)).map_err(|_| BigIntConversionError)
+        } else {
+            Self::try_from(mantissa)
+                .ok()
+                .and_then(|mantissa| mantissa.checked_shl(exponent - 1075))
+                .ok_or(BigIntConversionError)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{U128, U256, U64, U8};
+
+    #[test]
+    fn uint_to_f64_lossy() {
+        assert_eq!(U256::ZERO.to_f64_lossy(), 0.0);
+        assert_eq!(U256::from(12345).to_f64_lossy(), 12345.0);
+        assert_eq!(U64::MAX.to_f64_lossy(), 2f64.powi(64));
+
+        let max = U256::MAX.to_f64_lossy();
+        assert!(max.is_finite());
+        assert_eq!(max, 2f64.powi(256));
+    }
+
+    #[test]
+    fn uint_try_from_f64() {
+        assert_eq!(U256::try_from_f64(0.0), Ok(U256::ZERO));
+        assert_eq!(U256::try_from_f64(-0.0), Ok(U256::ZERO));
+        assert_eq!(U256::try_from_f64(12345.0), Ok(U256::from(12345)));
+        assert_eq!(U256::try_from_f64(2f64.powi(200)), Ok(U256::from(1) << 200));
+        assert_eq!(U128::try_from_f64(2f64.powi(127)), Ok(U128::from(1) << 127));
+        assert_eq!(U256::try_from_f64(9007199254740991.0), Ok(U256::from(9007199254740991u64)));
+        assert_eq!(U8::try_from_f64(255.0), Ok(U8::from(255)));
+        assert_eq!(U8::try_from_f64(256.0), Err(BigIntConversionError));
+
+        for value in [0.5, 1.5, -1.0, -0.5, f64::NAN, f64::INFINITY, f64::NEG_INFINITY] {
+            assert_eq!(U256::try_from_f64(value), Err(BigIntConversionError), "{value}");
+        }
+        // out of range
+        assert_eq!(U256::try_from_f64(2f64.powi(256)), Err(BigIntConversionError));
+        assert_eq!(U64::try_from_f64(2f64.powi(64)), Err(BigIntConversionError));
+    }
+
+    #[test]
+    fn uint_f64_roundtrip() {
+        let values = [
+            U256::from(1),
+            U256::from(u64::MAX >> 11),
+            U256::from(u64::MAX >> 11) << 100,
+            U256::from(1) << 255,
+        ];
+        for value in values {
+            assert_eq!(U256::try_from_f64(value.to_f64_lossy()), Ok(value));
+        }
+    }
+}
@@ -13,6 +13,9 @@
 extern crate alloc;
 
 use tiny_keccak as _;
+// `tiny-keccak` takes precedence over `asm-keccak` when both are enabled.
+#[cfg(all(feature = "asm-keccak", any(feature = "tiny-keccak", miri)))]
+use keccak_asm as _;
 
 #[cfg(feature = "postgres")]
 pub mod postgres;
@@ -28,10 +31,19 @@ pub use aliases::{
 #[macro_use]
 mod bits;
 pub use bits::{
-    Address, AddressChecksumBuffer, AddressError, Bloom, BloomInput, FixedBytes, Function,
-    BLOOM_BITS_PER_ITEM, BLOOM_SIZE_BITS, BLOOM_SIZE_BYTES,
+    Address, AddressChecksumBuffer, AddressError, Bloom, BloomInput, FixedBytes,
+    FixedBytesLengthError, Function, IcapError, BLOOM_BITS_PER_ITEM, BLOOM_SIZE_BITS,
+    BLOOM_SIZE_BYTES,
 };
 
+mod decimal;
+pub use decimal::{ParseDecimalError, UintDecimal};
+
+#[cfg(feature = "std")]
+mod float;
+#[cfg(feature = "std")]
+pub use float::UintFloat;
+
 #[path = "bytes/mod.rs"]
 mod bytes_;
 pub use self::bytes_::Bytes;
@@ -47,6 +59,9 @@ mod log_serde;
 mod sealed;
 pub use sealed::{Sealable, Sealed};
 
+mod slice;
+pub use slice::UintSlice;
+
 mod signed;
 pub use signed::{BigIntConversionError, ParseSignedError, Sign, Signed};
 
@@ -68,12 +83,47 @@ pub type Signature = signature::Signature<k256::ecdsa::Signature>;
 pub type Signature = signature::Signature<()>;
 
 pub mod utils;
-pub use utils::{eip191_hash_message, keccak256, Keccak256};
+pub use utils::{eip191_hash_message, keccak256, keccak256_const, Keccak256};
+
+/// Hex encoding and decoding, re-exported from [`const-hex`](https://docs.rs/const-hex).
+///
+/// This is the hex API used by this crate, and should be preferred over depending on a
+/// hex crate directly, so that the versions always match. It is `no_std` compatible; the
+/// functions returning owned values require the `alloc` feature, which this crate always
+/// enables.
+///
+/// [`decode`](hex::decode) and [`decode_to_slice`](hex::decode_to_slice) accept input both
+/// with and without a `0x` prefix, like the [`FromStr`](core::str::FromStr) implementations
+/// of the types in this crate.
+///
+/// # Examples
+///
+/// ```
+/// use alloy_primitives::{hex, Address};
+///
+/// assert_eq!(hex::encode([0xde, 0xad]), "dead");
+/// assert_eq!(hex::encode_prefixed([0xde, 0xad]), "0xdead");
+/// assert_eq!(hex::encode_prefixed([]), "0x");
+///
+/// assert_eq!(hex::decode("0xdead")?, hex::decode("dead")?);
+/// assert_eq!(hex::decode("0x")?, Vec::<u8>::new());
+///
+/// let mut buf = [0u8; 20];
+/// let s = "0x0000000000000000000000000000000000000001";
+/// hex::decode_to_slice(s, &mut buf)?;
+/// assert_eq!(Address::from(buf), s.parse::<Address>()?);
+///
+/// use hex::{FromHex, ToHex};
+/// let bytes = <[u8; 2]>::from_hex("0xbeef")?;
+/// assert_eq!(bytes.encode_hex::<String>(), "beef");
+/// # Ok::<_, Box<dyn std::error::Error>>(())
+/// ```
+#[doc(no_inline)]
+pub use ::hex;
 
 #[doc(no_inline)]
 pub use {
     ::bytes,
-    ::hex,
     hex_literal::{self, hex},
     ruint::{self, uint, Uint},
 };
@@ -163,6 +163,31 @@ where
     }
 }
 
+impl<T: IntoLogData> Log<T> {
+    /// Consumes the log, converting its data into a raw [`LogData`] while
+    /// keeping the address.
+    ///
+    /// This is useful to re-emit a decoded log, e.g. a
+    /// `Log<MyEvent>`, in its raw form.
+    #[inline]
+    pub fn into_raw(self) -> Log {
+        Log { address: self.address, data: self.data.into_log_data() }
+    }
+}
+
+/// Note that the address is dropped. Use [`Log::into_raw`] to keep it.
+impl<T: IntoLogData> IntoLogData for Log<T> {
+    #[inline]
+    fn to_log_data(&self) -> LogData {
+        self.data.to_log_data()
+    }
+
+    #[inline]
+    fn into_log_data(self) -> LogData {
+        self.data.into_log_data()
+    }
+}
+
 #[cfg(feature = "rlp")]
 impl alloy_rlp::Encodable for Log {
     fn encode(&self, out: &mut dyn alloy_rlp::BufMut) {
@@ -127,4 +127,56 @@ mod tests {
 
         assert_eq!(expected, dumped);
     }
+
+    #[test]
+    fn test_log_data_json_empty() {
+        let data = LogData::new(vec![], Bytes::new()).unwrap();
+        let json = serde_json::to_string(&data).unwrap();
+        assert_eq!(json, r#"{"topics":[],"data":"0x"}"#);
+        assert_eq!(serde_json::from_str::<LogData>(&json).unwrap(), data);
+    }
+
+    #[test]
+    fn test_log_json_rpc() {
+        // an ERC-20 `Transfer` log in the shape returned by `eth_getLogs`
+        let rpc = r#"{
+            "address": "0xa0b86991c6218b36c1d19d4a2e9eb0ce3606eb48",
+            "topics": [
+                "0xddf252ad1be2c89b69c2b068fc378daa952ba7f163c4a11628f55a4df523b3ef",
+                "0x0000000000000000000000001111111111111111111111111111111111111111",
+                "0x0000000000000000000000002222222222222222222222222222222222222222"
+            ],
+            "data": "0x00000000000000000000000000000000000000000000000000000000000f4240",
+            "blockNumber": "0x112a880",
+            "transactionHash": "0x5c504ed432cb51138bcf09aa5e8a410dd4a1e204ef84bfed1be16dfba1b22060",
+            "transactionIndex": "0x0",
+            "blockHash": "0x1d59ff54b1eb26b013ce3cb5fc9dab3705b415a67127a003c3e61eb445bb8df2",
+            "logIndex": "0x0",
+            "removed": false
+        }"#;
+
+        let log: Log = serde_json::from_str(rpc).unwrap();
+        assert_eq!(log.address, address!("a0b86991c6218b36c1d19d4a2e9eb0ce3606eb48"));
+        assert_eq!(log.topics().len(), 3);
+        assert_eq!(
+            log.topics()[0],
+            b256!("ddf252ad1be2c89b69c2b068fc378daa952ba7f163c4a11628f55a4df523b3ef")
+        );
+        assert_eq!(log.data.data, Bytes::from(crate::U256::from(1_000_000).to_be_bytes::<32>()));
+
+        let json = serde_json::to_value(&log).unwrap();
+        assert_eq!(
+            json,
+            serde_json::json!({
+                "address": "0xa0b86991c6218b36c1d19d4a2e9eb0ce3606eb48",
+                "topics": [
+                    "0xddf252ad1be2c89b69c2b068fc378daa952ba7f163c4a11628f55a4df523b3ef",
+                    "0x0000000000000000000000001111111111111111111111111111111111111111",
+                    "0x0000000000000000000000002222222222222222222222222222222222222222"
+                ],
+                "data": "0x00000000000000000000000000000000000000000000000000000000000f4240"
+            })
+        );
+        assert_eq!(serde_json::from_value::<Log>(json).unwrap(), log);
+    }
 }
@@ -50,6 +50,19 @@ use ruint::{BaseConvertError, Uint};
 /// assert_eq!(I256::ONE, I256::unchecked_from(1));
 /// assert_eq!(I256::MINUS_ONE, I256::unchecked_from(-1));
 /// ```
+///
+/// ## Formatting
+///
+/// The binary, octal and hexadecimal formatters print the two's complement
+/// bit pattern, like the primitive signed integers, and respect the formatter's
+/// width, fill and alternate flags:
+///
+/// ```
+/// # use alloy_primitives::I256;
+/// let n = I256::MINUS_ONE;
+/// assert_eq!(format!("{n:#x}"), format!("0x{}", "f".repeat(64)));
+/// assert_eq!(format!("{:#010x}", I256::unchecked_from(0xab)), "0x000000ab");
+/// ```
 #[derive(Clone, Copy, Default, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "arbitrary", derive(derive_arbitrary::Arbitrary, proptest_derive::Arbitrary))]
 pub struct Signed<const BITS: usize, const LIMBS: usize>(pub(crate) Uint<BITS, LIMBS>);
@@ -336,6 +349,20 @@ impl<const BITS: usize, const LIMBS: usize> Signed<BITS, LIMBS> {
     }
 
     /// Convert from a decimal string.
+    ///
+    /// The string may start with a `+` or `-` sign, and may have leading
+    /// zeros. Unlike [`FromStr`](core::str::FromStr), it is always parsed as
+    /// decimal, so prefixes such as `0x` are rejected.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use alloy_primitives::I256;
+    ///
+    /// assert_eq!(I256::from_dec_str("-0012"), Ok(I256::try_from(-12).unwrap()));
+    /// assert_eq!(I256::from_dec_str("+12"), Ok(I256::try_from(12).unwrap()));
+    /// assert!(I256::from_dec_str("0x12").is_err());
+    /// ```
     pub fn from_dec_str(value: &str) -> Result<Self, ParseSignedError> {
         let (sign, value) = match value.as_bytes().first() {
             Some(b'+') => (Sign::Positive, &value[1..]),
@@ -347,6 +374,8 @@ impl<const BITS: usize, const LIMBS: usize> Signed<BITS, LIMBS> {
     }
 
     /// Convert to a decimal string.
+    ///
+    /// Negative numbers are prefixed with `-`.
     pub fn to_dec_string(&self) -> String {
         let sign = self.sign();
         let abs = self.unsigned_abs();
@@ -380,6 +409,41 @@ impl<const BITS: usize, const LIMBS: usize> Signed<BITS, LIMBS> {
         format!("{sign}0x{abs:x}")
     }
 
+    /// Approximates `self` as a double precision float.
+    ///
+    /// Precision is lost for values whose magnitude exceeds 2^53. Values too large to be
+    /// represented are converted to positive or negative infinity, but this can only happen for
+    /// integers wider than 1024 bits.
+    ///
+    /// For unsigned integers, use [`UintFloat::to_f64_lossy`](crate::UintFloat::to_f64_lossy).
+    #[cfg(feature = "std")]
+    #[inline]
+    pub fn to_f64_lossy(&self) -> f64 {
+        let (sign, abs) = self.into_sign_and_abs();
+        let abs = f64::from(&abs);
+        if sign.is_negative() {
+            -abs
+        } else {
+            abs
+        }
+    }
+
+    /// Converts a double precision float to a signed integer, returning an error if the float is
+    /// not finite, has a fractional part, or is out of range.
+    ///
+    /// For unsigned integers, use [`UintFloat::try_from_f64`](crate::UintFloat::try_from_f64).
+    #[cfg(feature = "std")]
+    pub fn try_from_f64(value: f64) -> Result<Self, super::BigIntConversionError> {
+        use super::BigIntConversionError;
+
+        if !value.is_finite() || value.fract() != 0.0 {
+            return Err(BigIntConversionError);
+        }
+        let sign = if value.is_sign_negative() { Sign::Negative } else { Sign::Positive };
+        let abs = <Uint<BITS, LIMBS> as crate::UintFloat>::try_from_f64(value.abs())?;
+        Self::checked_from_sign_and_abs(sign, abs).ok_or(BigIntConversionError)
+    }
+
     /// Splits a Signed into its absolute value and negative flag.
     #[inline]
     pub fn into_sign_and_abs(&self) -> (Sign, Uint<BITS, LIMBS>) {
@@ -448,6 +512,60 @@ impl<const BITS: usize, const LIMBS: usize> Signed<BITS, LIMBS> {
         Self(Uint::from_le_bytes::<BYTES>(bytes))
     }
 
+    /// Convert from an array in BE format, sign-extending from bit `bits - 1`.
+    ///
+    /// Only the lowest `bits` bits of the array are used: the bits above them
+    /// are replaced with the sign bit, which is bit `bits - 1`. This is useful
+    /// to decode narrower integers, like an ABI-encoded `int24`, from a word.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `bits` is zero or greater than `BITS`, or in the same cases as
+    /// [`from_be_bytes`](Self::from_be_bytes).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use alloy_primitives::I256;
+    ///
+    /// let mut word = [0u8; 32];
+    /// word[29..].copy_from_slice(&[0xff, 0xff, 0xff]);
+    /// assert_eq!(I256::from_be_bytes_signed(word, 24), I256::MINUS_ONE);
+    /// assert_eq!(I256::from_be_bytes_signed(word, 32), I256::try_from(0xff_ffff).unwrap());
+    /// ```
+    #[inline]
+    #[track_caller]
+    pub fn from_be_bytes_signed<const BYTES: usize>(bytes: [u8; BYTES], bits: usize) -> Self {
+        Self::from_be_bytes(bytes).sign_extend(bits)
+    }
+
+    /// Sign-extends this integer from bit `bits - 1`.
+    ///
+    /// The bits above the lowest `bits` bits are replaced with bit `bits - 1`,
+    /// which is treated as the sign bit of a `bits`-bit integer.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `bits` is zero or greater than `BITS`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use alloy_primitives::I256;
+    ///
+    /// let x = I256::try_from(0x80).unwrap();
+    /// assert_eq!(x.sign_extend(8), I256::try_from(-128).unwrap());
+    /// assert_eq!(x.sign_extend(16), x);
+    /// ```
+    #[inline]
+    #[track_caller]
+    #[must_use]
+    pub fn sign_extend(self, bits: usize) -> Self {
+        assert!(bits != 0 && bits <= BITS, "cannot sign-extend from {bits} bits");
+        let shift = BITS - bits;
+        Self(self.0 << shift).asr(shift)
+    }
+
     /// Creates a new integer from a big endian slice of bytes.
     ///
     /// The slice is interpreted as a big endian number. Leading zeros
@@ -460,12 +578,12 @@ impl<const BITS: usize, const LIMBS: usize> Signed<BITS, LIMBS> {
 
     /// Creates a new integer from a little endian slice of bytes.
     ///
-    /// The slice is interpreted as a big endian number. Leading zeros
-    /// are ignored. The slice can be any length.
+    /// The slice is interpreted as a little endian number.
     ///
-    /// Returns [`None`] if the value is larger than fits the [`Uint`].
+    /// Returns [`None`] if the slice is longer than the [`Uint`], or if the
+    /// value is larger than fits the [`Uint`].
     pub fn try_from_le_slice(slice: &[u8]) -> Option<Self> {
-        Uint::try_from_le_slice(slice).map(Self)
+        <Uint<BITS, LIMBS> as crate::UintSlice>::checked_from_le_slice(slice).map(Self)
     }
 
     /// View the array of limbs.
@@ -528,6 +646,19 @@ mod tests {
     type I96 = Signed<96, 2>;
     type U96 = Uint<96, 2>;
 
+    #[test]
+    fn is_zero_positive_negative() {
+        const _: () = assert!(I256::ZERO.is_zero());
+        let checks = |x: I256| (x.is_zero(), x.is_positive(), x.is_negative());
+        assert_eq!(checks(I256::ZERO), (true, false, false));
+        assert_eq!(checks(I256::ONE), (false, true, false));
+        assert_eq!(checks(I256::MINUS_ONE), (false, false, true));
+        assert_eq!(checks(I256::MAX), (false, true, false));
+        assert_eq!(checks(I256::MIN), (false, false, true));
+        assert!(U256::ZERO.is_zero());
+        assert!(!U256::MAX.is_zero());
+    }
+
     #[test]
     fn identities() {
         macro_rules! test_identities {
@@ -616,6 +747,33 @@ mod tests {
         run_test!(I256, U256);
     }
 
+    #[test]
+    #[cfg(feature = "std")]
+    fn f64_conversion() {
+        assert_eq!(I256::ZERO.to_f64_lossy(), 0.0);
+        assert_eq!(I256::MINUS_ONE.to_f64_lossy(), -1.0);
+        assert_eq!(I256::try_from(1234567).unwrap().to_f64_lossy(), 1234567.0);
+        assert_eq!(I256::MAX.to_f64_lossy(), 2f64.powi(255));
+        assert_eq!(I256::MIN.to_f64_lossy(), -(2f64.powi(255)));
+
+        assert_eq!(I256::try_from_f64(0.0), Ok(I256::ZERO));
+        assert_eq!(I256::try_from_f64(-0.0), Ok(I256::ZERO));
+        assert_eq!(I256::try_from_f64(-42.0), Ok(I256::try_from(-42).unwrap()));
+        assert_eq!(I256::try_from_f64(1e18), Ok(I256::try_from(10u64.pow(18)).unwrap()));
+        assert_eq!(I256::try_from_f64(-(2f64.powi(255))), Ok(I256::MIN));
+
+        assert_eq!(I256::try_from_f64(0.5), Err(BigIntConversionError));
+        assert_eq!(I256::try_from_f64(-1.5), Err(BigIntConversionError));
+        assert_eq!(I256::try_from_f64(f64::NAN), Err(BigIntConversionError));
+        assert_eq!(I256::try_from_f64(f64::INFINITY), Err(BigIntConversionError));
+        assert_eq!(I256::try_from_f64(f64::NEG_INFINITY), Err(BigIntConversionError));
+        assert_eq!(I256::try_from_f64(2f64.powi(255)), Err(BigIntConversionError));
+        assert_eq!(I256::try_from_f64(f64::MAX), Err(BigIntConversionError));
+
+        let x = I256::try_from(-12345).unwrap();
+        assert_eq!(I256::try_from_f64(x.to_f64_lossy()), Ok(x));
+    }
+
     #[test]
     fn from_dec_str() {
         macro_rules! run_test {
@@ -775,6 +933,30 @@ mod tests {
         run_test!(I256, U256);
     }
 
+    #[test]
+    fn hex_padding() {
+        let u = U256::from(0xabcdu64);
+        assert_eq!(format!("{u:#066x}"), format!("0x{}abcd", "0".repeat(60)));
+        assert_eq!(format!("{u:064X}"), format!("{}ABCD", "0".repeat(60)));
+        assert_eq!(format!("{u:#x}"), "0xabcd");
+        assert_eq!(format!("{u:#X}"), "0xABCD");
+        assert_eq!(format!("{u:>8x}"), "    abcd");
+        assert_eq!(format!("{u:*<#8x}"), "0xabcd**");
+        assert_eq!(format!("{u:^8X}"), "  ABCD  ");
+        // width smaller than the natural length does not truncate
+        assert_eq!(format!("{u:#02x}"), "0xabcd");
+        assert_eq!(format!("{:#066x}", U256::ZERO), format!("0x{}", "0".repeat(64)));
+        assert_eq!(format!("{:#066x}", U256::MAX), format!("0x{}", "f".repeat(64)));
+
+        // signed integers are formatted as their two's complement bit pattern
+        let i = I256::try_from(0xabcdu64).unwrap();
+        assert_eq!(format!("{i:#066x}"), format!("{u:#066x}"));
+        assert_eq!(format!("{:#x}", I256::MINUS_ONE), format!("0x{}", "f".repeat(64)));
+        assert_eq!(format!("{:#X}", -i), format!("{:#X}", (-i).into_raw()));
+        assert_eq!(format!("{:#x}", I256::MIN), format!("0x8{}", "0".repeat(63)));
+        assert_eq!(format!("{:>#70x}", I256::MINUS_ONE), format!("    0x{}", "f".repeat(64)));
+    }
+
     #[test]
     fn signs() {
         macro_rules! run_test {
@@ -1295,6 +1477,50 @@ mod tests {
         run_test!(I256, U256);
     }
 
+    #[test]
+    #[cfg(feature = "std")]
+    fn assign_ops() {
+        use std::panic::catch_unwind;
+
+        macro_rules! check {
+            ($a:expr, $b:expr, $($op:tt $op_assign:tt),+) => {$(
+                let (a, b) = ($a, $b);
+                let expected = catch_unwind(|| a $op b).ok();
+                let mut x = a;
+                assert_eq!(catch_unwind(move || { x $op_assign b; x }).ok(), expected);
+                let mut x = a;
+                assert_eq!(catch_unwind(move || { x $op_assign &b; x }).ok(), expected);
+            )+};
+        }
+
+        let signed = [
+            I256::MIN,
+            I256::MIN + I256::ONE,
+            I256::MINUS_ONE,
+            I256::ZERO,
+            I256::ONE,
+            I256::try_from(7).unwrap(),
+            I256::MAX - I256::ONE,
+            I256::MAX,
+        ];
+        for a in signed {
+            for b in signed {
+                check!(a, b, + +=, - -=, * *=, / /=, % %=, & &=, | |=, ^ ^=);
+            }
+            for n in [0usize, 1, 255, 256] {
+                check!(a, n, << <<=, >> >>=);
+            }
+        }
+
+        let unsigned =
+            [U256::ZERO, U256::from(1), U256::from(7), U256::MAX - U256::from(1), U256::MAX];
+        for a in unsigned {
+            for b in unsigned {
+                check!(a, b, + +=, - -=, * *=, / /=, % %=, & &=, | |=, ^ ^=);
+            }
+        }
+    }
+
     #[test]
     #[cfg(feature = "std")]
     fn division_by_zero() {
@@ -1571,6 +1797,44 @@ mod tests {
         run_test!(I256, U256);
     }
 
+    #[test]
+    fn exponentiation_signed() {
+        let int = |x: i64| I256::try_from(x).unwrap();
+        let exp = |x: u64| U256::from(x);
+
+        assert_eq!(int(-2).pow(exp(3)), int(-8));
+        assert_eq!(int(-2).pow(exp(4)), int(16));
+        assert_eq!(int(-1).pow(exp(u64::MAX)), int(-1));
+        assert_eq!(int(-5).pow(exp(0)), I256::ONE);
+        assert_eq!(I256::ZERO.pow(exp(0)), I256::ONE);
+
+        // `MIN` is representable, but not its negation or its square
+        assert_eq!(int(-2).checked_pow(exp(255)), Some(I256::MIN));
+        assert_eq!(int(2).checked_pow(exp(255)), None);
+        assert_eq!(int(2).saturating_pow(exp(255)), I256::MAX);
+        assert_eq!(int(2).wrapping_pow(exp(255)), I256::MIN);
+        assert_eq!(I256::MIN.checked_pow(exp(1)), Some(I256::MIN));
+        assert_eq!(I256::MIN.checked_pow(exp(2)), None);
+        assert_eq!(I256::MIN.saturating_pow(exp(2)), I256::MAX);
+        assert_eq!(I256::MIN.saturating_pow(exp(3)), I256::MIN);
+        assert_eq!(I256::MIN.wrapping_pow(exp(2)), I256::ZERO);
+        assert_eq!(I256::MAX.checked_pow(exp(2)), None);
+        assert_eq!(I256::MAX.saturating_pow(exp(2)), I256::MAX);
+        assert_eq!(int(-3).saturating_pow(exp(1000)), I256::MAX);
+        assert_eq!(int(-3).saturating_pow(exp(1001)), I256::MIN);
+
+        // all variants match the primitive integers
+        for base in [i128::MIN, i128::MIN + 1, -7, -3, -2, -1, 0, 1, 2, 3, 7, i128::MAX] {
+            for e in [0u32, 1, 2, 3, 63, 64, 126, 127, 128, 129] {
+                let (b, e_u) = (I128::try_from(base).unwrap(), U128::from(e));
+                let expected = |x: i128| I128::try_from(x).unwrap();
+                assert_eq!(b.wrapping_pow(e_u), expected(base.wrapping_pow(e)), "{base}^{e}");
+                assert_eq!(b.saturating_pow(e_u), expected(base.saturating_pow(e)), "{base}^{e}");
+                assert_eq!(b.checked_pow(e_u), base.checked_pow(e).map(expected), "{base}^{e}");
+            }
+        }
+    }
+
     #[test]
     fn iterators() {
         macro_rules! run_test {
@@ -1600,6 +1864,52 @@ mod tests {
         run_test!(I256, U256);
     }
 
+    #[test]
+    fn sign_extend() {
+        for bits in [8, 16, 24, 32, 64, 72, 128, 136, 248] {
+            let min = I256::MINUS_ONE.wrapping_shl(bits - 1);
+            let max = !min;
+
+            // the lowest `bits` bits of the boundary values, without sign extension
+            let mask = U256::MAX >> (256 - bits);
+            let raw_min = I256::from_raw(min.into_raw() & mask);
+            let raw_max = I256::from_raw(max.into_raw() & mask);
+            let raw_minus_one = I256::from_raw(mask);
+
+            assert_eq!(raw_min.sign_extend(bits), min, "{bits}");
+            assert_eq!(raw_max.sign_extend(bits), max, "{bits}");
+            assert_eq!(raw_minus_one.sign_extend(bits), I256::MINUS_ONE, "{bits}");
+            assert_eq!(I256::ZERO.sign_extend(bits), I256::ZERO, "{bits}");
+            assert_eq!(I256::from_be_bytes_signed(raw_min.to_be_bytes::<32>(), bits), min);
+            assert_eq!(
+                I256::from_be_bytes_signed(raw_minus_one.to_be_bytes::<32>(), bits),
+                I256::MINUS_ONE
+            );
+
+            // the upper bits are ignored
+            assert_eq!(I256::MINUS_ONE.sign_extend(bits), I256::MINUS_ONE);
+            assert_eq!((min ^ I256::MINUS_ONE).sign_extend(bits), max);
+        }
+
+        assert_eq!(I256::MIN.sign_extend(256), I256::MIN);
+        assert_eq!(I256::ONE.sign_extend(1), I256::MINUS_ONE);
+        assert_eq!(I256::try_from(2).unwrap().sign_extend(1), I256::ZERO);
+        assert_eq!(I8::from_raw(U8::from(0x0f)).sign_extend(4), I8::MINUS_ONE);
+        assert_eq!(I8::from_raw(U8::from(0x07)).sign_extend(4), I8::from_raw(U8::from(7)));
+    }
+
+    #[test]
+    #[should_panic = "cannot sign-extend from 0 bits"]
+    fn sign_extend_zero_bits() {
+        let _ = I256::ONE.sign_extend(0);
+    }
+
+    #[test]
+    #[should_panic = "cannot sign-extend from 257 bits"]
+    fn sign_extend_too_many_bits() {
+        let _ = I256::ONE.sign_extend(257);
+    }
+
     #[test]
     fn twos_complement() {
         macro_rules! assert_twos_complement {
@@ -607,9 +607,22 @@ impl<const BITS: usize, const LIMBS: usize> Signed<BITS, LIMBS> {
 
     /// Raises self to the power of `exp`, using exponentiation by squaring.
     ///
+    /// A negative base raised to an odd exponent is negative, and any base
+    /// raised to `0`, including `0`, is `1`.
+    ///
     /// # Panics
     ///
     /// If the result overflows the type in debug mode.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use alloy_primitives::{I256, U256};
+    /// let minus_two = I256::try_from(-2).unwrap();
+    /// assert_eq!(minus_two.pow(U256::from(3)), I256::try_from(-8).unwrap());
+    /// assert_eq!(minus_two.pow(U256::from(4)), I256::try_from(16).unwrap());
+    /// assert_eq!(I256::ZERO.pow(U256::ZERO), I256::ONE);
+    /// ```
     #[inline]
     #[track_caller]
     #[must_use]
@@ -651,6 +664,9 @@ impl<const BITS: usize, const LIMBS: usize> Signed<BITS, LIMBS> {
 
     /// Saturating integer exponentiation. Computes `self.pow(exp)`, saturating
     /// at the numeric bounds instead of overflowing.
+    ///
+    /// The bound is chosen by the sign of the result: [`MIN`](Self::MIN) for a
+    /// negative base raised to an odd exponent, and [`MAX`](Self::MAX) otherwise.
     #[inline]
     #[must_use]
     pub fn saturating_pow(self, exp: Uint<BITS, LIMBS>) -> Self {
@@ -834,6 +850,13 @@ macro_rules! impl_shift {
                 }
             }
 
+            impl<const BITS: usize, const LIMBS: usize> ops::ShlAssign<&$t> for Signed<BITS, LIMBS> {
+                #[inline]
+                fn shl_assign(&mut self, rhs: &$t) {
+                    *self = *self << *rhs;
+                }
+            }
+
             impl<const BITS: usize, const LIMBS: usize> ops::Shr<$t> for Signed<BITS, LIMBS> {
                 type Output = Self;
 
@@ -849,6 +872,13 @@ macro_rules! impl_shift {
                     *self = *self >> rhs;
                 }
             }
+
+            impl<const BITS: usize, const LIMBS: usize> ops::ShrAssign<&$t> for Signed<BITS, LIMBS> {
+                #[inline]
+                fn shr_assign(&mut self, rhs: &$t) {
+                    *self = *self >> *rhs;
+                }
+            }
         )+
     };
 }
@@ -1080,6 +1110,30 @@ impl<const BITS: usize, const LIMBS: usize> ops::BitXorAssign for Signed<BITS, L
     }
 }
 
+// assignment ops with a reference right-hand side
+macro_rules! impl_assign_ref {
+    ($($trait:ident::$fn:ident),+ $(,)?) => {$(
+        impl<const BITS: usize, const LIMBS: usize> ops::$trait<&Self> for Signed<BITS, LIMBS> {
+            #[inline]
+            #[track_caller]
+            fn $fn(&mut self, rhs: &Self) {
+                ops::$trait::$fn(self, *rhs);
+            }
+        }
+    )+};
+}
+
+impl_assign_ref!(
+    AddAssign::add_assign,
+    SubAssign::sub_assign,
+    MulAssign::mul_assign,
+    DivAssign::div_assign,
+    RemAssign::rem_assign,
+    BitAndAssign::bitand_assign,
+    BitOrAssign::bitor_assign,
+    BitXorAssign::bitxor_assign,
+);
+
 // unary ops
 impl<const BITS: usize, const LIMBS: usize> ops::Neg for Signed<BITS, LIMBS> {
     type Output = Self;
@@ -48,8 +48,28 @@ impl<'de, const BITS: usize, const LIMBS: usize> Deserialize<'de> for Signed<BIT
             }
         }
 
-        deserializer.deserialize_any(SignedVisitor)
+        // Always serialized as a string, so formats that are not self-describing need the hint.
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_any(SignedVisitor)
+        } else {
+            deserializer.deserialize_str(SignedVisitor)
+        }
     }
 }
 
-// TODO: Tests
+#[cfg(test)]
+mod tests {
+    use crate::I256;
+
+    #[test]
+    fn roundtrip() {
+        for value in [I256::ZERO, I256::MINUS_ONE, I256::MIN, I256::MAX, I256::unchecked_from(42)] {
+            let bin = bincode::serialize(&value).unwrap();
+            assert_eq!(bincode::deserialize::<I256>(&bin).unwrap(), value);
+
+            let json = serde_json::to_string(&value).unwrap();
+            assert_eq!(json, format!("\"{value}\""));
+            assert_eq!(serde_json::from_str::<I256>(&json).unwrap(), value);
+        }
+    }
+}
@@ -0,0 +1,58 @@
+use crate::Uint;
+
+/// Length checked slice conversions for unsigned integers, such as
+/// [`U256`](crate::U256).
+///
+/// [`Uint::try_from_le_slice`] in `ruint` 1.12 only rejects slices that are
+/// at least a full limb longer than the integer, and panics on the lengths in
+/// between, e.g. 33 to 39 bytes for [`U256`](crate::U256).
+/// [`checked_from_le_slice`](Self::checked_from_le_slice) returns [`None`]
+/// for any slice that is longer than the integer instead, like
+/// [`Uint::try_from_be_slice`] does.
+///
+/// # Examples
+///
+/// ```
+/// use alloy_primitives::{UintSlice, U256};
+///
+/// assert_eq!(U256::checked_from_le_slice(&[1, 2]), Some(U256::from(0x0201)));
+/// assert_eq!(U256::checked_from_le_slice(&[0xff; 32]), Some(U256::MAX));
+/// assert_eq!(U256::checked_from_le_slice(&[0; 33]), None);
+/// ```
+pub trait UintSlice: Sized {
+    /// Creates a new integer from a little endian slice of bytes.
+    ///
+    /// Returns [`None`] if the slice is longer than the integer, or if the
+    /// value is larger than fits the integer.
+    fn checked_from_le_slice(slice: &[u8]) -> Option<Self>;
+}
+
+impl<const BITS: usize, const LIMBS: usize> UintSlice for Uint<BITS, LIMBS> {
+    #[inline]
+    fn checked_from_le_slice(slice: &[u8]) -> Option<Self> {
+        if slice.len() > Self::BYTES {
+            return None;
+        }
+        Self::try_from_le_slice(slice)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{U160, U256};
+
+    #[test]
+    fn uint_checked_from_le_slice() {
+        assert_eq!(U256::checked_from_le_slice(&[]), Some(U256::ZERO));
+        assert_eq!(U256::checked_from_le_slice(&[0xff; 32]), Some(U256::MAX));
+        assert_eq!(U160::checked_from_le_slice(&[0xff; 20]), Some(U160::MAX));
+
+        for len in 33..=40 {
+            assert_eq!(U256::checked_from_le_slice(&alloc::vec![0; len]), None, "{len}");
+            assert_eq!(U256::checked_from_le_slice(&alloc::vec![0xff; len]), None, "{len}");
+        }
+        assert_eq!(U160::checked_from_le_slice(&[0; 21]), None);
+        assert_eq!(U160::checked_from_le_slice(&[0xff; 21]), None);
+    }
+}
@@ -0,0 +1,177 @@
+//! `const` implementation of Keccak-256.
+
+use crate::B256;
+
+/// The Keccak-f\[1600\] round constants.
+const RC: [u64; 24] = [
+    0x0000000000000001,
+    0x0000000000008082,
+    0x800000000000808a,
+    0x8000000080008000,
+    0x000000000000808b,
+    0x0000000080000001,
+    0x8000000080008081,
+    0x8000000000008009,
+    0x000000000000008a,
+    0x0000000000000088,
+    0x0000000080008009,
+    0x000000008000000a,
+    0x000000008000808b,
+    0x800000000000008b,
+    0x8000000000008089,
+    0x8000000000008003,
+    0x8000000000008002,
+    0x8000000000000080,
+    0x000000000000800a,
+    0x800000008000000a,
+    0x8000000080008081,
+    0x8000000000008080,
+    0x0000000080000001,
+    0x8000000080008008,
+];
+
+/// The rotation offsets of the ρ step, in the lane order of the π step.
+const RHO: [u32; 24] =
+    [1, 3, 6, 10, 15, 21, 28, 36, 45, 55, 2, 14, 27, 41, 56, 8, 25, 43, 62, 18, 39, 61, 20, 44];
+
+/// The lane permutation of the π step.
+const PI: [usize; 24] =
+    [10, 7, 11, 17, 18, 3, 5, 16, 8, 21, 24, 4, 15, 23, 19, 13, 12, 2, 20, 14, 22, 9, 6, 1];
+
+/// The sponge rate of Keccak-256, in bytes.
+const RATE: usize = 136;
+
+/// Computes the [`Keccak-256`] hash of `bytes` in a `const` context.
+///
+/// This is much slower than [`keccak256`](super::keccak256), and should only be used
+/// to compute constants at compile time, such as [EIP-1967] storage slots.
+///
+/// [`Keccak-256`]: https://en.wikipedia.org/wiki/SHA-3
+/// [EIP-1967]: https://eips.ethereum.org/EIPS/eip-1967
+///
+/// # Examples
+///
+/// ```
+/// use alloy_primitives::{b256, keccak256, utils::keccak256_const, B256, U256};
+///
+/// // bytes32(uint256(keccak256("eip1967.proxy.implementation")) - 1)
+/// const IMPLEMENTATION_SLOT: B256 = B256::new(
+///     U256::from_be_bytes(keccak256_const(b"eip1967.proxy.implementation").0)
+///         .wrapping_sub(U256::from_limbs([1, 0, 0, 0]))
+///         .to_be_bytes(),
+/// );
+/// assert_eq!(
+///     IMPLEMENTATION_SLOT,
+///     b256!("360894a13ba1a3210667c828492db98dca3e2076cc3735a920a3ca505d382bbc")
+/// );
+/// assert_eq!(keccak256_const(b"hello world"), keccak256("hello world"));
+/// ```
+pub const fn keccak256_const(bytes: &[u8]) -> B256 {
+    let mut state = [0u64; 25];
+
+    // absorb
+    let mut pos = 0;
+    let mut i = 0;
+    while i < bytes.len() {
+        state[pos / 8] ^= (bytes[i] as u64) << (8 * (pos % 8));
+        pos += 1;
+        if pos == RATE {
+            state = keccak_f(state);
+            pos = 0;
+        }
+        i += 1;
+    }
+
+    // pad
+    state[pos / 8] ^= 0x01 << (8 * (pos % 8));
+    state[(RATE - 1) / 8] ^= 0x80 << (8 * ((RATE - 1) % 8));
+    state = keccak_f(state);
+
+    // squeeze
+    let mut out = [0u8; 32];
+    let mut i = 0;
+    while i < 32 {
+        out[i] = (state[i / 8] >> (8 * (i % 8))) as u8;
+        i += 1;
+    }
+    B256::new(out)
+}
+
+/// The Keccak-f\[1600\] permutation.
+const fn keccak_f(mut a: [u64; 25]) -> [u64; 25] {
+    let mut round = 0;
+    while round < 24 {
+        // θ
+        let mut c = [0u64; 5];
+        let mut x = 0;
+        while x < 5 {
+            c[x] = a[x] ^ a[x + 5] ^ a[x + 10] ^ a[x + 15] ^ a[x + 20];
+            x += 1;
+        }
+        let mut x = 0;
+        while x < 5 {
+            let d = c[(x + 4) % 5] ^ c[(x + 1) % 5].rotate_left(1);
+            let mut y = 0;
+            while y < 25 {
+                a[y + x] ^= d;
+                y += 5;
+            }
+            x += 1;
+        }
+
+        // ρ and π
+        let mut last = a[1];
+        let mut i = 0;
+        while i < 24 {
+            let j = PI[i];
+            let tmp = a[j];
+            a[j] = last.rotate_left(RHO[i]);
+            last = tmp;
+            i += 1;
+        }
+
+        // χ
+        let mut y = 0;
+        while y < 25 {
+            let row = [a[y], a[y + 1], a[y + 2], a[y + 3], a[y + 4]];
+            let mut x = 0;
+            while x < 5 {
+                a[y + x] = row[x] ^ (!row[(x + 1) % 5] & row[(x + 2) % 5]);
+                x += 1;
+            }
+            y += 5;
+        }
+
+        // ι
+        a[0] ^= RC[round];
+        round += 1;
+    }
+    a
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::keccak256;
+
+    #[test]
+    fn differential() {
+        // cover the empty input, and inputs around multiples of the rate
+        let data: alloc::vec::Vec<u8> = (0..=RATE * 3 + 1).map(|i| i as u8).collect();
+        for len in 0..=data.len() {
+            let input = &data[..len];
+            assert_eq!(keccak256_const(input), keccak256(input), "len: {len}");
+        }
+    }
+
+    #[test]
+    fn const_eval() {
+        const EMPTY: B256 = keccak256_const(&[]);
+        const LONG: B256 = keccak256_const(&[0xab; 1000]);
+        assert_eq!(
+            EMPTY,
+            b256!("c5d2460186f7233c927e7db2dcc703c0e500b653ca82273b7bfad8045d85a470")
+        );
+        assert_eq!(LONG, keccak256([0xab; 1000]));
+    }
+}
@@ -5,13 +5,17 @@ use alloc::{boxed::Box, collections::TryReserveError, vec::Vec};
 use cfg_if::cfg_if;
 use core::{fmt, mem::MaybeUninit};
 
+mod const_keccak;
+pub use const_keccak::keccak256_const;
+
 mod units;
 pub use units::{
-    format_ether, format_units, parse_ether, parse_units, ParseUnits, Unit, UnitsError,
+    format_ether, format_units, format_units_trimmed, parse_ether, parse_units, parse_units_strict,
+    ParseUnits, Unit, UnitsError,
 };
 
 cfg_if! {
-    if #[cfg(all(feature = "asm-keccak", not(miri)))] {
+    if #[cfg(all(feature = "asm-keccak", not(feature = "tiny-keccak"), not(miri)))] {
         use keccak_asm::Digest as _;
     } else {
         use tiny_keccak::Hasher as _;
@@ -113,9 +117,22 @@ pub fn vec_try_from_elem<T: Clone>(elem: T, n: usize) -> Result<Vec<T>, TryReser
 /// The final message is a UTF-8 string, encoded as follows:
 /// `"\x19Ethereum Signed Message:\n" + message.length + message`
 ///
-/// This message is then hashed using [Keccak-256](keccak256).
+/// where `message.length` is the byte length of the message, formatted in ASCII
+/// decimal without padding. This message is then hashed using
+/// [Keccak-256](keccak256).
 ///
 /// [EIP-191]: https://eips.ethereum.org/EIPS/eip-191
+///
+/// # Examples
+///
+/// ```
+/// use alloy_primitives::{b256, utils::eip191_hash_message};
+///
+/// assert_eq!(
+///     eip191_hash_message("Hello World"),
+///     b256!("a1de988600a42c4b4ab089b619297c17d53cffae5d5120d82d8a92d0bb3b78f2")
+/// );
+/// ```
 pub fn eip191_hash_message<T: AsRef<[u8]>>(message: T) -> B256 {
     keccak256(eip191_message(message))
 }
@@ -144,7 +161,15 @@ pub fn eip191_message<T: AsRef<[u8]>>(message: T) -> Vec<u8> {
 
 /// Simple interface to the [`Keccak-256`] hash function.
 ///
+/// The implementation is selected with the following features:
+/// - `asm-keccak`: use the assembly implementation from [`keccak-asm`];
+/// - `native-keccak`: import the host environment's implementation (see below);
+/// - `tiny-keccak`: force the pure-Rust [`tiny_keccak`] implementation, overriding the two features
+///   above. This is also the default if neither of them is enabled.
+///
 /// [`Keccak-256`]: https://en.wikipedia.org/wiki/SHA-3
+/// [`keccak-asm`]: https://docs.rs/keccak-asm
+/// [`tiny_keccak`]: https://docs.rs/tiny-keccak
 pub fn keccak256<T: AsRef<[u8]>>(bytes: T) -> B256 {
     fn keccak256(bytes: &[u8]) -> B256 {
         let mut output = MaybeUninit::<B256>::uninit();
@@ -190,14 +215,15 @@ pub fn keccak256<T: AsRef<[u8]>>(bytes: T) -> B256 {
 /// Simple [`Keccak-256`] hasher.
 ///
 /// Note that the "native-keccak" feature is not supported for this struct, and will default to the
-/// [`tiny_keccak`] implementation.
+/// [`tiny_keccak`] implementation. See [`keccak256`] for the other features that select the
+/// implementation.
 ///
 /// [`Keccak-256`]: https://en.wikipedia.org/wiki/SHA-3
 #[derive(Clone)]
 pub struct Keccak256 {
-    #[cfg(all(feature = "asm-keccak", not(miri)))]
+    #[cfg(all(feature = "asm-keccak", not(feature = "tiny-keccak"), not(miri)))]
     hasher: keccak_asm::Keccak256,
-    #[cfg(not(all(feature = "asm-keccak", not(miri))))]
+    #[cfg(not(all(feature = "asm-keccak", not(feature = "tiny-keccak"), not(miri))))]
     hasher: tiny_keccak::Keccak,
 }
 
@@ -220,7 +246,7 @@ impl Keccak256 {
     #[inline]
     pub fn new() -> Self {
         cfg_if! {
-            if #[cfg(all(feature = "asm-keccak", not(miri)))] {
+            if #[cfg(all(feature = "asm-keccak", not(feature = "tiny-keccak"), not(miri)))] {
                 let hasher = keccak_asm::Keccak256::new();
             } else {
                 let hasher = tiny_keccak::Keccak::v256();
@@ -260,7 +286,7 @@ impl Keccak256 {
     #[inline]
     pub fn finalize_into_array(self, output: &mut [u8; 32]) {
         cfg_if! {
-            if #[cfg(all(feature = "asm-keccak", not(miri)))] {
+            if #[cfg(all(feature = "asm-keccak", not(feature = "tiny-keccak"), not(miri)))] {
                 self.hasher.finalize_into(output.into());
             } else {
                 self.hasher.finalize(output);
@@ -298,6 +324,34 @@ mod tests {
         assert_eq!(eip191_hash_message(msg), hash);
     }
 
+    #[test]
+    fn eip191_length_prefix() {
+        let assert = |msg: &[u8], prefix: &str, hash: B256| {
+            assert_eq!(
+                eip191_message(msg),
+                [EIP191_PREFIX.as_bytes(), prefix.as_bytes(), msg].concat()
+            );
+            assert_eq!(eip191_hash_message(msg), hash, "{prefix}");
+        };
+
+        assert(b"", "0", b256!("5f35dce98ba4fba25530a026ed80b2cecdaa31091ba4958b99b52ea1d068adad"));
+        assert(
+            b"a",
+            "1",
+            b256!("34f291c0b5f0c13c8f43e9d37c04094c22234da43f4040adb36654c98235b4b3"),
+        );
+        assert(
+            b"0123456789",
+            "10",
+            b256!("91e26c79ff38a9cd1035942268ee3d72eaccb7e19e833121d3714716f1402c0a"),
+        );
+        assert(
+            &[0xab; 100],
+            "100",
+            b256!("704fe02d1a6bda2efd2026bab88200ad0ab304d093d01b9ae3c41341b9206709"),
+        );
+    }
+
     #[test]
     fn keccak256_hasher() {
         let expected = b256!("47173285a8d7341e5e972fc677286384f802f8ef42a5ec5f03bbfa254cb01fad");
@@ -322,6 +376,34 @@ mod tests {
         assert_eq!(hash, expected);
     }
 
+    // the `tiny_keccak` dependency is always available, so compare the asm implementation against
+    // it
+    #[test]
+    #[cfg(all(feature = "asm-keccak", not(feature = "tiny-keccak"), not(miri)))]
+    fn keccak256_backends() {
+        fn tiny_keccak256(bytes: &[u8]) -> B256 {
+            use tiny_keccak::Hasher;
+
+            let mut hasher = tiny_keccak::Keccak::v256();
+            hasher.update(bytes);
+            let mut output = B256::ZERO;
+            hasher.finalize(&mut output.0);
+            output
+        }
+
+        let inputs: Vec<Vec<u8>> =
+            [0usize, 1, 31, 32, 33, 135, 136, 137, 1000].iter().map(|&n| vec![0xab; n]).collect();
+        for input in &inputs {
+            assert_eq!(keccak256(input), tiny_keccak256(input), "len: {}", input.len());
+
+            let mut hasher = Keccak256::new();
+            for chunk in input.chunks(7) {
+                hasher.update(chunk);
+            }
+            assert_eq!(hasher.finalize(), tiny_keccak256(input), "len: {}", input.len());
+        }
+    }
+
     #[test]
     fn test_try_boxing() {
         let x = Box::new(42);
@@ -23,6 +23,10 @@ pub fn parse_ether(eth: &str) -> Result<U256, UnitsError> {
 
 /// Parses a decimal number and multiplies it with 10^units.
 ///
+/// Negative numbers are parsed as [`ParseUnits::I256`], and all others as [`ParseUnits::U256`].
+/// Fractional digits beyond `units` are truncated, so the result is rounded towards zero. `units`
+/// must be at most [`Unit::MAX`], and the scaled value must fit in 256 bits.
+///
 /// # Examples
 ///
 /// ```
@@ -52,6 +56,30 @@ where
     ParseUnits::parse_units(amount, units.try_into()?)
 }
 
+/// Parses a decimal number and multiplies it with 10^units, rejecting any loss of precision.
+///
+/// This is the same as [`parse_units`], but returns [`UnitsError::TooManyDecimals`] instead of
+/// truncating if the amount has more fractional digits than `units`.
+///
+/// # Examples
+///
+/// ```
+/// use alloy_primitives::{utils::parse_units_strict, U256};
+///
+/// let amount: U256 = parse_units_strict("1.5", "gwei").unwrap().into();
+/// assert_eq!(amount, U256::from(1_500_000_000u64));
+///
+/// assert!(parse_units_strict("1.5", "wei").is_err());
+/// assert!(parse_units_strict("0.0000000001", "gwei").is_err());
+/// ```
+pub fn parse_units_strict<K, E>(amount: &str, units: K) -> Result<ParseUnits, UnitsError>
+where
+    K: TryInto<Unit, Error = E>,
+    UnitsError: From<E>,
+{
+    ParseUnits::parse_units_strict(amount, units.try_into()?)
+}
+
 /// Formats the given number of Wei as an Ether amount.
 ///
 /// # Examples
@@ -68,6 +96,10 @@ pub fn format_ether<T: Into<ParseUnits>>(amount: T) -> String {
 
 /// Formats the given number of Wei as the given unit.
 ///
+/// The fractional part always has exactly `units` digits, so trailing zeros are kept and the
+/// output can be parsed back with [`parse_units`] using the same unit. Returns an error if
+/// `units` is greater than [`Unit::MAX`].
+///
 /// # Examples
 ///
 /// ```
@@ -89,6 +121,34 @@ where
     units.try_into().map(|units| amount.into().format_units(units)).map_err(UnitsError::from)
 }
 
+/// Formats the given number of Wei as the given unit, without trailing fractional zeros.
+///
+/// This is the same as [`format_units`], but trailing zeros of the fractional part are removed,
+/// along with the decimal point if the amount is a whole number of `units`.
+///
+/// # Examples
+///
+/// ```
+/// use alloy_primitives::{utils::format_units_trimmed, U256};
+///
+/// let eth = U256::from_str_radix("1395633240123456000", 10).unwrap();
+/// assert_eq!(format_units_trimmed(eth, "eth").unwrap(), "1.395633240123456");
+///
+/// assert_eq!(format_units_trimmed(2_000_000_000u64, "gwei").unwrap(), "2");
+/// assert_eq!(format_units_trimmed(-1_500_000_000i64, "gwei").unwrap(), "-1.5");
+/// ```
+pub fn format_units_trimmed<T, K, E>(amount: T, units: K) -> Result<String, UnitsError>
+where
+    T: Into<ParseUnits>,
+    K: TryInto<Unit, Error = E>,
+    UnitsError: From<E>,
+{
+    units
+        .try_into()
+        .map(|units| amount.into().format_units_trimmed(units))
+        .map_err(UnitsError::from)
+}
+
 /// Error type for [`Unit`]-related operations.
 #[derive(Debug)]
 pub enum UnitsError {
@@ -96,13 +156,20 @@ pub enum UnitsError {
     InvalidUnit(String),
     /// Overflow when parsing a signed number.
     ParseSigned(ParseSignedError),
+    /// The amount has more fractional digits than the unit allows.
+    TooManyDecimals {
+        /// The number of fractional digits in the amount.
+        digits: usize,
+        /// The unit's number of decimals.
+        decimals: u8,
+    },
 }
 
 #[cfg(feature = "std")]
 impl std::error::Error for UnitsError {
     fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
         match self {
-            Self::InvalidUnit(_) => None,
+            Self::InvalidUnit(_) | Self::TooManyDecimals { .. } => None,
             Self::ParseSigned(e) => Some(e),
         }
     }
@@ -113,6 +180,9 @@ impl fmt::Display for UnitsError {
         match self {
             Self::InvalidUnit(s) => write!(f, "{s:?} is not a valid unit"),
             Self::ParseSigned(e) => e.fmt(f),
+            Self::TooManyDecimals { digits, decimals } => {
+                write!(f, "amount has {digits} fractional digits, but the unit has {decimals}")
+            }
         }
     }
 }
@@ -243,6 +313,17 @@ impl ParseUnits {
         }
     }
 
+    /// Parses a decimal number and multiplies it with 10^units, rejecting any loss of precision.
+    ///
+    /// See [`parse_units_strict`] for more information.
+    pub fn parse_units_strict(amount: &str, unit: Unit) -> Result<Self, UnitsError> {
+        let digits = amount.find('.').map_or(0, |di| amount.len() - di - 1);
+        if digits > unit.get() as usize {
+            return Err(UnitsError::TooManyDecimals { digits, decimals: unit.get() });
+        }
+        Self::parse_units(amount, unit)
+    }
+
     /// Formats the given number of Wei as the given unit.
     ///
     /// See [`format_units`] for more information.
@@ -273,6 +354,16 @@ impl ParseUnits {
         }
     }
 
+    /// Formats the given number of Wei as the given unit, without trailing fractional zeros.
+    ///
+    /// See [`format_units_trimmed`] for more information.
+    pub fn format_units_trimmed(&self, unit: Unit) -> String {
+        let mut s = self.format_units(unit);
+        let trimmed = s.trim_end_matches('0').trim_end_matches('.').len();
+        s.truncate(trimmed);
+        s
+    }
+
     /// Returns `true` if the number is signed.
     #[inline]
     pub const fn is_signed(&self) -> bool {
@@ -727,6 +818,32 @@ mod tests {
         let _err = format_units(I256::MIN, 79).unwrap_err();
     }
 
+    #[test]
+    fn round_trip() {
+        let units = [0u8, 1, 6, 9, 18, 36, 76];
+        let unsigned = [U256::ZERO, U256::from(1), U256::from(1_000_000u64), U256::MAX];
+        for unit in units.into_iter().chain([77]) {
+            for value in unsigned {
+                let formatted = format_units(value, unit).unwrap();
+                let parsed = parse_units(&formatted, unit).unwrap();
+                assert_eq!(parsed, ParseUnits::U256(value), "{formatted} ({unit})");
+            }
+        }
+
+        let signed = [I256::ZERO, I256::MINUS_ONE, I256::try_from(-1_000_000).unwrap(), I256::MAX];
+        for unit in units {
+            for value in signed.into_iter().chain([I256::MIN]) {
+                let formatted = format_units(value, unit).unwrap();
+                let parsed: I256 = parse_units(&formatted, unit).unwrap().into();
+                assert_eq!(parsed, value, "{formatted} ({unit})");
+            }
+        }
+
+        assert_eq!(format_units(1_000u64, 3).unwrap(), "1.000");
+        assert_eq!(parse_units("1.000", 3).unwrap(), ParseUnits::U256(U256::from(1_000)));
+        assert!(parse_units("1", 78).is_err());
+    }
+
     #[test]
     fn parse_large_units() {
         let decimals = 27u8;
@@ -736,6 +853,58 @@ mod tests {
         assert_eq!(n.to_string(), "10550000000000000000000000000");
     }
 
+    #[test]
+    fn format_units_trims_zeros() {
+        assert_eq!(format_units_trimmed(1_000u64, 3).unwrap(), "1");
+        assert_eq!(format_units_trimmed(1_050u64, 3).unwrap(), "1.05");
+        assert_eq!(format_units_trimmed(10u64, 0).unwrap(), "10");
+        assert_eq!(format_units_trimmed(0u64, 18).unwrap(), "0");
+        assert_eq!(format_units_trimmed(-100i64, 2).unwrap(), "-1");
+        assert_eq!(format_units_trimmed(-1i64, 2).unwrap(), "-0.01");
+        assert_eq!(format_units_trimmed(1u64, 76).unwrap(), format!("0.{:0>76}", 1));
+        assert!(format_units_trimmed(1u64, 78).is_err());
+
+        for unit in [0u8, 1, 6, 18, 77] {
+            for value in [U256::ZERO, U256::from(10), U256::from(1_000_000u64), U256::MAX] {
+                let formatted = format_units_trimmed(value, unit).unwrap();
+                let parsed = parse_units_strict(&formatted, unit).unwrap();
+                assert_eq!(parsed, ParseUnits::U256(value), "{formatted} ({unit})");
+            }
+        }
+    }
+
+    #[test]
+    fn parse_units_strict_rejects_excess_decimals() {
+        assert_eq!(
+            parse_units_strict("1.5", "gwei").unwrap(),
+            ParseUnits::U256(U256::from(1_500_000_000u64))
+        );
+        assert_eq!(
+            parse_units_strict("-1.25", 2).unwrap(),
+            ParseUnits::I256(I256::unchecked_from(-125))
+        );
+        assert_eq!(parse_units_strict("1.", 0).unwrap(), ParseUnits::U256(U256::from(1)));
+        assert_eq!(parse_units_strict("1.000", 3).unwrap(), ParseUnits::U256(U256::from(1_000)));
+
+        for (amount, unit, digits) in
+            [("1.5", 0u8, 1usize), ("1.0", 0, 1), ("1.0001", 3, 4), ("-0.123", 2, 3), (".1", 0, 1)]
+        {
+            match parse_units_strict(amount, unit) {
+                Err(UnitsError::TooManyDecimals { digits: d, decimals }) => {
+                    assert_eq!((d, decimals), (digits, unit), "{amount}");
+                }
+                r => panic!("{amount}: {r:?}"),
+            }
+        }
+        let too_long = format!("1.{}", "1".repeat(78));
+        assert!(matches!(
+            parse_units_strict(&too_long, 77),
+            Err(UnitsError::TooManyDecimals { digits: 78, decimals: 77 })
+        ));
+        // the lenient variant still truncates
+        assert_eq!(parse_units("1.0001", 3).unwrap(), ParseUnits::U256(U256::from(1_000)));
+    }
+
     #[test]
     fn test_parse_units() {
         let gwei: U256 = parse_units("1.5", 9).unwrap().into();
@@ -2,10 +2,11 @@
 
 use super::{anon_name, ty, ExpCtxt};
 use crate::utils::ExprArray;
-use alloy_sol_macro_input::{docs_str, mk_doc, ContainsSolAttrs};
+use alloy_sol_macro_input::{docs_str, mk_doc, ContainsSolAttrs, SolAttrs};
 use ast::{Item, ItemContract, ItemError, ItemEvent, ItemFunction, SolIdent, Spanned};
 use heck::ToSnakeCase;
 use proc_macro2::{Ident, TokenStream};
+use proc_macro_error::emit_error;
 use quote::{format_ident, quote};
 use syn::{parse_quote, Attribute, Result};
 
@@ -26,10 +27,15 @@ use syn::{parse_quote, Attribute, Result};
 ///     pub enum #{name}Events {
 ///         ...
 ///    }
+///
+///     #if event_trait
+///     pub trait #{name}Event { ... }
+///     #endif
 /// }
 /// ```
 pub(super) fn expand(cx: &ExpCtxt<'_>, contract: &ItemContract) -> Result<TokenStream> {
     let ItemContract { name, body, .. } = contract;
+    let inherited = inherited_items(cx, contract)?;
 
     let (sol_attrs, attrs) = contract.split_attrs()?;
 
@@ -37,6 +43,8 @@ pub(super) fn expand(cx: &ExpCtxt<'_>, contract: &ItemContract) -> Result<TokenS
     let rpc = sol_attrs.rpc.or(cx.attrs.rpc).unwrap_or(false);
     let abi = sol_attrs.abi.or(cx.attrs.abi).unwrap_or(false);
     let docs = sol_attrs.docs.or(cx.attrs.docs).unwrap_or(true);
+    let non_exhaustive = sol_attrs.non_exhaustive.or(cx.attrs.non_exhaustive).unwrap_or(false);
+    let event_trait = sol_attrs.event_trait.or(cx.attrs.event_trait).unwrap_or(false);
 
     let bytecode = sol_attrs.bytecode.map(|lit| {
         let name = Ident::new("BYTECODE", lit.span());
@@ -84,8 +92,47 @@ pub(super) fn expand(cx: &ExpCtxt<'_>, contract: &ItemContract) -> Result<TokenS
         attrs.into_iter().partition::<Vec<_>, _>(|a| a.path().is_ident("doc"));
     mod_attrs.extend(item_attrs.iter().filter(|a| !a.path().is_ident("derive")).cloned());
 
+    // The contract's `docs`, `all_derives`, `extra_derives`, `error_impls` and `filters`
+    // attributes are the defaults for all of its items.
+    let docs_attr: Option<Attribute> =
+        sol_attrs.docs.map(|docs| parse_quote!(#[sol(docs = #docs)]));
+    let all_derives_attr: Option<Attribute> =
+        sol_attrs.all_derives.map(|all| parse_quote!(#[sol(all_derives = #all)]));
+    let error_impls_attr: Option<Attribute> =
+        sol_attrs.error_impls.map(|error_impls| parse_quote!(#[sol(error_impls = #error_impls)]));
+    let filters_attr: Option<Attribute> =
+        sol_attrs.filters.map(|filters| parse_quote!(#[sol(filters = #filters)]));
+    let extra_derives_attr: Option<Attribute> = sol_attrs
+        .extra_derives
+        .as_ref()
+        .map(|extra| parse_quote!(#[sol(extra_derives(#(#extra),*))]));
+
     let mut item_tokens = TokenStream::new();
-    for item in body {
+    for item in body.iter().chain(inherited) {
+        let mut inherited_attrs = Vec::new();
+        if let Some(attrs) = item.attrs() {
+            let item_sol_attrs = SolAttrs::parse(attrs)?.0;
+            if let Some(cfg) = item_sol_attrs.cfg {
+                let msg = "`cfg` is only supported on top-level items";
+                return Err(syn::Error::new(cfg.span(), msg));
+            }
+            if item_sol_attrs.docs.is_none() {
+                inherited_attrs.extend(&docs_attr);
+            }
+            if item_sol_attrs.all_derives.is_none() {
+                inherited_attrs.extend(&all_derives_attr);
+            }
+            if item_sol_attrs.extra_derives.is_none() {
+                inherited_attrs.extend(&extra_derives_attr);
+            }
+            if item_sol_attrs.error_impls.is_none() {
+                inherited_attrs.extend(&error_impls_attr);
+            }
+            if item_sol_attrs.filters.is_none() {
+                inherited_attrs.extend(&filters_attr);
+            }
+        }
+
         match item {
             Item::Function(function) => match function.kind {
                 ast::FunctionKind::Function(_) if function.name.is_some() => {
@@ -128,18 +175,32 @@ pub(super) fn expand(cx: &ExpCtxt<'_>, contract: &ItemContract) -> Result<TokenS
             _ => {}
         }
 
-        if item.attrs().is_none() || item_attrs.is_empty() {
+        if item.attrs().is_none() || (item_attrs.is_empty() && inherited_attrs.is_empty()) {
             // avoid cloning item if we don't have to
             item_tokens.extend(cx.expand_item(item)?);
         } else {
-            // prepend `item_attrs` to `item.attrs`
+            // prepend `item_attrs` and the inherited `sol` attributes to `item.attrs`
             let mut item = item.clone();
-            item.attrs_mut().expect("is_none checked above").splice(0..0, item_attrs.clone());
+            let prepended = item_attrs.iter().chain(inherited_attrs);
+            item.attrs_mut().expect("is_none checked above").splice(0..0, prepended.cloned());
             item_tokens.extend(cx.expand_item(&item)?);
         }
     }
 
-    let enum_expander = CallLikeExpander { cx, contract_name: name.clone(), extra_methods };
+    // The contract's derives also apply to its container enums.
+    let derive_attrs = SolAttrs {
+        all_derives: sol_attrs.all_derives,
+        extra_derives: sol_attrs.extra_derives,
+        ..Default::default()
+    };
+    let enum_expander = CallLikeExpander {
+        cx,
+        contract_name: name.clone(),
+        extra_methods,
+        non_exhaustive,
+        event_trait,
+        derive_attrs,
+    };
     // Remove any `Default` derives.
     let mut enum_attrs = item_attrs;
     for attr in &mut enum_attrs {
@@ -510,6 +571,82 @@ pub(super) fn expand(cx: &ExpCtxt<'_>, contract: &ItemContract) -> Result<TokenS
     Ok(tokens)
 }
 
+/// Collects the items that `contract` inherits from the base contracts defined in the same macro
+/// invocation, recursively and in declaration order.
+///
+/// Every base is visited at most once, so items reached through multiple paths (diamond
+/// inheritance) are only included once. Constructors, modifiers, fallback and receive functions
+/// are not inherited. Bases that are not defined in this macro invocation are ignored.
+fn inherited_items<'a>(cx: &'a ExpCtxt<'_>, contract: &'a ItemContract) -> Result<Vec<&'a Item>> {
+    fn collect<'a>(
+        cx: &'a ExpCtxt<'_>,
+        contract: &'a ItemContract,
+        path: &mut Vec<&'a SolIdent>,
+        visited: &mut Vec<&'a SolIdent>,
+        items: &mut Vec<(&'a SolIdent, &'a Item)>,
+    ) -> Result<()> {
+        let Some(inheritance) = &contract.inheritance else { return Ok(()) };
+        path.push(&contract.name);
+        for modifier in &inheritance.inheritance {
+            let Some(Item::Contract(base)) = cx.try_item(&modifier.name) else { continue };
+            if path.contains(&&base.name) {
+                let msg = format!("cyclic inheritance: `{}` inherits from itself", base.name);
+                return Err(syn::Error::new(modifier.name.span(), msg));
+            }
+            if visited.contains(&&base.name) {
+                continue;
+            }
+            visited.push(&base.name);
+
+            items.extend(
+                base.body
+                    .iter()
+                    .filter(|item| match item {
+                        Item::Function(f) => matches!(f.kind, ast::FunctionKind::Function(_)),
+                        Item::Error(_)
+                        | Item::Event(_)
+                        | Item::Struct(_)
+                        | Item::Enum(_)
+                        | Item::Udt(_)
+                        | Item::Variable(_) => true,
+                        _ => false,
+                    })
+                    .map(|item| (&base.name, item)),
+            );
+            collect(cx, base, path, visited, items)?;
+        }
+        path.pop();
+        Ok(())
+    }
+
+    let mut items = Vec::new();
+    collect(cx, contract, &mut Vec::new(), &mut Vec::new(), &mut items)?;
+
+    // Functions, errors and events are overloadable and are checked globally; type definitions
+    // would otherwise silently collide in the generated module.
+    let mut types = contract
+        .body
+        .iter()
+        .filter(|item| matches!(item, Item::Struct(_) | Item::Enum(_) | Item::Udt(_)))
+        .filter_map(|item| Some((item.name()?, &contract.name)))
+        .collect::<Vec<_>>();
+    for &(base, item) in &items {
+        if !matches!(item, Item::Struct(_) | Item::Enum(_) | Item::Udt(_)) {
+            continue;
+        }
+        let Some(item_name) = item.name() else { continue };
+        if let Some((_, other)) = types.iter().find(|(name, _)| *name == item_name) {
+            let msg = format!(
+                "`{item_name}` inherited from `{base}` conflicts with the one defined in `{other}`"
+            );
+            return Err(syn::Error::new(item_name.span(), msg));
+        }
+        types.push((item_name, base));
+    }
+
+    Ok(items.into_iter().map(|(_, item)| item).collect())
+}
+
 // note that item impls generated here do not need to be wrapped in an anonymous
 // constant (`const _: () = { ... };`) because they are in one already
 
@@ -518,6 +655,10 @@ pub(super) fn expand(cx: &ExpCtxt<'_>, contract: &ItemContract) -> Result<TokenS
 /// ```ignore (pseudo-code)
 /// #name = #{contract_name}Calls | #{contract_name}Errors | #{contract_name}Events;
 ///
+/// #[derive(Clone)]
+/// #[derive(Debug, PartialEq, Eq, Hash)] // if all_derives and the parameters support it
+/// #[derive(Debug, PartialEq, Eq)] // otherwise, if all the parameters are elementary types
+/// #[non_exhaustive] // if non_exhaustive
 /// pub enum #name {
 ///    #(#variants(#types),)*
 /// }
@@ -530,9 +671,12 @@ pub(super) fn expand(cx: &ExpCtxt<'_>, contract: &ItemContract) -> Result<TokenS
 ///     pub const SELECTORS: &'static [[u8; _]] = &[...];
 /// }
 ///
-/// #if extra_methods
 /// #(
 ///     impl From<#types> for #name { ... }
+/// )*
+///
+/// #if extra_methods
+/// #(
 ///     impl TryFrom<#name> for #types { ... }
 /// )*
 ///
@@ -547,6 +691,10 @@ struct CallLikeExpander<'a> {
     cx: &'a ExpCtxt<'a>,
     contract_name: SolIdent,
     extra_methods: bool,
+    non_exhaustive: bool,
+    event_trait: bool,
+    /// The contract's `all_derives` and `extra_derives` attributes.
+    derive_attrs: SolAttrs,
 }
 
 #[derive(Clone, Debug)]
@@ -603,6 +751,19 @@ enum ToExpand<'a> {
 }
 
 impl<'a> ToExpand<'a> {
+    /// Returns the types of the parameters of all the items.
+    fn param_types(&self) -> Vec<&'a ast::Type> {
+        match *self {
+            Self::Functions(functions) => {
+                functions.iter().flat_map(|f| f.parameters.types()).collect()
+            }
+            Self::Errors(errors) => errors.iter().flat_map(|e| e.parameters.types()).collect(),
+            Self::Events(events) => {
+                events.iter().flat_map(|e| e.parameters.iter().map(|p| &p.ty)).collect()
+            }
+        }
+    }
+
     fn to_data(&self, expander: &CallLikeExpander<'_>) -> ExpandData {
         let &CallLikeExpander { cx, ref contract_name, .. } = expander;
         match self {
@@ -675,11 +836,28 @@ impl<'a> CallLikeExpander<'a> {
             assert_eq!(s, ns);
         }
 
+        // `SolInterface` dispatches on the sorted selectors, which must be unique
+        for (i, pair) in sorted_data.selectors.windows(2).enumerate() {
+            if pair[0] == pair[1] {
+                let (a, b) = (&sorted_data.variants[i], &sorted_data.variants[i + 1]);
+                let selector = hex::encode_prefixed(&pair[0].array);
+                emit_error!(
+                    b.span(),
+                    "selector collision: `{}` and `{}` have the same selector `{}`",
+                    a,
+                    b,
+                    selector;
+
+                    note = a.span() => "other declaration is here";
+                );
+            }
+        }
+
         if let ToExpand::Events(events) = to_expand {
             return self.expand_events(events, data, &sorted_data, attrs);
         }
 
-        let def = self.generate_enum(data, &sorted_data, attrs);
+        let def = self.generate_enum(&to_expand, data, &sorted_data, attrs);
         let ExpandData { name, variants, min_data_len, trait_, .. } = data;
         let types = data.types();
         let name_s = name.to_string();
@@ -767,7 +945,7 @@ impl<'a> CallLikeExpander<'a> {
         sorted_data: &ExpandData,
         attrs: Vec<Attribute>,
     ) -> TokenStream {
-        let def = self.generate_enum(data, sorted_data, attrs);
+        let def = self.generate_enum(&ToExpand::Events(events), data, sorted_data, attrs);
         let ExpandData { name, trait_, .. } = data;
         let name_s = name.to_string();
         let count = data.variants.len();
@@ -836,6 +1014,104 @@ impl<'a> CallLikeExpander<'a> {
             }
         };
 
+        let event_trait = self.event_trait.then(|| {
+            let contract_name = &self.contract_name;
+            let trait_name = format_ident!("{contract_name}Event");
+            for name in events.iter().map(e_name).filter(|name| name.0 == trait_name) {
+                emit_error!(
+                    name.span(),
+                    "event `{}` conflicts with the `#[sol(event_trait)]` trait of `{}`",
+                    trait_name,
+                    contract_name,
+                );
+            }
+            let doc = format!(
+                "Implemented by all the [`{contract_name}`](self) events and by [`{name}`], so that \
+                 code can be generic over any of them."
+            );
+            let variants: Vec<_> = events.iter().map(e_name).collect();
+            quote! {
+                #[doc = #doc]
+                pub trait #trait_name: alloy_sol_types::private::IntoLogData + Sized {
+                    /// Returns the signature hash of the event.
+                    ///
+                    /// This is the first topic of a non-anonymous event's log. Anonymous events
+                    /// do not emit it.
+                    fn signature_hash(&self) -> alloy_sol_types::private::B256;
+
+                    /// Decodes the event from the given log topics and data. The address that
+                    /// emitted the log is not checked.
+                    ///
+                    /// Unlike `SolEvent::decode_raw_log`, the first topic of a non-anonymous
+                    /// event must be its signature hash.
+                    fn decode_raw_event(
+                        topics: &[alloy_sol_types::Word],
+                        data: &[u8],
+                        validate: bool,
+                    ) -> alloy_sol_types::Result<Self>;
+
+                    /// Decodes the event from the given log data. The address that emitted the
+                    /// log is not checked.
+                    #[inline]
+                    fn decode_event(
+                        log: &alloy_sol_types::private::LogData,
+                        validate: bool,
+                    ) -> alloy_sol_types::Result<Self> {
+                        Self::decode_raw_event(log.topics(), &log.data, validate)
+                    }
+                }
+
+                #(
+                    #[automatically_derived]
+                    impl #trait_name for #variants {
+                        #[inline]
+                        fn signature_hash(&self) -> alloy_sol_types::private::B256 {
+                            <Self as alloy_sol_types::#trait_>::SIGNATURE_HASH
+                        }
+
+                        #[inline]
+                        fn decode_raw_event(
+                            topics: &[alloy_sol_types::Word],
+                            data: &[u8],
+                            validate: bool,
+                        ) -> alloy_sol_types::Result<Self> {
+                            if !<Self as alloy_sol_types::#trait_>::ANONYMOUS
+                                && topics.first() != alloy_sol_types::private::Some(&<Self as alloy_sol_types::#trait_>::SIGNATURE_HASH)
+                            {
+                                return alloy_sol_types::private::Err(alloy_sol_types::Error::InvalidLog {
+                                    name: <Self as alloy_sol_types::#trait_>::SIGNATURE,
+                                    log: alloy_sol_types::private::Box::new(alloy_sol_types::private::LogData::new_unchecked(
+                                        topics.to_vec(),
+                                        data.to_vec().into(),
+                                    )),
+                                });
+                            }
+                            <Self as alloy_sol_types::#trait_>::decode_raw_log(topics, data, validate)
+                        }
+                    }
+                )*
+
+                #[automatically_derived]
+                impl #trait_name for #name {
+                    #[inline]
+                    fn signature_hash(&self) -> alloy_sol_types::private::B256 {
+                        match self {#(
+                            Self::#variants(inner) => <#variants as #trait_name>::signature_hash(inner),
+                        )*}
+                    }
+
+                    #[inline]
+                    fn decode_raw_event(
+                        topics: &[alloy_sol_types::Word],
+                        data: &[u8],
+                        validate: bool,
+                    ) -> alloy_sol_types::Result<Self> {
+                        <Self as alloy_sol_types::SolEventInterface>::decode_raw_log(topics, data, validate)
+                    }
+                }
+            }
+        });
+
         quote! {
             #def
 
@@ -851,11 +1127,14 @@ impl<'a> CallLikeExpander<'a> {
             }
 
             #into_impl
+
+            #event_trait
         }
     }
 
     fn generate_enum(
         &self,
+        to_expand: &ToExpand<'_>,
         data: &ExpandData,
         sorted_data: &ExpandData,
         mut attrs: Vec<Attribute>,
@@ -869,7 +1148,15 @@ impl<'a> CallLikeExpander<'a> {
         assert!(selectors.iter().all(|s| s.array.len() == selector_len));
         let selector_type = quote!([u8; #selector_len]);
 
-        self.cx.type_derives(&mut attrs, types.iter().cloned().map(ast::Type::custom), false);
+        // All variant payloads are owned and always `Clone`; the other builtin traits depend on
+        // the parameters of every variant, like for the payloads themselves.
+        attrs.push(parse_quote!(#[derive(Clone)]));
+        let param_types = to_expand.param_types();
+        self.cx.type_derives(&mut attrs, &self.derive_attrs, param_types.iter().copied(), false);
+        self.cx.builtin_derives(&mut attrs, param_types);
+        if self.non_exhaustive {
+            attrs.push(parse_quote!(#[non_exhaustive]));
+        }
 
         let mut tokens = quote! {
             #(#attrs)*
@@ -890,9 +1177,13 @@ impl<'a> CallLikeExpander<'a> {
             }
         };
 
+        // Variant payloads are distinct generated structs, even for overloads with identical
+        // parameters (`f_0Call`, `f_1Call`), so these impls never overlap.
+        tokens.extend(variants.iter().zip(types).map(|(v, t)| generate_variant_from(name, v, t)));
+
         if self.extra_methods {
             let conversions =
-                variants.iter().zip(types).map(|(v, t)| generate_variant_conversions(name, v, t));
+                variants.iter().zip(types).map(|(v, t)| generate_variant_try_from(name, v, t));
             let methods = variants.iter().zip(types).map(generate_variant_methods);
             tokens.extend(conversions);
             tokens.extend(quote! {
@@ -907,7 +1198,7 @@ impl<'a> CallLikeExpander<'a> {
     }
 }
 
-fn generate_variant_conversions(name: &Ident, variant: &Ident, ty: &Ident) -> TokenStream {
+fn generate_variant_from(name: &Ident, variant: &Ident, ty: &Ident) -> TokenStream {
     quote! {
         #[automatically_derived]
         impl ::core::convert::From<#ty> for #name {
@@ -916,7 +1207,11 @@ fn generate_variant_conversions(name: &Ident, variant: &Ident, ty: &Ident) -> To
                 Self::#variant(value)
             }
         }
+    }
+}
 
+fn generate_variant_try_from(name: &Ident, variant: &Ident, ty: &Ident) -> TokenStream {
+    quote! {
         #[automatically_derived]
         impl ::core::convert::TryFrom<#name> for #ty {
             type Error = #name;
@@ -23,7 +23,7 @@ pub(super) fn expand(cx: &ExpCtxt<'_>, enumm: &ItemEnum) -> Result<TokenStream>
     let ItemEnum { name, variants, .. } = enumm;
 
     let (sol_attrs, mut attrs) = enumm.split_attrs()?;
-    cx.derives(&mut attrs, [], false);
+    cx.derives(&mut attrs, &sol_attrs, [], false);
     let docs = sol_attrs.docs.or(cx.attrs.docs).unwrap_or(true);
 
     let name_s = name.to_string();
@@ -65,7 +65,11 @@ pub(super) fn expand(cx: &ExpCtxt<'_>, enumm: &ItemEnum) -> Result<TokenStream>
         quote! { expect("unreachable") }
     };
 
-    let alloy_sol_types = &cx.crates.sol_types;
+    let sol_types_alias = cx.sol_types_alias();
+
+    let variant_names = variants.iter().map(|v| &v.ident);
+    let variant_names_s = variants.iter().map(|v| v.ident.to_string());
+    let invalid_pretty = has_invalid_variant.then(|| quote!(Self::__Invalid => "__Invalid",));
 
     let uint8 = quote!(alloy_sol_types::sol_data::Uint<8>);
     let uint8_st = quote!(<#uint8 as alloy_sol_types::SolType>);
@@ -84,7 +88,7 @@ pub(super) fn expand(cx: &ExpCtxt<'_>, enumm: &ItemEnum) -> Result<TokenStream>
 
         #[allow(non_camel_case_types, non_snake_case, clippy::style)]
         const _: () = {
-            use #alloy_sol_types as alloy_sol_types;
+            #sol_types_alias
 
             #[automatically_derived]
             impl ::core::convert::From<#name> for u8 {
@@ -184,6 +188,17 @@ pub(super) fn expand(cx: &ExpCtxt<'_>, enumm: &ItemEnum) -> Result<TokenStream>
                 }
             }
 
+            #[automatically_derived]
+            impl alloy_sol_types::private::PrettyDebug for #name {
+                #[inline]
+                fn pretty_fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                    f.write_str(match self {
+                        #(Self::#variant_names => #variant_names_s,)*
+                        #invalid_pretty
+                    })
+                }
+            }
+
             #[automatically_derived]
             impl alloy_sol_types::SolEnum for #name {
                 const COUNT: usize = #count;
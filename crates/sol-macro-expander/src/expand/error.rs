@@ -1,7 +1,7 @@
 //! [`ItemError`] expansion.
 
-use super::{expand_fields, expand_from_into_tuples, expand_tokenize, ExpCtxt};
-use alloy_sol_macro_input::{mk_doc, ContainsSolAttrs};
+use super::{expand_fields, expand_from_into_tuples, expand_tokenize, param_docs, ExpCtxt};
+use alloy_sol_macro_input::{derives_mapped, mk_doc, ArrayRepr, ContainsSolAttrs, NatSpec};
 use ast::ItemError;
 use proc_macro2::TokenStream;
 use quote::quote;
@@ -17,15 +17,26 @@ use syn::Result;
 /// impl SolError for #name {
 ///     ...
 /// }
+///
+/// #if error_impls
+/// impl Display for #name {
+///     ...
+/// }
+///
+/// // if `#name: Debug`
+/// impl Error for #name {}
+/// #endif
 /// ```
 pub(super) fn expand(cx: &ExpCtxt<'_>, error: &ItemError) -> Result<TokenStream> {
     let ItemError { parameters: params, .. } = error;
     cx.assert_resolved(params)?;
 
     let (sol_attrs, mut attrs) = error.split_attrs()?;
-    cx.derives(&mut attrs, params, true);
+    cx.derives(&mut attrs, &sol_attrs, params, true);
+    cx.builtin_derives(&mut attrs, params.types());
     let docs = sol_attrs.docs.or(cx.attrs.docs).unwrap_or(true);
     let abi = sol_attrs.abi.or(cx.attrs.abi).unwrap_or(false);
+    let error_impls = sol_attrs.error_impls.or(cx.attrs.error_impls).unwrap_or(false);
 
     let tokenize_impl = expand_tokenize(params, cx);
 
@@ -33,10 +44,12 @@ pub(super) fn expand(cx: &ExpCtxt<'_>, error: &ItemError) -> Result<TokenStream>
     let signature = cx.error_signature(error);
     let selector = crate::utils::selector(&signature);
 
-    let alloy_sol_types = &cx.crates.sol_types;
+    let sol_types_alias = cx.sol_types_alias();
 
-    let converts = expand_from_into_tuples(&name.0, params, cx);
-    let fields = expand_fields(params, cx);
+    let converts = expand_from_into_tuples(&name.0, params, ArrayRepr::Vec, cx);
+    let natspec = if docs { NatSpec::parse(&error.attrs) } else { NatSpec::default() };
+    let field_docs = param_docs(&natspec, params);
+    let fields = expand_fields(params, &field_docs, ArrayRepr::Vec, cx);
     let doc = docs.then(|| {
         let selector = hex::encode_prefixed(selector.array.as_slice());
         mk_doc(format!(
@@ -60,6 +73,28 @@ pub(super) fn expand(cx: &ExpCtxt<'_>, error: &ItemError) -> Result<TokenStream>
             }
         }
     });
+    let error_impls = error_impls.then(|| {
+        // `Error` requires `Debug`, which is only available if it was derived
+        let derives_debug = derives_mapped(&attrs)
+            .any(|path| path.segments.last().map_or(false, |s| s.ident == "Debug"));
+        let std_error = derives_debug.then(|| {
+            quote! {
+                #[automatically_derived]
+                impl alloy_sol_types::private::StdError for #name {}
+            }
+        });
+        quote! {
+            #[automatically_derived]
+            impl ::core::fmt::Display for #name {
+                #[inline]
+                fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                    f.write_str(<Self as alloy_sol_types::SolError>::SIGNATURE)
+                }
+            }
+
+            #std_error
+        }
+    });
     let tokens = quote! {
         #(#attrs)*
         #doc
@@ -71,7 +106,7 @@ pub(super) fn expand(cx: &ExpCtxt<'_>, error: &ItemError) -> Result<TokenStream>
 
         #[allow(non_camel_case_types, non_snake_case, clippy::style)]
         const _: () = {
-            use #alloy_sol_types as alloy_sol_types;
+            #sol_types_alias
 
             #converts
 
@@ -94,6 +129,8 @@ pub(super) fn expand(cx: &ExpCtxt<'_>, error: &ItemError) -> Result<TokenStream>
                 }
             }
 
+            #error_impls
+
             #abi
         };
     };
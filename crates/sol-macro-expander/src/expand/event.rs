@@ -1,10 +1,10 @@
 //! [`ItemEvent`] expansion.
 
 use super::{anon_name, expand_event_tokenize, expand_tuple_types, expand_type, ty, ExpCtxt};
-use alloy_sol_macro_input::{mk_doc, ContainsSolAttrs};
+use alloy_sol_macro_input::{mk_doc, ContainsSolAttrs, NatSpec};
 use ast::{EventParameter, ItemEvent, SolIdent, Spanned};
 use proc_macro2::TokenStream;
-use quote::{quote, quote_spanned};
+use quote::{format_ident, quote, quote_spanned};
 use syn::Result;
 
 /// Expands an [`ItemEvent`]:
@@ -17,14 +17,20 @@ use syn::Result;
 /// impl SolEvent for #name {
 ///     ...
 /// }
+///
+/// #if filters
+/// pub struct #{name}Filter { ... }
+/// #endif
 /// ```
 pub(super) fn expand(cx: &ExpCtxt<'_>, event: &ItemEvent) -> Result<TokenStream> {
     let params = event.params();
 
     let (sol_attrs, mut attrs) = event.split_attrs()?;
-    cx.derives(&mut attrs, &params, true);
+    cx.derives(&mut attrs, &sol_attrs, &params, true);
+    cx.builtin_derives(&mut attrs, params.iter().map(|p| &p.ty));
     let docs = sol_attrs.docs.or(cx.attrs.docs).unwrap_or(true);
     let abi = sol_attrs.abi.or(cx.attrs.abi).unwrap_or(false);
+    let filters = sol_attrs.filters.or(cx.attrs.filters).unwrap_or(false);
 
     cx.assert_resolved(&params)?;
     event.assert_valid()?;
@@ -39,6 +45,8 @@ pub(super) fn expand(cx: &ExpCtxt<'_>, event: &ItemEvent) -> Result<TokenStream>
     let topic_list = event.indexed_params().map(|p| expand_event_topic_type(p, cx));
     let topic_list = first_topic.into_iter().chain(topic_list);
 
+    let topic_types = event.indexed_params().map(|p| p.ty.to_string());
+
     let (data_tuple, _) = expand_tuple_types(event.non_indexed_params().map(|p| &p.ty), cx);
 
     // skip first topic if not anonymous, which is the hash of the signature
@@ -92,14 +100,24 @@ pub(super) fn expand(cx: &ExpCtxt<'_>, event: &ItemEvent) -> Result<TokenStream>
         }
     });
 
-    let fields = event
-        .parameters
-        .iter()
-        .enumerate()
-        .map(|(i, p)| expand_event_topic_field(i, p, p.name.as_ref(), cx));
+    let natspec = if docs { NatSpec::parse(&event.attrs) } else { NatSpec::default() };
+    let fields = event.parameters.iter().enumerate().map(|(i, p)| {
+        let field = expand_event_topic_field(i, p, p.name.as_ref(), cx);
+        let doc = p.name.as_ref().and_then(|name| natspec.param(&name.as_string())).map(mk_doc);
+        quote!(#doc pub #field)
+    });
 
     let tokenize_body_impl = expand_event_tokenize(&event.parameters, cx);
 
+    let matches_selector = if anonymous {
+        quote! {
+            let _ = topic0;
+            false
+        }
+    } else {
+        quote!(topic0 == <Self as alloy_sol_types::SolEvent>::SIGNATURE_HASH)
+    };
+
     let encode_topics_impl = encode_first_topic
         .into_iter()
         .chain(encode_topics_impl)
@@ -131,7 +149,14 @@ pub(super) fn expand(cx: &ExpCtxt<'_>, event: &ItemEvent) -> Result<TokenStream>
         }
     });
 
-    let alloy_sol_types = &cx.crates.sol_types;
+    let (filter_struct, filter_impls) = if filters {
+        let (filter_struct, filter_impls) = expand_filter(cx, event, &name, docs);
+        (Some(filter_struct), Some(filter_impls))
+    } else {
+        (None, None)
+    };
+
+    let sol_types_alias = cx.sol_types_alias();
 
     let tokens = quote! {
         #(#attrs)*
@@ -141,13 +166,49 @@ pub(super) fn expand(cx: &ExpCtxt<'_>, event: &ItemEvent) -> Result<TokenStream>
         pub struct #name {
             #(
                 #[allow(missing_docs)]
-                pub #fields,
+                #fields,
             )*
         }
 
+        #filter_struct
+
         #[allow(non_camel_case_types, non_snake_case, clippy::style)]
         const _: () = {
-            use #alloy_sol_types as alloy_sol_types;
+            #sol_types_alias
+
+            #filter_impls
+
+            #[automatically_derived]
+            impl #name {
+                /// The Solidity types of the indexed parameters, in order, spelled as
+                /// declared.
+                ///
+                /// Indexed dynamic types, like `string`, are stored in the topics as
+                /// their Keccak-256 hash, but are reported here with their declared type.
+                /// Custom types are reported by name, like `MyStruct`, and not as their
+                /// ABI type.
+                pub const TOPIC_TYPES: &'static [&'static str] = &[#(#topic_types),*];
+
+                /// Returns `true` if `topic0` is this event's signature hash.
+                ///
+                /// This is a cheap check that can be done before fully decoding a log.
+                /// Anonymous events have no signature topic, so this always returns
+                /// `false` for them.
+                #[inline]
+                pub fn matches_selector(topic0: alloy_sol_types::private::B256) -> bool {
+                    #matches_selector
+                }
+
+                /// Returns `true` if the first topic of `log` is this event's signature
+                /// hash, without decoding the rest of the log.
+                ///
+                /// Anonymous events have no signature topic, so this always returns
+                /// `false` for them.
+                #[inline]
+                pub fn matches_log(log: &alloy_sol_types::private::LogData) -> bool {
+                    log.topics().first().map_or(false, |&topic0| Self::matches_selector(topic0))
+                }
+            }
 
             #[automatically_derived]
             impl alloy_sol_types::SolEvent for #name {
@@ -222,6 +283,115 @@ pub(super) fn expand(cx: &ExpCtxt<'_>, event: &ItemEvent) -> Result<TokenStream>
     Ok(tokens)
 }
 
+/// Expands the `#[sol(filters)]` topic filter type of an event, returning the filter struct and
+/// its impls.
+fn expand_filter(
+    cx: &ExpCtxt<'_>,
+    event: &ItemEvent,
+    name: &SolIdent,
+    docs: bool,
+) -> (TokenStream, TokenStream) {
+    let filter_name = format_ident!("{}Filter", name.0);
+    let anonymous = event.is_anonymous();
+
+    let indexed = event.parameters.iter().enumerate().filter(|(_, p)| p.is_indexed());
+    let mut names = Vec::new();
+    let mut types = Vec::new();
+    let mut encodes = Vec::new();
+    let mut setter_docs = Vec::new();
+    for (topic_i, (i, p)) in (!anonymous as usize..).zip(indexed) {
+        let field = anon_name((i, p.name.as_ref()));
+        let ty = if p.indexed_as_hash() {
+            quote!(alloy_sol_types::sol_data::FixedBytes<32>)
+        } else {
+            expand_type(&p.ty, &cx.crates)
+        };
+        encodes.push(quote! {
+            self.topics[#topic_i] = alloy_sol_types::private::Some(
+                <#ty as alloy_sol_types::EventTopic>::encode_topic(&#field).0,
+            );
+        });
+        types.push(quote!(<#ty as alloy_sol_types::SolType>::RustType));
+        setter_docs.push(docs.then(|| {
+            let doc = if p.indexed_as_hash() {
+                format!(
+                    "Filters by the `{field}` parameter.\n\n\
+                     This parameter is indexed by the Keccak-256 hash of its value, \
+                     so this takes the hash, not the value itself."
+                )
+            } else {
+                format!("Filters by the `{field}` parameter.")
+            };
+            mk_doc(doc)
+        }));
+        names.push(field);
+    }
+
+    let name_s = name.as_string();
+    let filter_doc = docs.then(|| {
+        mk_doc(format!(
+            "Topic filter for the [`{name_s}`] event, created with [`{name_s}::filter`].\n\n\
+             Parameters that are not set match any value. Convert it into the \
+             `[Option<B256>; 4]` topics to filter by with `From`/`Into`. For non-anonymous \
+             events, the first topic is always the event's signature hash."
+        ))
+    });
+    let ctor_doc = docs.then(|| mk_doc(format!("Creates a new topic filter for [`{name_s}`].")));
+    let topics = if anonymous {
+        quote!([alloy_sol_types::private::None; 4])
+    } else {
+        quote! {
+            [
+                alloy_sol_types::private::Some(<Self as alloy_sol_types::SolEvent>::SIGNATURE_HASH),
+                alloy_sol_types::private::None,
+                alloy_sol_types::private::None,
+                alloy_sol_types::private::None,
+            ]
+        }
+    };
+    let alloy_sol_types = &cx.crates.sol_types;
+
+    let filter_struct = quote! {
+        #filter_doc
+        #[allow(non_camel_case_types, non_snake_case)]
+        #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+        pub struct #filter_name {
+            topics: [::core::option::Option<#alloy_sol_types::private::B256>; 4],
+        }
+    };
+    let filter_impls = quote! {
+        #[automatically_derived]
+        impl #name {
+            #ctor_doc
+            #[inline]
+            pub fn filter() -> #filter_name {
+                #filter_name { topics: #topics }
+            }
+        }
+
+        #[automatically_derived]
+        impl #filter_name {
+            #(
+                #setter_docs
+                #[inline]
+                pub fn #names(mut self, #names: #types) -> Self {
+                    #encodes
+                    self
+                }
+            )*
+        }
+
+        #[automatically_derived]
+        impl alloy_sol_types::private::From<#filter_name> for [alloy_sol_types::private::Option<alloy_sol_types::private::B256>; 4] {
+            #[inline]
+            fn from(filter: #filter_name) -> Self {
+                filter.topics
+            }
+        }
+    };
+    (filter_struct, filter_impls)
+}
+
 fn expand_event_topic_type(param: &EventParameter, cx: &ExpCtxt<'_>) -> TokenStream {
     let alloy_sol_types = &cx.crates.sol_types;
     assert!(param.is_indexed());
@@ -1,7 +1,10 @@
 //! [`ItemFunction`] expansion.
 
-use super::{expand_fields, expand_from_into_tuples, expand_tokenize, expand_tuple_types, ExpCtxt};
-use alloy_sol_macro_input::{mk_doc, ContainsSolAttrs};
+use super::{
+    anon_name, expand_fields, expand_from_into_tuples, expand_tokenize, expand_tuple_types,
+    param_docs, return_docs, ty, ExpCtxt,
+};
+use alloy_sol_macro_input::{mk_doc, ArrayRepr, ContainsSolAttrs, NatSpec};
 use ast::{FunctionKind, ItemFunction, Spanned};
 use proc_macro2::TokenStream;
 use quote::{format_ident, quote};
@@ -18,10 +21,28 @@ use syn::Result;
 ///     #(pub #return_name: #return_type,)*
 /// }
 ///
+/// impl #{name}Call {
+///     pub const ARG_COUNT: usize = ...;
+///     pub const ARG_TYPES: &'static [&'static str] = &[...];
+///     pub const ARG_NAMES: &'static [&'static str] = &[...];
+/// }
+///
 /// impl SolCall for #{name}Call {
 ///     type Return = #{name}Return;
 ///     ...
 /// }
+///
+/// #if returns.len() == 1
+/// impl #{name}Call {
+///     pub fn abi_decode_returns_single(data: &[u8], validate: bool) -> Result<#return_type> { ... }
+/// }
+/// #endif
+///
+/// #if returns.len() > 0
+/// impl #{name}Call {
+///     pub fn abi_decode_returns_tuple(data: &[u8], validate: bool) -> Result<(#(#return_type,)*)> { ... }
+/// }
+/// #endif
 /// ```
 pub(super) fn expand(cx: &ExpCtxt<'_>, function: &ItemFunction) -> Result<TokenStream> {
     let ItemFunction { parameters, returns, name, kind, .. } = function;
@@ -44,9 +65,11 @@ pub(super) fn expand(cx: &ExpCtxt<'_>, function: &ItemFunction) -> Result<TokenS
 
     let (sol_attrs, mut call_attrs) = function.split_attrs()?;
     let mut return_attrs = call_attrs.clone();
-    cx.derives(&mut call_attrs, parameters, true);
+    cx.derives(&mut call_attrs, &sol_attrs, parameters, true);
+    cx.builtin_derives(&mut call_attrs, parameters.types());
     if !returns.is_empty() {
-        cx.derives(&mut return_attrs, returns, true);
+        cx.derives(&mut return_attrs, &sol_attrs, returns, true);
+        cx.builtin_derives(&mut return_attrs, returns.types());
     }
     let docs = sol_attrs.docs.or(cx.attrs.docs).unwrap_or(true);
     let abi = sol_attrs.abi.or(cx.attrs.abi).unwrap_or(false);
@@ -54,18 +77,33 @@ pub(super) fn expand(cx: &ExpCtxt<'_>, function: &ItemFunction) -> Result<TokenS
     let call_name = cx.call_name(function);
     let return_name = cx.return_name(function);
 
-    let call_fields = expand_fields(parameters, cx);
-    let return_fields = expand_fields(returns, cx);
+    let natspec = if docs { NatSpec::parse(&function.attrs) } else { NatSpec::default() };
+    let call_docs = param_docs(&natspec, parameters);
+    let return_docs = return_docs(&natspec, returns);
+    let call_fields = expand_fields(parameters, &call_docs, ArrayRepr::Vec, cx);
+    let return_fields = expand_fields(returns, &return_docs, ArrayRepr::Vec, cx);
 
     let call_tuple = expand_tuple_types(parameters.types(), cx).0;
     let return_tuple = expand_tuple_types(returns.types(), cx).0;
 
-    let converts = expand_from_into_tuples(&call_name, parameters, cx);
-    let return_converts = expand_from_into_tuples(&return_name, returns, cx);
+    let converts = expand_from_into_tuples(&call_name, parameters, ArrayRepr::Vec, cx);
+    let return_converts = expand_from_into_tuples(&return_name, returns, ArrayRepr::Vec, cx);
 
     let signature = cx.function_signature(function);
     let selector = crate::utils::selector(&signature);
+    let state_mutability = match function.attributes.mutability() {
+        Some(ast::Mutability::Pure(_)) => "pure",
+        Some(ast::Mutability::View(_) | ast::Mutability::Constant(_)) => "view",
+        Some(ast::Mutability::Payable(_)) => "payable",
+        None => "nonpayable",
+    };
     let tokenize_impl = expand_tokenize(parameters, cx);
+    let tokenize_returns_impl = expand_tokenize(returns, cx);
+
+    let arg_count = parameters.len();
+    let arg_types = parameters.types().map(|ty| ty::TypePrinter::new(cx, ty).to_string());
+    let arg_names =
+        parameters.iter().map(|p| p.name.as_ref().map(|n| n.as_string()).unwrap_or_default());
 
     let call_doc = docs.then(|| {
         let selector = hex::encode_prefixed(selector.array.as_slice());
@@ -97,7 +135,41 @@ pub(super) fn expand(cx: &ExpCtxt<'_>, function: &ItemFunction) -> Result<TokenS
         }
     });
 
-    let alloy_sol_types = &cx.crates.sol_types;
+    let decode_returns_single = (returns.len() == 1).then(|| {
+        let field = anon_name((0, returns[0].name.as_ref()));
+        let ty = ty::expand_rust_type(&returns[0].ty, &cx.crates);
+        quote! {
+            #[automatically_derived]
+            impl #call_name {
+                /// Decodes the ABI-encoded return data of this function, returning its only value.
+                #[inline]
+                pub fn abi_decode_returns_single(data: &[u8], validate: bool) -> alloy_sol_types::Result<#ty> {
+                    <Self as alloy_sol_types::SolCall>::abi_decode_returns(data, validate).map(|r| r.#field)
+                }
+            }
+        }
+    });
+
+    let decode_returns_tuple = (!returns.is_empty()).then(|| {
+        quote! {
+            #[automatically_derived]
+            impl #call_name {
+                /// Decodes the ABI-encoded return data of this function into a tuple of its return
+                /// values, in declaration order.
+                ///
+                /// A single return value is decoded into a one-element tuple.
+                #[inline]
+                pub fn abi_decode_returns_tuple(
+                    data: &[u8],
+                    validate: bool,
+                ) -> alloy_sol_types::Result<<#return_tuple as alloy_sol_types::SolType>::RustType> {
+                    <#return_tuple as alloy_sol_types::SolType>::abi_decode_sequence(data, validate)
+                }
+            }
+        }
+    });
+
+    let sol_types_alias = cx.sol_types_alias();
 
     let tokens = quote! {
         #(#call_attrs)*
@@ -118,11 +190,25 @@ pub(super) fn expand(cx: &ExpCtxt<'_>, function: &ItemFunction) -> Result<TokenS
 
         #[allow(non_camel_case_types, non_snake_case, clippy::style)]
         const _: () = {
-            use #alloy_sol_types as alloy_sol_types;
+            #sol_types_alias
 
             { #converts }
             { #return_converts }
 
+            #[automatically_derived]
+            impl #call_name {
+                /// The number of parameters of this function.
+                pub const ARG_COUNT: usize = #arg_count;
+
+                /// The canonical Solidity types of the parameters, in order.
+                pub const ARG_TYPES: &'static [&'static str] = &[#(#arg_types),*];
+
+                /// The names of the parameters, in order.
+                ///
+                /// Unnamed parameters have an empty name.
+                pub const ARG_NAMES: &'static [&'static str] = &[#(#arg_names),*];
+            }
+
             #[automatically_derived]
             impl alloy_sol_types::SolCall for #call_name {
                 type Parameters<'a> = #call_tuple;
@@ -135,6 +221,7 @@ pub(super) fn expand(cx: &ExpCtxt<'_>, function: &ItemFunction) -> Result<TokenS
 
                 const SIGNATURE: &'static str = #signature;
                 const SELECTOR: [u8; 4] = #selector;
+                const STATE_MUTABILITY: &'static str = #state_mutability;
 
                 #[inline]
                 fn new<'a>(tuple: <Self::Parameters<'a> as alloy_sol_types::SolType>::RustType) -> Self {
@@ -152,6 +239,34 @@ pub(super) fn expand(cx: &ExpCtxt<'_>, function: &ItemFunction) -> Result<TokenS
                 }
             }
 
+            #[automatically_derived]
+            impl #return_name {
+                /// ABI-encodes these return values, as they would be returned by the function.
+                ///
+                /// This is the inverse of [`SolCall::abi_decode_returns`](alloy_sol_types::SolCall::abi_decode_returns).
+                #[inline]
+                pub fn abi_encode(&self) -> alloy_sol_types::private::Vec<u8> {
+                    let tokens: <#call_name as alloy_sol_types::SolCall>::ReturnToken<'_> = #tokenize_returns_impl;
+                    alloy_sol_types::abi::encode_sequence(&tokens)
+                }
+            }
+
+            #[automatically_derived]
+            impl alloy_sol_types::private::TryFrom<&[u8]> for #call_name {
+                type Error = alloy_sol_types::Error;
+
+                #[inline]
+                fn try_from(data: &[u8]) -> alloy_sol_types::Result<Self> {
+                    if data.len() < 4 {
+                        return alloy_sol_types::private::Err(alloy_sol_types::Error::Overrun);
+                    }
+                    <Self as alloy_sol_types::SolCall>::abi_decode(data, true)
+                }
+            }
+
+            #decode_returns_single
+            #decode_returns_tuple
+
             #abi
         };
     };
@@ -164,12 +279,14 @@ fn expand_constructor(cx: &ExpCtxt<'_>, constructor: &ItemFunction) -> Result<To
     let (sol_attrs, call_attrs) = constructor.split_attrs()?;
     let docs = sol_attrs.docs.or(cx.attrs.docs).unwrap_or(true);
 
-    let alloy_sol_types = &cx.crates.sol_types;
+    let sol_types_alias = cx.sol_types_alias();
 
     let call_name = format_ident!("constructorCall").with_span(constructor.kind.span());
-    let call_fields = expand_fields(parameters, cx);
+    let natspec = if docs { NatSpec::parse(&constructor.attrs) } else { NatSpec::default() };
+    let call_docs = param_docs(&natspec, parameters);
+    let call_fields = expand_fields(parameters, &call_docs, ArrayRepr::Vec, cx);
     let call_tuple = expand_tuple_types(parameters.types(), cx).0;
-    let converts = expand_from_into_tuples(&call_name, parameters, cx);
+    let converts = expand_from_into_tuples(&call_name, parameters, ArrayRepr::Vec, cx);
     let tokenize_impl = expand_tokenize(parameters, cx);
 
     let call_doc = docs.then(|| {
@@ -189,7 +306,7 @@ fn expand_constructor(cx: &ExpCtxt<'_>, constructor: &ItemFunction) -> Result<To
         }
 
         const _: () = {
-            use #alloy_sol_types as alloy_sol_types;
+            #sol_types_alias
 
             { #converts }
 
@@ -1,10 +1,9 @@
 //! Functions which generate Rust code from the Solidity AST.
 
-use crate::{
-    expand::ty::expand_rust_type,
-    utils::{self, ExprArray},
+use crate::utils::{self, ExprArray};
+use alloy_sol_macro_input::{
+    derives_mapped, mk_doc, ArrayRepr, ContainsSolAttrs, NatSpec, SolAttrs,
 };
-use alloy_sol_macro_input::{ContainsSolAttrs, SolAttrs};
 use ast::{
     EventParameter, File, Item, ItemError, ItemEvent, ItemFunction, Parameters, SolIdent, SolPath,
     Spanned, Type, VariableDeclaration, Visit,
@@ -15,6 +14,7 @@ use proc_macro_error::{abort, emit_error};
 use quote::{format_ident, quote, TokenStreamExt};
 use std::{
     borrow::Borrow,
+    cell::Cell,
     fmt::Write,
     sync::atomic::{AtomicBool, Ordering},
 };
@@ -26,6 +26,9 @@ mod macros;
 pub mod ty;
 pub use ty::expand_type;
 
+mod selectors;
+pub use selectors::expand_selectors;
+
 mod contract;
 mod r#enum;
 mod error;
@@ -62,6 +65,10 @@ pub struct ExpCtxt<'ast> {
     attrs: SolAttrs,
     crates: ExternCrates,
     ast: &'ast File,
+
+    /// Whether items are being expanded in a contract module, which already
+    /// imports `alloy_sol_types` for all of its items.
+    in_contract: Cell<bool>,
 }
 
 // expand
@@ -75,6 +82,7 @@ impl<'ast> ExpCtxt<'ast> {
             attrs: SolAttrs::default(),
             crates: ExternCrates::default(),
             ast,
+            in_contract: Cell::new(false),
         }
     }
 
@@ -101,7 +109,7 @@ impl<'ast> ExpCtxt<'ast> {
 
         for item in &self.ast.items {
             // TODO: Dummy items
-            let t = match self.expand_item(item) {
+            let t = match self.expand_top_level_item(item) {
                 Ok(t) => t,
                 Err(e) => e.into_compile_error(),
             };
@@ -110,9 +118,41 @@ impl<'ast> ExpCtxt<'ast> {
         Ok(tokens)
     }
 
+    /// Expands a top-level item, gating all of the generated items behind the
+    /// file's and the item's `#[sol(cfg = "...")]` attributes.
+    fn expand_top_level_item(&self, item: &Item) -> Result<TokenStream> {
+        let tokens = self.expand_item(item)?;
+
+        let item_cfg = match item.attrs() {
+            Some(attrs) => SolAttrs::parse(attrs)?.0.cfg,
+            None => None,
+        };
+        let cfgs = self
+            .attrs
+            .cfg
+            .iter()
+            .chain(&item_cfg)
+            .map(|cfg| cfg.parse::<TokenStream>())
+            .collect::<Result<Vec<_>>>()?;
+        if cfgs.is_empty() {
+            return Ok(tokens);
+        }
+
+        // The item can expand to multiple Rust items, like the type itself, its
+        // impls and `const _` blocks, so each of them has to be gated.
+        let items = syn::parse2::<syn::File>(tokens)?.items;
+        let cfg_attrs = quote!(#(#[cfg(#cfgs)])*);
+        Ok(quote!(#(#cfg_attrs #items)*))
+    }
+
     fn expand_item(&self, item: &Item) -> Result<TokenStream> {
         match item {
-            Item::Contract(contract) => contract::expand(self, contract),
+            Item::Contract(contract) => {
+                self.in_contract.set(true);
+                let tokens = contract::expand(self, contract);
+                self.in_contract.set(false);
+                tokens
+            }
             Item::Enum(enumm) => r#enum::expand(self, enumm),
             Item::Error(error) => error::expand(self, error),
             Item::Event(event) => event::expand(self, event),
@@ -461,7 +501,10 @@ impl<'ast> ExpCtxt<'ast> {
     }
 
     /// Extends `attrs` with all possible derive attributes for the given type
-    /// if `#[sol(all_derives)]` was passed.
+    /// if `#[sol(all_derives)]` was passed, and with the derives listed in
+    /// `#[sol(extra_derives(...))]`.
+    ///
+    /// The item's `sol_attrs` take precedence over the file's attributes.
     ///
     /// The following traits are only implemented on tuples of arity 12 or less:
     /// - [PartialEq](https://doc.rust-lang.org/stable/std/cmp/trait.PartialEq.html)
@@ -480,20 +523,36 @@ impl<'ast> ExpCtxt<'ast> {
     /// Array reference: <https://doc.rust-lang.org/stable/std/primitive.array.html>
     ///
     /// `derive_default` should be set to false when calling this for enums.
-    fn derives<'a, I>(&self, attrs: &mut Vec<Attribute>, params: I, derive_default: bool)
-    where
+    fn derives<'a, I>(
+        &self,
+        attrs: &mut Vec<Attribute>,
+        sol_attrs: &SolAttrs,
+        params: I,
+        derive_default: bool,
+    ) where
         I: IntoIterator<Item = &'a VariableDeclaration>,
     {
-        self.type_derives(attrs, params.into_iter().map(|p| &p.ty), derive_default);
+        self.type_derives(attrs, sol_attrs, params.into_iter().map(|p| &p.ty), derive_default);
     }
 
     /// Implementation of [`derives`](Self::derives).
-    fn type_derives<T, I>(&self, attrs: &mut Vec<Attribute>, types: I, mut derive_default: bool)
-    where
+    fn type_derives<T, I>(
+        &self,
+        attrs: &mut Vec<Attribute>,
+        sol_attrs: &SolAttrs,
+        types: I,
+        mut derive_default: bool,
+    ) where
         I: IntoIterator<Item = T>,
         T: Borrow<Type>,
     {
-        let Some(true) = self.attrs.all_derives else {
+        let extra_derives =
+            sol_attrs.extra_derives.as_deref().or(self.attrs.extra_derives.as_deref());
+        if let Some(extra) = extra_derives.filter(|e| !e.is_empty()) {
+            attrs.push(parse_quote! { #[derive(#(#extra),*)] });
+        }
+
+        let Some(true) = sol_attrs.all_derives.or(self.attrs.all_derives) else {
             return;
         };
 
@@ -514,6 +573,46 @@ impl<'ast> ExpCtxt<'ast> {
         attrs.push(parse_quote! { #[derive(#(#derives), *)] });
     }
 
+    /// Derives `Debug`, `PartialEq` and `Eq` if all the `types` are elementary,
+    /// even without `#[sol(all_derives)]`.
+    ///
+    /// This is used for call, error and event structs, and for the contract
+    /// container enums holding them. Traits that are already derived, through
+    /// `all_derives` or otherwise, are skipped.
+    fn builtin_derives<T, I>(&self, attrs: &mut Vec<Attribute>, types: I)
+    where
+        I: IntoIterator<Item = T>,
+        T: Borrow<Type>,
+    {
+        if !types.into_iter().all(|ty| ty::is_elementary_derivable(ty.borrow())) {
+            return;
+        }
+
+        let derived = derives_mapped(attrs)
+            .filter_map(|path| path.segments.last().map(|segment| segment.ident.to_string()))
+            .collect::<Vec<_>>();
+        let derives = ["Debug", "PartialEq", "Eq"]
+            .into_iter()
+            .filter(|derive| !derived.iter().any(|d| d == derive))
+            .map(|s| Ident::new(s, Span::call_site()))
+            .collect::<Vec<_>>();
+        if !derives.is_empty() {
+            attrs.push(parse_quote! { #[derive(#(#derives), *)] });
+        }
+    }
+
+    /// Returns the `alloy_sol_types` import of the anonymous constant that
+    /// wraps an item's implementations.
+    ///
+    /// This is `None` in contract modules, which import the crate once for
+    /// all of their items.
+    fn sol_types_alias(&self) -> Option<TokenStream> {
+        (!self.in_contract.get()).then(|| {
+            let alloy_sol_types = &self.crates.sol_types;
+            quote!(use #alloy_sol_types as alloy_sol_types;)
+        })
+    }
+
     /// Returns an error if any of the types in the parameters are unresolved.
     ///
     /// Provides a better error message than an `unwrap` or `expect` when we
@@ -577,21 +676,44 @@ impl ExternCrates {
 // helper functions
 
 /// Expands a list of parameters into a list of struct fields.
+///
+/// `docs` are the additional field docs, indexed by parameter position.
 fn expand_fields<'a, P>(
     params: &'a Parameters<P>,
+    docs: &'a [Option<&str>],
+    array_repr: ArrayRepr,
     cx: &'a ExpCtxt<'_>,
 ) -> impl Iterator<Item = TokenStream> + 'a {
-    params.iter().enumerate().map(|(i, var)| {
+    params.iter().enumerate().map(move |(i, var)| {
         let name = anon_name((i, var.name.as_ref()));
-        let ty = expand_rust_type(&var.ty, &cx.crates);
+        let ty = ty::expand_rust_field_type(&var.ty, array_repr, &cx.crates);
         let attrs = &var.attrs;
+        let doc = docs.get(i).copied().flatten().map(mk_doc);
         quote! {
             #(#attrs)*
+            #doc
             pub #name: #ty
         }
     })
 }
 
+/// Returns the NatSpec `@param` descriptions of the given parameters.
+fn param_docs<'a, P>(natspec: &'a NatSpec, params: &Parameters<P>) -> Vec<Option<&'a str>> {
+    params
+        .iter()
+        .map(|p| p.name.as_ref().and_then(|name| natspec.param(&name.as_string())))
+        .collect()
+}
+
+/// Returns the NatSpec `@return` descriptions of the given return values.
+fn return_docs<'a, P>(natspec: &'a NatSpec, returns: &Parameters<P>) -> Vec<Option<&'a str>> {
+    returns
+        .iter()
+        .enumerate()
+        .map(|(i, p)| natspec.return_value(i, p.name.as_ref().map(SolIdent::as_string).as_deref()))
+        .collect()
+}
+
 /// Generates an anonymous name from an integer. Used in [`anon_name`].
 #[inline]
 pub fn generate_name(i: usize) -> Ident {
@@ -607,15 +729,36 @@ pub fn anon_name<T: Into<Ident> + Clone>((i, name): (usize, Option<&T>)) -> Iden
 }
 
 /// Expands `From` impls for a list of types and the corresponding tuple.
+///
+/// Fields represented as `Box<[T]>` are converted from and into the `Vec<T>` of the tuple, which
+/// does not reallocate for vectors created by the decoder.
 fn expand_from_into_tuples<P>(
     name: &Ident,
     fields: &Parameters<P>,
+    array_repr: ArrayRepr,
     cx: &ExpCtxt<'_>,
 ) -> TokenStream {
     let names = fields.names().enumerate().map(anon_name);
-
-    let names2 = names.clone();
-    let idxs = (0..fields.len()).map(syn::Index::from);
+    let boxed = fields.types().map(|ty| ty::is_boxed_array(ty, array_repr)).collect::<Vec<_>>();
+
+    let names =
+        names.zip(&boxed).map(
+            |(name, &boxed)| {
+                if boxed {
+                    quote!(value.#name.into())
+                } else {
+                    quote!(value.#name)
+                }
+            },
+        );
+    let names2 = fields.names().enumerate().map(anon_name);
+    let idxs = (0..fields.len()).map(syn::Index::from).zip(&boxed).map(|(idx, &boxed)| {
+        if boxed {
+            quote!(tuple.#idx.into())
+        } else {
+            quote!(tuple.#idx)
+        }
+    });
 
     let (sol_tuple, rust_tuple) = expand_tuple_types(fields.types(), cx);
 
@@ -637,7 +780,7 @@ fn expand_from_into_tuples<P>(
         #[doc(hidden)]
         impl ::core::convert::From<#name> for UnderlyingRustTuple<'_> {
             fn from(value: #name) -> Self {
-                (#(value.#names,)*)
+                (#(#names,)*)
             }
         }
 
@@ -646,7 +789,7 @@ fn expand_from_into_tuples<P>(
         impl ::core::convert::From<UnderlyingRustTuple<'_>> for #name {
             fn from(tuple: UnderlyingRustTuple<'_>) -> Self {
                 Self {
-                    #(#names2: tuple.#idxs),*
+                    #(#names2: #idxs),*
                 }
             }
         }
@@ -719,3 +862,249 @@ fn emit_json_error() {
         );
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn expand_str(s: &str) -> String {
+        expand(syn::parse_str(s).unwrap()).unwrap().to_string()
+    }
+
+    #[test]
+    fn selectors_errors() {
+        let input: alloy_sol_macro_input::SolInput = syn::parse_str(
+            "selectors { f(S), f(mapping(uint => bool)), g(), g(), h_0(), h(), h(uint) }",
+        )
+        .unwrap();
+        let alloy_sol_macro_input::SolInputKind::Selectors(signatures) = input.kind else {
+            panic!("not selectors: {:?}", input.kind);
+        };
+        let errors = expand_selectors(&signatures)
+            .unwrap_err()
+            .into_iter()
+            .map(|e| e.to_string())
+            .collect::<Vec<_>>();
+        let types = "only elementary, array and tuple types are allowed in selector signatures";
+        assert_eq!(
+            errors,
+            [
+                types,
+                types,
+                "duplicate signature `g()`",
+                "selector constant `h_0` conflicts with an overloaded function",
+            ]
+        );
+    }
+
+    #[test]
+    fn natspec_field_docs() {
+        let tokens = expand_str(
+            r#"
+            /// @notice Sends tokens.
+            /// @param to the recipient
+            /// @param fn the kind of
+            /// transfer
+            /// @return success whether it succeeded
+            /// @return the new balance
+            function send(address to, uint256 amount, uint8 fn) returns (bool success, uint256);
+
+            /// @param owner the owner
+            event Transfer(address indexed owner, uint256 value);
+
+            /// @param needed the required amount
+            error Insufficient(uint256 needed);
+            "#,
+        );
+        let field = |doc: &str, field: &str| format!("# [doc = \"{doc}\"] pub {field} :");
+        assert!(tokens.contains(&field("the recipient", "to")), "{tokens}");
+        assert!(tokens.contains(&field("the kind of transfer", "r#fn")), "{tokens}");
+        assert!(tokens.contains(&field("whether it succeeded", "success")), "{tokens}");
+        assert!(tokens.contains(&field("the new balance", "_1")), "{tokens}");
+        assert!(tokens.contains(&field("the owner", "owner")), "{tokens}");
+        assert!(tokens.contains(&field("the required amount", "needed")), "{tokens}");
+
+        // undocumented parameters get no docs
+        assert!(tokens.contains("pub amount :"), "{tokens}");
+        assert!(!tokens.contains("] pub amount :"), "{tokens}");
+        assert!(tokens.contains("pub value :"), "{tokens}");
+        assert!(!tokens.contains("\"] pub value :"), "{tokens}");
+    }
+
+    #[test]
+    fn natspec_no_docs() {
+        let tokens = expand_str(
+            r#"
+            #[sol(docs = false)]
+            /// @param to the recipient
+            function send(address to);
+            "#,
+        );
+        assert!(!tokens.contains("[doc = \"the recipient\"]"), "{tokens}");
+    }
+
+    #[test]
+    fn docs_precedence() {
+        let docs = |sig: &str| format!("with signature `{sig}`");
+
+        let tokens = expand_str(
+            r#"
+            #![sol(docs = false)]
+            function f();
+            #[sol(docs = true)]
+            function g();
+            event E();
+            "#,
+        );
+        assert!(!tokens.contains(&docs("f()")), "{tokens}");
+        assert!(tokens.contains(&docs("g()")), "{tokens}");
+        assert!(!tokens.contains(&docs("E()")), "{tokens}");
+
+        // contract attributes apply to the contract's items, and override the invocation's
+        let tokens = expand_str(
+            r#"
+            #![sol(docs = true)]
+            #[sol(docs = false)]
+            contract C {
+                function f();
+                #[sol(docs = true)]
+                event E();
+                error Err();
+            }
+            contract D {
+                function g();
+            }
+            "#,
+        );
+        assert!(!tokens.contains(&docs("f()")), "{tokens}");
+        assert!(tokens.contains(&docs("E()")), "{tokens}");
+        assert!(!tokens.contains(&docs("Err()")), "{tokens}");
+        assert!(tokens.contains(&docs("g()")), "{tokens}");
+    }
+
+    #[test]
+    fn cfg_gates_all_items() {
+        let file: syn::File = syn::parse2(
+            expand(
+                syn::parse_str(r#"#[sol(cfg = "feature = \"erc20\"")] struct S { uint a; }"#)
+                    .unwrap(),
+            )
+            .unwrap(),
+        )
+        .unwrap();
+        // the struct, and at least one `const _` block with its impls
+        assert!(file.items.len() > 1);
+        let cfg: Attribute = parse_quote!(#[cfg(feature = "erc20")]);
+        for item in &file.items {
+            let item_tokens = quote!(#item).to_string();
+            assert!(item_tokens.starts_with(&quote!(#cfg).to_string()), "{item_tokens}");
+        }
+
+        let tokens = expand_str(
+            r#"
+            #![sol(cfg = "unix")]
+            #[sol(cfg = "test")]
+            contract C {}
+            "#,
+        );
+        assert!(tokens.starts_with("# [cfg (unix)] # [cfg (test)]"), "{tokens}");
+    }
+
+    #[test]
+    fn cfg_inner_item() {
+        let tokens = expand_str(
+            r#"
+            contract C {
+                #[sol(cfg = "test")]
+                function f();
+            }
+            "#,
+        );
+        assert!(tokens.contains("compile_error"), "{tokens}");
+        assert!(tokens.contains("`cfg` is only supported on top-level items"), "{tokens}");
+    }
+
+    #[test]
+    fn builder_build_field() {
+        let tokens = expand_str("#[sol(builder)] struct S { uint256 build; }");
+        assert!(tokens.contains("compile_error"), "{tokens}");
+        assert!(tokens.contains("with a field named `build`"), "{tokens}");
+
+        let tokens = expand_str("#![sol(builder)] struct S { uint256 a; } struct T { bytes b; }");
+        assert!(!tokens.contains("compile_error"), "{tokens}");
+        assert!(tokens.contains("pub struct SBuilder"), "{tokens}");
+        assert!(tokens.contains("pub struct TBuilder"), "{tokens}");
+    }
+
+    #[test]
+    fn event_filter_opt_in() {
+        let tokens = expand_str("event E(uint256 indexed a);");
+        assert!(!tokens.contains("EFilter"), "{tokens}");
+
+        let tokens = expand_str("#[sol(filters)] event E(uint256 indexed topics);");
+        assert!(!tokens.contains("compile_error"), "{tokens}");
+        assert!(tokens.contains("pub struct EFilter"), "{tokens}");
+    }
+
+    #[test]
+    fn event_trait_conflict() {
+        let tokens = expand_str("contract C { event CEvent(uint256 a); }");
+        assert!(!tokens.contains("trait CEvent"), "{tokens}");
+
+        let tokens = expand_str("#[sol(event_trait)] contract C { event E(uint256 a); }");
+        assert!(tokens.contains("pub trait CEvent"), "{tokens}");
+    }
+
+    #[test]
+    fn sol_types_alias() {
+        let alias = "use alloy_core :: sol_types as alloy_sol_types ;";
+        let tokens = expand_str(
+            r#"
+            #![sol(alloy_sol_types = alloy_core::sol_types)]
+            struct S { uint256 a; }
+            contract C {
+                enum E { A }
+                struct T { uint256 a; }
+                event Ev(uint256 a);
+                error Er(uint256 a);
+                function f(uint256 a) returns (uint256);
+            }
+            function g(uint256 a);
+            "#,
+        );
+        assert!(!tokens.contains("compile_error"), "{tokens}");
+        // `S`, `g` and the contract module, but none of the contract's items
+        assert_eq!(tokens.matches(alias).count(), 3, "{tokens}");
+        assert!(!tokens.contains(":: alloy_sol_types"), "{tokens}");
+    }
+
+    #[test]
+    fn transparent_multiple_fields() {
+        let tokens = expand_str(
+            r#"
+            #[sol(transparent)]
+            struct S {
+                uint256 a;
+                uint256 b;
+            }
+            "#,
+        );
+        assert!(tokens.contains("compile_error"), "{tokens}");
+        assert!(tokens.contains("only supported on structs with exactly one field"), "{tokens}");
+
+        let tokens = expand_str(
+            r#"
+            #![sol(transparent)]
+            struct S {
+                uint256 a;
+                uint256 b;
+            }
+            struct T {
+                uint256 a;
+            }
+            "#,
+        );
+        assert!(!tokens.contains("compile_error"), "{tokens}");
+        assert_eq!(tokens.matches("repr (transparent)").count(), 1, "{tokens}");
+    }
+}
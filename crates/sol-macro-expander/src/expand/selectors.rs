@@ -0,0 +1,80 @@
+//! [`SelectorSignature`] expansion.
+
+use super::{ty::TypePrinter, ExpCtxt};
+use crate::utils;
+use alloy_sol_macro_input::SelectorSignature;
+use ast::{File, Spanned, Type};
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use std::collections::{HashMap, HashSet};
+use syn::{Error, Result};
+
+/// Expands the function signatures of a `selectors { ... }` input into `[u8; 4]` selector
+/// constants.
+///
+/// Constants are named after their function. Overloaded functions are suffixed with their index,
+/// like the call types of overloaded functions are.
+pub fn expand_selectors(signatures: &[SelectorSignature]) -> Result<TokenStream> {
+    // there are no custom types to resolve
+    let file = File { attrs: Vec::new(), items: Vec::new() };
+    let cx = ExpCtxt::new(&file);
+
+    let mut overloads = HashMap::<String, usize>::new();
+    for sig in signatures {
+        *overloads.entry(sig.name.as_string()).or_default() += 1;
+    }
+
+    let mut errors = Vec::new();
+    let mut seen_signatures = HashSet::new();
+    let mut seen_names = HashSet::new();
+    let mut indices = HashMap::<String, usize>::new();
+    let mut tokens = TokenStream::new();
+    for sig in signatures {
+        let n_errors = errors.len();
+        for ty in &sig.types {
+            ty.visit(|ty| {
+                if matches!(ty, Type::Custom(_) | Type::Mapping(_)) {
+                    let msg = "only elementary, array and tuple types are allowed in selector \
+                               signatures";
+                    errors.push(Error::new(ty.span(), msg));
+                }
+            });
+        }
+        if errors.len() > n_errors {
+            continue;
+        }
+
+        let types = sig.types.iter().map(|ty| TypePrinter::new(&cx, ty).to_string());
+        let signature = format!("{}({})", sig.name, types.collect::<Vec<_>>().join(","));
+        if !seen_signatures.insert(signature.clone()) {
+            errors.push(Error::new(sig.name.span(), format!("duplicate signature `{signature}`")));
+            continue;
+        }
+
+        let name = sig.name.as_string();
+        let name = if overloads[&name] > 1 {
+            let i = indices.entry(name).or_default();
+            let new_name = format_ident!("{}_{}", sig.name, *i, span = sig.name.span());
+            *i += 1;
+            new_name
+        } else {
+            sig.name.0.clone()
+        };
+        if !seen_names.insert(name.to_string()) {
+            let msg = format!("selector constant `{name}` conflicts with an overloaded function");
+            errors.push(Error::new(sig.name.span(), msg));
+            continue;
+        }
+
+        let doc = format!("Function selector for `{signature}`.");
+        let selector = utils::selector(&signature).with_span(sig.name.span());
+        tokens.extend(quote! {
+            #[doc = #doc]
+            #[allow(non_upper_case_globals)]
+            pub const #name: [u8; 4] = #selector;
+        });
+    }
+    utils::combine_errors(errors)?;
+
+    Ok(tokens)
+}
@@ -1,12 +1,16 @@
 //! [`ItemStruct`] expansion.
 
-use super::{expand_fields, expand_from_into_tuples, expand_tokenize, expand_type, ExpCtxt};
-use alloy_sol_macro_input::{mk_doc, ContainsSolAttrs};
+use super::{
+    expand_fields, expand_from_into_tuples, expand_tokenize, expand_type,
+    ty::{self, is_boxed_array},
+    ExpCtxt,
+};
+use alloy_sol_macro_input::{mk_doc, parse_derives, ArrayRepr, ContainsSolAttrs};
 use ast::{Item, ItemStruct, Spanned, Type};
 use proc_macro2::TokenStream;
-use quote::quote;
+use quote::{format_ident, quote};
 use std::num::NonZeroU16;
-use syn::Result;
+use syn::{parse_quote, Attribute, Result};
 
 /// Expands an [`ItemStruct`]:
 ///
@@ -23,14 +27,50 @@ use syn::Result;
 /// impl EventTopic for #name {
 ///     ...
 /// }
+///
+/// #if builder
+/// pub struct #{name}Builder {
+///     #(#field_name: Option<#field_type>,)*
+/// }
+///
+/// impl #name {
+///     pub fn builder() -> #{name}Builder { ... }
+/// }
+///
+/// impl #{name}Builder {
+///     #(pub fn #field_name(self, #field_name: #field_type) -> Self { ... })*
+///     pub fn build(self) -> Result<#name, MissingFieldError> { ... }
+/// }
+/// #endif
 /// ```
 pub(super) fn expand(cx: &ExpCtxt<'_>, s: &ItemStruct) -> Result<TokenStream> {
     let ItemStruct { name, fields, .. } = s;
 
     let (sol_attrs, mut attrs) = s.split_attrs()?;
 
-    cx.derives(&mut attrs, fields, true);
+    cx.derives(&mut attrs, &sol_attrs, fields, true);
+    if let Some(serde) = sol_attrs.serde.as_deref().or(cx.attrs.serde.as_deref()) {
+        attrs.push(parse_quote!(#[serde(#(#serde),*)]));
+    }
     let docs = sol_attrs.docs.or(cx.attrs.docs).unwrap_or(true);
+    let array_repr = sol_attrs.array_repr.or(cx.attrs.array_repr).unwrap_or_default();
+    if sol_attrs.transparent == Some(true) && fields.len() != 1 {
+        let msg = "`transparent` is only supported on structs with exactly one field";
+        return Err(syn::Error::new(name.span(), msg));
+    }
+    let transparent =
+        sol_attrs.transparent.or(cx.attrs.transparent).unwrap_or(false) && fields.len() == 1;
+    let builder = sol_attrs.builder.or(cx.attrs.builder).unwrap_or(false);
+    let pretty_debug = sol_attrs.pretty_debug.or(cx.attrs.pretty_debug).unwrap_or(false);
+    if pretty_debug {
+        remove_debug_derive(&mut attrs);
+    }
+    let (builder_struct, builder_impls) = if builder {
+        let (builder_struct, builder_impls) = expand_builder(cx, s, array_repr, docs)?;
+        (Some(builder_struct), Some(builder_impls))
+    } else {
+        (None, None)
+    };
 
     let (field_types, field_names): (Vec<_>, Vec<_>) =
         fields.iter().map(|f| (expand_type(&f.ty, &cx.crates), f.name.as_ref().unwrap())).unzip();
@@ -53,12 +93,47 @@ pub(super) fn expand(cx: &ExpCtxt<'_>, s: &ItemStruct) -> Result<TokenStream> {
         },
     };
 
-    let alloy_sol_types = &cx.crates.sol_types;
+    let sol_types_alias = cx.sol_types_alias();
 
     let attrs = attrs.iter();
-    let convert = expand_from_into_tuples(&name.0, fields, cx);
+    let convert = expand_from_into_tuples(&name.0, fields, array_repr, cx);
     let name_s = name.as_string();
-    let fields = expand_fields(fields, cx);
+    // `EventTopic` takes the Rust type of the field's `SolType`, which is a `Vec<T>`.
+    let topic_fields = fields
+        .iter()
+        .zip(&field_names)
+        .map(|(f, name)| {
+            if is_boxed_array(&f.ty, array_repr) {
+                quote!(&rust.#name.to_vec())
+            } else {
+                quote!(&rust.#name)
+            }
+        })
+        .collect::<Vec<_>>();
+    let transparent_impls = transparent.then(|| {
+        let ty = ty::expand_rust_field_type(&fields[0].ty, array_repr, &cx.crates);
+        let field = field_names[0];
+        quote! {
+            #[automatically_derived]
+            impl ::core::convert::From<#ty> for #name {
+                #[inline]
+                fn from(value: #ty) -> Self {
+                    Self { #field: value }
+                }
+            }
+
+            #[automatically_derived]
+            impl ::core::convert::From<#name> for #ty {
+                #[inline]
+                fn from(value: #name) -> Self {
+                    value.#field
+                }
+            }
+        }
+    });
+    let pretty_debug_impls = pretty_debug.then(|| expand_pretty_debug(s));
+    let repr = transparent.then(|| quote!(#[repr(transparent)]));
+    let fields = expand_fields(fields, &[], array_repr, cx);
 
     let doc = docs.then(|| mk_doc(format!("```solidity\n{s}\n```")));
     let tokens = quote! {
@@ -66,16 +141,25 @@ pub(super) fn expand(cx: &ExpCtxt<'_>, s: &ItemStruct) -> Result<TokenStream> {
         #doc
         #[allow(non_camel_case_types, non_snake_case)]
         #[derive(Clone)]
+        #repr
         pub struct #name {
             #(#fields),*
         }
 
+        #builder_struct
+
         #[allow(non_camel_case_types, non_snake_case, clippy::style)]
         const _: () = {
-            use #alloy_sol_types as alloy_sol_types;
+            #sol_types_alias
 
             #convert
 
+            #transparent_impls
+
+            #pretty_debug_impls
+
+            #builder_impls
+
             #[automatically_derived]
             impl alloy_sol_types::SolValue for #name {
                 type SolType = Self;
@@ -164,7 +248,7 @@ pub(super) fn expand(cx: &ExpCtxt<'_>, s: &ItemStruct) -> Result<TokenStream> {
                 fn topic_preimage_length(rust: &Self::RustType) -> usize {
                     0usize
                     #(
-                        + <#field_types as alloy_sol_types::EventTopic>::topic_preimage_length(&rust.#field_names)
+                        + <#field_types as alloy_sol_types::EventTopic>::topic_preimage_length(#topic_fields)
                     )*
                 }
 
@@ -172,7 +256,7 @@ pub(super) fn expand(cx: &ExpCtxt<'_>, s: &ItemStruct) -> Result<TokenStream> {
                 fn encode_topic_preimage(rust: &Self::RustType, out: &mut alloy_sol_types::private::Vec<u8>) {
                     out.reserve(<Self as alloy_sol_types::EventTopic>::topic_preimage_length(rust));
                     #(
-                        <#field_types as alloy_sol_types::EventTopic>::encode_topic_preimage(&rust.#field_names, out);
+                        <#field_types as alloy_sol_types::EventTopic>::encode_topic_preimage(#topic_fields, out);
                     )*
                 }
 
@@ -190,6 +274,143 @@ pub(super) fn expand(cx: &ExpCtxt<'_>, s: &ItemStruct) -> Result<TokenStream> {
     Ok(tokens)
 }
 
+/// Removes `Debug` from the `#[derive(...)]` attributes, as it is implemented by
+/// [`expand_pretty_debug`] instead.
+fn remove_debug_derive(attrs: &mut [Attribute]) {
+    for attr in attrs.iter_mut().filter(|attr| attr.path().is_ident("derive")) {
+        let derives = parse_derives(attr)
+            .into_iter()
+            .filter(|path| path.segments.last().map_or(true, |segment| segment.ident != "Debug"));
+        *attr = parse_quote!(#[derive(#(#derives),*)]);
+    }
+}
+
+/// Expands the `Debug` implementation of a `#[sol(pretty_debug)]` struct.
+///
+/// Every field is formatted with `PrettyDebug`, which is also implemented for the struct so that
+/// it can be nested in other `pretty_debug` structs.
+fn expand_pretty_debug(s: &ItemStruct) -> TokenStream {
+    let name = &s.name;
+    let name_s = name.as_string();
+    let field_names = s.fields.iter().map(|f| f.name.as_ref().unwrap());
+    let field_names_s = s.fields.iter().map(|f| f.name.as_ref().unwrap().as_string());
+    quote! {
+        #[automatically_derived]
+        impl ::core::fmt::Debug for #name {
+            fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                f.debug_struct(#name_s)
+                    #(.field(#field_names_s, &alloy_sol_types::private::Pretty(&self.#field_names)))*
+                    .finish()
+            }
+        }
+
+        #[automatically_derived]
+        impl alloy_sol_types::private::PrettyDebug for #name {
+            #[inline]
+            fn pretty_fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                ::core::fmt::Debug::fmt(self, f)
+            }
+        }
+    }
+}
+
+/// Expands the `#[sol(builder)]` type of a struct, returning the builder struct and its impls.
+fn expand_builder(
+    cx: &ExpCtxt<'_>,
+    s: &ItemStruct,
+    array_repr: ArrayRepr,
+    docs: bool,
+) -> Result<(TokenStream, TokenStream)> {
+    let name = &s.name;
+    let builder_name = format_ident!("{}Builder", name.0);
+    let names = s.fields.iter().map(|f| f.name.as_ref().unwrap()).collect::<Vec<_>>();
+    if let Some(field) = names.iter().find(|n| n.as_string() == "build") {
+        let msg = "`builder` is not supported on structs with a field named `build`";
+        return Err(syn::Error::new(field.span(), msg));
+    }
+    let types = s
+        .fields
+        .iter()
+        .map(|f| ty::expand_rust_field_type(&f.ty, array_repr, &cx.crates))
+        .collect::<Vec<_>>();
+    let name_strs = names.iter().map(|n| n.as_string());
+    let name_s = name.as_string();
+
+    let builder_doc = docs
+        .then(|| mk_doc(format!("Builder for [`{name_s}`], created with [`{name_s}::builder`].")));
+    let ctor_doc = docs.then(|| mk_doc(format!("Creates a new builder for [`{name_s}`].")));
+    let setter_docs =
+        names.iter().map(|n| docs.then(|| mk_doc(format!("Sets the `{}` field.", n.as_string()))));
+    let build_doc = docs.then(|| {
+        mk_doc(format!(
+            "Builds the [`{name_s}`], or returns an error listing all the fields that were not set."
+        ))
+    });
+
+    // with no fields, both match arms would be `()`; empty structs are currently rejected by the
+    // parser, so this only guards against that changing
+    let build_body = if names.is_empty() {
+        quote!(::core::result::Result::Ok(#name {}))
+    } else {
+        quote! {
+            match (#(self.#names,)*) {
+                (#(::core::option::Option::Some(#names),)*) => {
+                    ::core::result::Result::Ok(#name { #(#names),* })
+                }
+                (#(#names,)*) => {
+                    let mut fields = alloy_sol_types::private::Vec::new();
+                    #(
+                        if #names.is_none() {
+                            fields.push(#name_strs);
+                        }
+                    )*
+                    ::core::result::Result::Err(alloy_sol_types::MissingFieldError {
+                        name: #name_s,
+                        fields,
+                    })
+                }
+            }
+        }
+    };
+
+    let builder_struct = quote! {
+        #builder_doc
+        #[allow(non_camel_case_types, non_snake_case)]
+        #[derive(Clone, Default)]
+        pub struct #builder_name {
+            #(#names: ::core::option::Option<#types>,)*
+        }
+    };
+    let builder_impls = quote! {
+        #[automatically_derived]
+        impl #name {
+            #ctor_doc
+            #[inline]
+            pub fn builder() -> #builder_name {
+                ::core::default::Default::default()
+            }
+        }
+
+        #[automatically_derived]
+        impl #builder_name {
+            #(
+                #setter_docs
+                #[inline]
+                pub fn #names(mut self, #names: #types) -> Self {
+                    self.#names = ::core::option::Option::Some(#names);
+                    self
+                }
+            )*
+
+            #build_doc
+            pub fn build(self) -> ::core::result::Result<#name, alloy_sol_types::MissingFieldError> {
+                #build_body
+            }
+        }
+    };
+    Ok((builder_struct, builder_impls))
+}
+
 fn expand_encode_type_fns(
     cx: &ExpCtxt<'_>,
     fields: &ast::Parameters<syn::token::Semi>,
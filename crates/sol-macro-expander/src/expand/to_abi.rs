@@ -85,8 +85,11 @@ impl ToAbi for ast::FunctionAttributes {
 
     fn to_dyn_abi(&self, _cx: &ExpCtxt<'_>) -> Self::DynAbi {
         match self.mutability() {
-            Some(ast::Mutability::Pure(_) | ast::Mutability::Constant(_)) => StateMutability::Pure,
-            Some(ast::Mutability::View(_)) => StateMutability::View,
+            Some(ast::Mutability::Pure(_)) => StateMutability::Pure,
+            // The legacy `constant` modifier was replaced by `view` in Solidity 0.4.17: it
+            // disallowed state modifications, but still allowed reading state. This matches the
+            // `STATE_MUTABILITY` of the generated call type.
+            Some(ast::Mutability::View(_) | ast::Mutability::Constant(_)) => StateMutability::View,
             Some(ast::Mutability::Payable(_)) => StateMutability::Payable,
             None => StateMutability::NonPayable,
         }
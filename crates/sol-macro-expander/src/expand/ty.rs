@@ -1,6 +1,7 @@
 //! [`Type`] expansion.
 
 use super::{ExpCtxt, ExternCrates};
+use alloy_sol_macro_input::ArrayRepr;
 use ast::{Item, Parameters, Spanned, Type, TypeArray};
 use proc_macro2::{Ident, Literal, TokenStream};
 use proc_macro_error::abort;
@@ -26,6 +27,31 @@ pub fn expand_rust_type(ty: &Type, crates: &ExternCrates) -> TokenStream {
     tokens
 }
 
+/// Expands the Rust type of a field, using `Box<[T]>` instead of `Vec<T>` for a dynamic array
+/// when `array_repr` is [`ArrayRepr::Box`].
+///
+/// Only the outermost array is affected: `T[][]` becomes `Box<[Vec<T>]>`.
+pub fn expand_rust_field_type(
+    ty: &Type,
+    array_repr: ArrayRepr,
+    crates: &ExternCrates,
+) -> TokenStream {
+    match ty {
+        Type::Array(array) if is_boxed_array(ty, array_repr) => {
+            let alloy_sol_types = &crates.sol_types;
+            let ty = expand_rust_type(&array.ty, crates);
+            quote_spanned! {array.span()=> #alloy_sol_types::private::Box<[#ty]> }
+        }
+        _ => expand_rust_type(ty, crates),
+    }
+}
+
+/// Returns `true` if a field of type `ty` is represented as a `Box<[T]>`. See
+/// [`expand_rust_field_type`].
+pub(super) fn is_boxed_array(ty: &Type, array_repr: ArrayRepr) -> bool {
+    array_repr == ArrayRepr::Box && matches!(ty, Type::Array(array) if array.size.is_none())
+}
+
 /// The [`expand_type`] recursive implementation.
 pub fn rec_expand_type(ty: &Type, crates: &ExternCrates, tokens: &mut TokenStream) {
     let alloy_sol_types = &crates.sol_types;
@@ -251,6 +277,22 @@ pub(super) fn can_derive_default(cx: &ExpCtxt<'_>, ty: &Type) -> bool {
     }
 }
 
+/// Returns whether the given type only contains elementary types, arrays and
+/// tuples, which implement `Debug`, `PartialEq` and `Eq` even without
+/// `#[sol(all_derives)]`.
+pub(super) fn is_elementary_derivable(ty: &Type) -> bool {
+    match ty {
+        Type::Array(a) => is_elementary_derivable(&a.ty),
+        Type::Tuple(tuple) => {
+            tuple.types.len() <= MAX_SUPPORTED_TUPLE_LEN
+                && tuple.types.iter().all(is_elementary_derivable)
+        }
+        // custom types only derive the builtin traits with `all_derives`
+        Type::Custom(_) | Type::Mapping(_) => false,
+        _ => true,
+    }
+}
+
 /// Returns whether the given type can derive the builtin traits listed in
 /// `ExprCtxt::derives`, minus `Default`.
 pub(super) fn can_derive_builtin_traits(cx: &ExpCtxt<'_>, ty: &Type) -> bool {
@@ -12,7 +12,7 @@ pub(super) fn expand(cx: &ExpCtxt<'_>, udt: &ItemUdt) -> Result<TokenStream> {
     let ItemUdt { name, ty, .. } = udt;
 
     let (sol_attrs, mut attrs) = udt.split_attrs()?;
-    cx.type_derives(&mut attrs, std::iter::once(ty), true);
+    cx.type_derives(&mut attrs, &sol_attrs, std::iter::once(ty), true);
 
     let underlying_sol = expand_type(ty, &cx.crates);
     let underlying_rust = expand_rust_type(ty, &cx.crates);
@@ -29,7 +29,7 @@ pub(super) fn expand(cx: &ExpCtxt<'_>, udt: &ItemUdt) -> Result<TokenStream> {
         }
     };
 
-    let alloy_sol_types = &cx.crates.sol_types;
+    let sol_types_alias = cx.sol_types_alias();
 
     let tokens = quote! {
         #(#attrs)*
@@ -38,7 +38,7 @@ pub(super) fn expand(cx: &ExpCtxt<'_>, udt: &ItemUdt) -> Result<TokenStream> {
         pub struct #name(#underlying_rust);
 
         const _: () = {
-            use #alloy_sol_types as alloy_sol_types;
+            #sol_types_alias
 
             #[automatically_derived]
             impl alloy_sol_types::private::SolTypeValue<#name> for #underlying_rust {
@@ -63,6 +63,14 @@ pub(super) fn expand(cx: &ExpCtxt<'_>, udt: &ItemUdt) -> Result<TokenStream> {
                 }
             }
 
+            #[automatically_derived]
+            impl alloy_sol_types::private::PrettyDebug for #name {
+                #[inline]
+                fn pretty_fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                    alloy_sol_types::private::PrettyDebug::pretty_fmt(&self.0, f)
+                }
+            }
+
             #[automatically_derived]
             impl #name {
                 /// The Solidity type name.
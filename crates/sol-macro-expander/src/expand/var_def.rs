@@ -65,7 +65,9 @@ fn expand_returns(cx: &ExpCtxt<'_>, f: &mut ItemFunction) -> Result<()> {
 
     // all types were complex, Solidity doesn't accept this
     if new_returns.is_empty() {
-        return Err(Error::new(f.name().span(), "invalid state variable type"));
+        let msg = "invalid state variable type: all of the struct's members are arrays or \
+                   mappings, so the getter would return nothing";
+        return Err(Error::new(f.name().span(), msg));
     }
 
     returns.returns = new_returns;
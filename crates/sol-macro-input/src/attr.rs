@@ -1,7 +1,7 @@
 use heck::{ToKebabCase, ToLowerCamelCase, ToShoutySnakeCase, ToSnakeCase, ToUpperCamelCase};
 use proc_macro2::TokenStream;
 use quote::quote;
-use syn::{punctuated::Punctuated, Attribute, Error, LitBool, LitStr, Path, Result, Token};
+use syn::{punctuated::Punctuated, Attribute, Error, LitBool, LitStr, Meta, Path, Result, Token};
 
 const DUPLICATE_ERROR: &str = "duplicate attribute";
 const UNKNOWN_ERROR: &str = "unknown `sol` attribute";
@@ -29,16 +29,7 @@ pub fn docs(attrs: &[Attribute]) -> impl Iterator<Item = &Attribute> {
 /// Flattens all the `#[doc = "..."]` attributes into a single string.
 pub fn docs_str(attrs: &[Attribute]) -> String {
     let mut doc = String::new();
-    for attr in docs(attrs) {
-        let syn::Meta::NameValue(syn::MetaNameValue {
-            value: syn::Expr::Lit(syn::ExprLit { lit: syn::Lit::Str(s), .. }),
-            ..
-        }) = &attr.meta
-        else {
-            continue;
-        };
-
-        let value = s.value();
+    for value in doc_values(attrs) {
         if !value.is_empty() {
             if !doc.is_empty() {
                 doc.push('\n');
@@ -49,6 +40,111 @@ pub fn docs_str(attrs: &[Attribute]) -> String {
     doc
 }
 
+/// Returns an iterator over the string values of all the `#[doc = "..."]` attributes.
+fn doc_values(attrs: &[Attribute]) -> impl Iterator<Item = String> + '_ {
+    docs(attrs).filter_map(|attr| match &attr.meta {
+        syn::Meta::NameValue(syn::MetaNameValue {
+            value: syn::Expr::Lit(syn::ExprLit { lit: syn::Lit::Str(s), .. }),
+            ..
+        }) => Some(s.value()),
+        _ => None,
+    })
+}
+
+/// The NatSpec `@param` and `@return` tags of a doc comment.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct NatSpec {
+    /// `@param <name> <description>` tags, in order.
+    pub params: Vec<(String, String)>,
+    /// `@return <description>` tags, in order.
+    pub returns: Vec<String>,
+}
+
+impl NatSpec {
+    /// Parses the NatSpec tags from the `#[doc = "..."]` attributes.
+    ///
+    /// A tag's description continues on the following lines until the next tag or an
+    /// empty line.
+    pub fn parse(attrs: &[Attribute]) -> Self {
+        enum Tag {
+            Param,
+            Return,
+        }
+
+        let mut natspec = Self::default();
+        let mut current = None;
+        // keep empty lines, which end the current tag
+        let docs = doc_values(attrs).collect::<Vec<_>>().join("\n");
+        for line in docs.lines() {
+            let line = line.trim();
+            let line = line.strip_prefix('*').map_or(line, str::trim_start);
+            if line.is_empty() {
+                current = None;
+                continue;
+            }
+
+            let Some(tag) = line.strip_prefix('@') else {
+                let description = match current {
+                    Some(Tag::Param) => natspec.params.last_mut().map(|(_, d)| d),
+                    Some(Tag::Return) => natspec.returns.last_mut(),
+                    None => None,
+                };
+                if let Some(description) = description {
+                    description.push(' ');
+                    description.push_str(line);
+                }
+                continue;
+            };
+
+            let (tag, rest) = split_word(tag);
+            current = match tag {
+                "param" => {
+                    let (name, description) = split_word(rest);
+                    natspec.params.push((name.into(), description.into()));
+                    Some(Tag::Param)
+                }
+                "return" => {
+                    natspec.returns.push(rest.into());
+                    Some(Tag::Return)
+                }
+                _ => None,
+            };
+        }
+        natspec
+    }
+
+    /// Returns the description of the parameter with the given name.
+    pub fn param(&self, name: &str) -> Option<&str> {
+        self.params
+            .iter()
+            .find(|(n, _)| n == name)
+            .map(|(_, d)| d.as_str())
+            .filter(|d| !d.is_empty())
+    }
+
+    /// Returns the description of the `i`th return value.
+    ///
+    /// Descriptions of named return values start with the name, which is stripped.
+    pub fn return_value(&self, i: usize, name: Option<&str>) -> Option<&str> {
+        let mut description = self.returns.get(i)?.as_str();
+        if let Some(name) = name {
+            let (first, rest) = split_word(description);
+            if first == name {
+                description = rest;
+            }
+        }
+        Some(description).filter(|d| !d.is_empty())
+    }
+}
+
+fn split_word(s: &str) -> (&str, &str) {
+    let s = s.trim_start();
+    match s.split_once(char::is_whitespace) {
+        Some((word, rest)) => (word, rest.trim()),
+        None => (s, ""),
+    }
+}
+
 /// Returns an iterator over all the `#[derive(...)]` attributes.
 pub fn derives(attrs: &[Attribute]) -> impl Iterator<Item = &Attribute> {
     attrs.iter().filter(|a| is_derive(a))
@@ -81,10 +177,16 @@ pub struct SolAttrs {
     pub abi: Option<bool>,
     /// `#[sol(all_derives)]`
     pub all_derives: Option<bool>,
+    /// `#[sol(extra_derives(serde::Serialize, arbitrary::Arbitrary))]`
+    pub extra_derives: Option<Vec<Path>>,
     /// `#[sol(extra_methods)]`
     pub extra_methods: Option<bool>,
     /// `#[sol(docs)]`
     pub docs: Option<bool>,
+    /// `#[sol(cfg = "feature = \"erc20\"")]`
+    pub cfg: Option<LitStr>,
+    /// `#[sol(non_exhaustive)]`
+    pub non_exhaustive: Option<bool>,
 
     /// `#[sol(alloy_sol_types = alloy_core::sol_types)]`
     pub alloy_sol_types: Option<Path>,
@@ -105,6 +207,25 @@ pub struct SolAttrs {
 
     /// UDVT only `#[sol(type_check = "my_function")]`
     pub type_check: Option<LitStr>,
+
+    /// Structs only: `#[sol(array_repr = "box")]`
+    pub array_repr: Option<ArrayRepr>,
+    /// Structs only: `#[sol(transparent)]`
+    pub transparent: Option<bool>,
+    /// Structs only: `#[sol(builder)]`
+    pub builder: Option<bool>,
+    /// Structs only: `#[sol(pretty_debug)]`
+    pub pretty_debug: Option<bool>,
+    /// Structs only: `#[sol(serde(rename_all = "camelCase"))]`
+    pub serde: Option<Vec<Meta>>,
+
+    /// Errors only: `#[sol(error_impls)]`
+    pub error_impls: Option<bool>,
+
+    /// Events only: `#[sol(filters)]`
+    pub filters: Option<bool>,
+    /// Contracts only: `#[sol(event_trait)]`
+    pub event_trait: Option<bool>,
 }
 
 impl SolAttrs {
@@ -152,6 +273,32 @@ impl SolAttrs {
                 // `path = "<str>"`
                 let lit = || meta.value()?.parse::<LitStr>();
 
+                // `path(<path>, ...)`
+                let paths = || {
+                    let content;
+                    syn::parenthesized!(content in meta.input);
+                    Punctuated::<Path, Token![,]>::parse_terminated(&content)
+                        .map(|paths| paths.into_iter().collect())
+                };
+
+                // `path(<meta>, ...)`
+                let metas = || {
+                    let content;
+                    syn::parenthesized!(content in meta.input);
+                    Punctuated::<Meta, Token![,]>::parse_terminated(&content)
+                        .map(|metas| metas.into_iter().collect())
+                };
+
+                // `path = "<cfg predicate>"`
+                let cfg = || {
+                    let lit = lit()?;
+                    if let Err(e) = lit.parse::<syn::Meta>() {
+                        let msg = format!("invalid cfg predicate: {e}");
+                        return Err(Error::new(lit.span(), msg));
+                    }
+                    Ok(lit)
+                };
+
                 // `path = "0x<hex>"`
                 let bytes = || {
                     let lit = lit()?;
@@ -166,8 +313,11 @@ impl SolAttrs {
                     rpc => bool()?,
                     abi => bool()?,
                     all_derives => bool()?,
+                    extra_derives => paths()?,
                     extra_methods => bool()?,
                     docs => bool()?,
+                    cfg => cfg()?,
+                    non_exhaustive => bool()?,
 
                     alloy_sol_types => path()?,
                     alloy_contract => path()?,
@@ -179,6 +329,17 @@ impl SolAttrs {
                     deployed_bytecode => bytes()?,
 
                     type_check => lit()?,
+
+                    array_repr => ArrayRepr::from_lit(&lit()?)?,
+                    transparent => bool()?,
+                    builder => bool()?,
+                    pretty_debug => bool()?,
+                    serde => metas()?,
+
+                    error_impls => bool()?,
+
+                    filters => bool()?,
+                    event_trait => bool()?,
                 };
                 Ok(())
             })?;
@@ -305,6 +466,26 @@ impl CasingStyle {
     }
 }
 
+/// Defines the Rust representation of dynamic arrays (`T[]`).
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ArrayRepr {
+    /// `Vec<T>`, the same type that the ABI decoder produces.
+    #[default]
+    Vec,
+    /// `Box<[T]>`, which is one word smaller than `Vec<T>` but cannot be grown.
+    Box,
+}
+
+impl ArrayRepr {
+    fn from_lit(repr: &LitStr) -> Result<Self> {
+        match repr.value().as_str() {
+            "vec" | "Vec" => Ok(Self::Vec),
+            "box" | "Box" => Ok(Self::Box),
+            s => Err(Error::new(repr.span(), format!("unsupported array representation: {s}"))),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -386,6 +567,13 @@ mod tests {
             #[sol(all_derives = "false")] => Err("expected boolean literal"),
             #[sol(all_derives)] #[sol(all_derives)] => Err(DUPLICATE_ERROR),
 
+            #[sol(extra_derives)] => Err("expected parentheses"),
+            #[sol(extra_derives = "Foo")] => Err("expected parentheses"),
+            #[sol(extra_derives())] => Ok(sol_attrs! { extra_derives: vec![] }),
+            #[sol(extra_derives(Foo))] => Ok(sol_attrs! { extra_derives: vec![parse_quote!(Foo)] }),
+            #[sol(extra_derives(serde::Serialize, ::arbitrary::Arbitrary,))] => Ok(sol_attrs! { extra_derives: vec![parse_quote!(serde::Serialize), parse_quote!(::arbitrary::Arbitrary)] }),
+            #[sol(extra_derives(Foo))] #[sol(extra_derives(Bar))] => Err(DUPLICATE_ERROR),
+
             #[sol(extra_methods)] => Ok(sol_attrs! { extra_methods: true }),
             #[sol(extra_methods = true)] => Ok(sol_attrs! { extra_methods: true }),
             #[sol(extra_methods = false)] => Ok(sol_attrs! { extra_methods: false }),
@@ -394,6 +582,34 @@ mod tests {
             #[sol(docs = true)] => Ok(sol_attrs! { docs: true }),
             #[sol(docs = false)] => Ok(sol_attrs! { docs: false }),
 
+            #[sol(cfg)] => Err("expected `=`"),
+            #[sol(cfg = feature)] => Err("expected string literal"),
+            #[sol(cfg = "feature = \"erc20\"")] => Ok(sol_attrs! { cfg: parse_quote!("feature = \"erc20\"") }),
+            #[sol(cfg = "all(unix, not(test))")] => Ok(sol_attrs! { cfg: parse_quote!("all(unix, not(test))") }),
+            #[sol(cfg = "")] => Err("invalid cfg predicate: "),
+            #[sol(cfg = "feature = ")] => Err("invalid cfg predicate: "),
+            #[sol(cfg = "unix")] #[sol(cfg = "test")] => Err(DUPLICATE_ERROR),
+
+            #[sol(non_exhaustive)] => Ok(sol_attrs! { non_exhaustive: true }),
+            #[sol(non_exhaustive = true)] => Ok(sol_attrs! { non_exhaustive: true }),
+            #[sol(non_exhaustive = false)] => Ok(sol_attrs! { non_exhaustive: false }),
+            #[sol(non_exhaustive)] #[sol(non_exhaustive)] => Err(DUPLICATE_ERROR),
+
+            #[sol(error_impls)] => Ok(sol_attrs! { error_impls: true }),
+            #[sol(error_impls = true)] => Ok(sol_attrs! { error_impls: true }),
+            #[sol(error_impls = false)] => Ok(sol_attrs! { error_impls: false }),
+            #[sol(error_impls)] #[sol(error_impls)] => Err(DUPLICATE_ERROR),
+
+            #[sol(filters)] => Ok(sol_attrs! { filters: true }),
+            #[sol(filters = true)] => Ok(sol_attrs! { filters: true }),
+            #[sol(filters = false)] => Ok(sol_attrs! { filters: false }),
+            #[sol(filters)] #[sol(filters)] => Err(DUPLICATE_ERROR),
+
+            #[sol(event_trait)] => Ok(sol_attrs! { event_trait: true }),
+            #[sol(event_trait = true)] => Ok(sol_attrs! { event_trait: true }),
+            #[sol(event_trait = false)] => Ok(sol_attrs! { event_trait: false }),
+            #[sol(event_trait)] #[sol(event_trait)] => Err(DUPLICATE_ERROR),
+
             #[sol(abi)] => Ok(sol_attrs! { abi: true }),
             #[sol(abi = true)] => Ok(sol_attrs! { abi: true }),
             #[sol(abi = false)] => Ok(sol_attrs! { abi: false }),
@@ -435,5 +651,92 @@ mod tests {
             #[sol(type_check = "my_function")] => Ok(sol_attrs! { type_check: parse_quote!("my_function") }),
             #[sol(type_check = "my_function1")] #[sol(type_check = "my_function2")] => Err(DUPLICATE_ERROR),
         }
+
+        array_repr {
+            #[sol(array_repr = "vec")] => Ok(sol_attrs! { array_repr: ArrayRepr::Vec }),
+            #[sol(array_repr = "box")] => Ok(sol_attrs! { array_repr: ArrayRepr::Box }),
+            #[sol(array_repr = "Box")] => Ok(sol_attrs! { array_repr: ArrayRepr::Box }),
+            #[sol(array_repr = "slice")] => Err("unsupported array representation: slice"),
+            #[sol(array_repr = box)] => Err("expected string literal"),
+            #[sol(array_repr = "box")] #[sol(array_repr = "vec")] => Err(DUPLICATE_ERROR),
+        }
+
+        transparent {
+            #[sol(transparent)] => Ok(sol_attrs! { transparent: true }),
+            #[sol(transparent = true)] => Ok(sol_attrs! { transparent: true }),
+            #[sol(transparent = false)] => Ok(sol_attrs! { transparent: false }),
+            #[sol(transparent = "true")] => Err("expected boolean literal"),
+            #[sol(transparent)] #[sol(transparent)] => Err(DUPLICATE_ERROR),
+        }
+
+        builder {
+            #[sol(builder)] => Ok(sol_attrs! { builder: true }),
+            #[sol(builder = true)] => Ok(sol_attrs! { builder: true }),
+            #[sol(builder = false)] => Ok(sol_attrs! { builder: false }),
+            #[sol(builder = "true")] => Err("expected boolean literal"),
+            #[sol(builder)] #[sol(builder)] => Err(DUPLICATE_ERROR),
+        }
+
+        pretty_debug {
+            #[sol(pretty_debug)] => Ok(sol_attrs! { pretty_debug: true }),
+            #[sol(pretty_debug = true)] => Ok(sol_attrs! { pretty_debug: true }),
+            #[sol(pretty_debug = false)] => Ok(sol_attrs! { pretty_debug: false }),
+            #[sol(pretty_debug = "true")] => Err("expected boolean literal"),
+            #[sol(pretty_debug)] #[sol(pretty_debug)] => Err(DUPLICATE_ERROR),
+        }
+
+        serde {
+            #[sol(serde)] => Err("expected parentheses"),
+            #[sol(serde = "camelCase")] => Err("expected parentheses"),
+            #[sol(serde())] => Ok(sol_attrs! { serde: vec![] }),
+            #[sol(serde(rename_all = "camelCase"))] => Ok(sol_attrs! { serde: vec![parse_quote!(rename_all = "camelCase")] }),
+            #[sol(serde(rename_all = "camelCase", deny_unknown_fields,))] => Ok(sol_attrs! { serde: vec![parse_quote!(rename_all = "camelCase"), parse_quote!(deny_unknown_fields)] }),
+            #[sol(serde(deny_unknown_fields))] #[sol(serde(default))] => Err(DUPLICATE_ERROR),
+        }
+    }
+
+    #[test]
+    fn natspec() {
+        let attrs: Vec<Attribute> = vec![
+            parse_quote!(#[doc = " @notice Sends tokens."]),
+            parse_quote!(#[doc = " @param to the recipient"]),
+            parse_quote!(#[doc = " @param amount the amount"]),
+            parse_quote!(#[doc = " to send"]),
+            parse_quote!(#[doc = " @param memo"]),
+            parse_quote!(#[doc = " @return success whether the transfer"]),
+            parse_quote!(#[doc = "   succeeded"]),
+            parse_quote!(#[doc = " @return the new balance"]),
+            parse_quote!(#[doc = ""]),
+            parse_quote!(#[doc = " Not part of the last tag."]),
+        ];
+        let natspec = NatSpec::parse(&attrs);
+        assert_eq!(
+            natspec.params,
+            [
+                ("to".into(), "the recipient".into()),
+                ("amount".into(), "the amount to send".into()),
+                ("memo".into(), String::new()),
+            ]
+        );
+        assert_eq!(natspec.returns, ["success whether the transfer succeeded", "the new balance"]);
+
+        assert_eq!(natspec.param("to"), Some("the recipient"));
+        assert_eq!(natspec.param("amount"), Some("the amount to send"));
+        assert_eq!(natspec.param("memo"), None);
+        assert_eq!(natspec.param("from"), None);
+
+        assert_eq!(
+            natspec.return_value(0, Some("success")),
+            Some("whether the transfer succeeded")
+        );
+        assert_eq!(natspec.return_value(0, None), Some("success whether the transfer succeeded"));
+        assert_eq!(natspec.return_value(1, Some("balance")), Some("the new balance"));
+        assert_eq!(natspec.return_value(2, None), None);
+
+        let block: Vec<Attribute> =
+            vec![parse_quote!(#[doc = "*\n * @param a first\n * @param b second\n "])];
+        let natspec = NatSpec::parse(&block);
+        assert_eq!(natspec.param("a"), Some("first"));
+        assert_eq!(natspec.param("b"), Some("second"));
     }
 }
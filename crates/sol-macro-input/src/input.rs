@@ -1,18 +1,24 @@
 use ast::Spanned;
 use std::path::PathBuf;
 use syn::{
+    braced, parenthesized,
     parse::{discouraged::Speculative, Parse, ParseStream},
+    punctuated::Punctuated,
+    token::{Brace, Paren},
     Attribute, Error, Ident, LitStr, Result, Token,
 };
 
-/// Parsed input for `sol!`-like macro expanders. This enum represents a `Sol` file, a JSON ABI, or
-/// a Solidity type.
+/// Parsed input for `sol!`-like macro expanders. This enum represents a `Sol` file, a JSON ABI, a
+/// Solidity type, or a list of function signatures.
 #[derive(Clone, Debug)]
+#[non_exhaustive]
 pub enum SolInputKind {
     /// Solidity type.
     Type(ast::Type),
     /// Solidity file or snippet.
     Sol(ast::File),
+    /// Function signatures of a `selectors { ... }` input.
+    Selectors(Vec<SelectorSignature>),
     /// JSON ABI file
     #[cfg(feature = "json")]
     Json(Ident, alloy_json_abi::ContractObject),
@@ -39,15 +45,42 @@ impl Parse for SolInputKind {
     }
 }
 
+/// A function signature in a `selectors { ... }` input, e.g. `transfer(address,uint256)`.
+#[derive(Clone, Debug)]
+pub struct SelectorSignature {
+    /// The function name.
+    pub name: ast::SolIdent,
+    /// The parentheses around the parameter types.
+    pub paren_token: Paren,
+    /// The parameter types.
+    pub types: Punctuated<ast::Type, Token![,]>,
+}
+
+impl Parse for SelectorSignature {
+    fn parse(input: ParseStream<'_>) -> Result<Self> {
+        let content;
+        Ok(Self {
+            name: input.parse()?,
+            paren_token: parenthesized!(content in input),
+            types: content.parse_terminated(ast::Type::parse, Token![,])?,
+        })
+    }
+}
+
 /// Parsed input for `sol!`-like macro expanders. This struct represents a list
 /// of expandable items parsed from either solidity code snippets, or from a
 /// JSON abi.
 #[derive(Clone, Debug)]
+#[non_exhaustive]
 pub struct SolInput {
     /// Attributes attached to the input, of the form `#[...]`.
     pub attrs: Vec<Attribute>,
     /// Path to the input, if any.
     pub path: Option<PathBuf>,
+    /// The name of the module to generate the Solidity items in, if any.
+    ///
+    /// This is set by the `sol!(name, "path/to/file.sol")` syntax.
+    pub module: Option<Ident>,
     /// The input kind.
     pub kind: SolInputKind,
 }
@@ -62,13 +95,33 @@ impl Parse for SolInput {
 
         if fork.peek(LitStr) || (fork.peek(Ident) && fork.peek2(Token![,]) && fork.peek3(LitStr)) {
             Self::parse_abigen(attrs, input)
+        } else if fork.cursor().ident().map_or(false, |(ident, _)| ident == "selectors")
+            && fork.peek2(Brace)
+        {
+            Self::parse_selectors(attrs, input)
         } else {
-            input.parse().map(|kind| Self { attrs, path: None, kind })
+            input.parse().map(|kind| Self { attrs, path: None, module: None, kind })
         }
     }
 }
 
 impl SolInput {
+    /// Selector-only syntax: `sol!(selectors { transfer(address,uint256), ... })`
+    fn parse_selectors(mut attrs: Vec<Attribute>, input: ParseStream<'_>) -> Result<Self> {
+        attrs.extend(Attribute::parse_outer(input)?);
+
+        input.parse::<Ident>()?;
+        let content;
+        braced!(content in input);
+        let signatures = content.parse_terminated(SelectorSignature::parse, Token![,])?;
+        Ok(Self {
+            attrs,
+            path: None,
+            module: None,
+            kind: SolInputKind::Selectors(signatures.into_iter().collect()),
+        })
+    }
+
     /// `abigen`-like syntax: `sol!(name, "path/to/file")`
     fn parse_abigen(mut attrs: Vec<Attribute>, input: ParseStream<'_>) -> Result<Self> {
         attrs.extend(Attribute::parse_outer(input)?);
@@ -122,7 +175,7 @@ impl SolInput {
                 let json = serde_json::from_str(s)
                     .map_err(|e| Error::new(span, format!("invalid JSON: {e}")))?;
                 let name = name.ok_or_else(|| Error::new(span, "need a name for JSON ABI"))?;
-                Ok(Self { attrs, path, kind: SolInputKind::Json(name, json) })
+                Ok(Self { attrs, path, module: None, kind: SolInputKind::Json(name, json) })
             }
             #[cfg(not(feature = "json"))]
             {
@@ -130,15 +183,15 @@ impl SolInput {
                 Err(Error::new(span, msg))
             }
         } else {
-            if let Some(name) = name {
-                let msg = "names are not allowed outside of JSON ABI, remove this name";
-                return Err(Error::new(name.span(), msg));
-            }
             let kind = syn::parse_str(s).map_err(|e| {
                 let msg = format!("expected a valid JSON ABI string or Solidity string: {e}");
                 Error::new(span, msg)
             })?;
-            Ok(Self { attrs, path, kind })
+            if let (Some(name), SolInputKind::Type(_)) = (&name, &kind) {
+                let msg = "names are not allowed for Solidity types, remove this name";
+                return Err(Error::new(name.span(), msg));
+            }
+            Ok(Self { attrs, path, module: name, kind })
         }
     }
 }
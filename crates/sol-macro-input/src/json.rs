@@ -10,6 +10,7 @@ impl SolInput {
         let SolInput {
             attrs,
             path,
+            module,
             kind: SolInputKind::Json(name, ContractObject { abi, bytecode, deployed_bytecode }),
         } = self
         else {
@@ -59,7 +60,7 @@ Generated by the following Solidity interface...
         })?;
 
         let kind = SolInputKind::Sol(ast);
-        Ok(SolInput { attrs, path, kind })
+        Ok(SolInput { attrs, path, module, kind })
     }
 }
 
@@ -11,11 +11,12 @@ extern crate syn_solidity as ast;
 /// Tools for working with `#[...]` attributes.
 mod attr;
 pub use attr::{
-    derives_mapped, docs_str, mk_doc, parse_derives, CasingStyle, ContainsSolAttrs, SolAttrs,
+    derives_mapped, docs_str, mk_doc, parse_derives, ArrayRepr, CasingStyle, ContainsSolAttrs,
+    NatSpec, SolAttrs,
 };
 
 mod input;
-pub use input::{SolInput, SolInputKind};
+pub use input::{SelectorSignature, SolInput, SolInputKind};
 
 mod expander;
 pub use expander::SolInputExpander;
@@ -4,6 +4,8 @@ use alloy_sol_types::{sol, SolCall, SolError};
 sol! {
     function foo(uint256 a, uint256 b) external view returns (uint256);
 
+    function getReserves() external view returns (uint112 reserve0, uint112 reserve1, uint32 blockTimestampLast);
+
     // These will generate structs prefixed with `overloaded_0`, `overloaded_1`,
     // and `overloaded_2` by default, but each signature is calculated with
     // `overloaded` as the function name.
@@ -24,7 +26,13 @@ fn function() {
     assert_call_signature::<fooCall>("foo(uint256,uint256)");
 
     let call = fooCall { a: U256::from(1), b: U256::from(2) };
-    let _call_data = call.abi_encode();
+    let call_data = call.abi_encode();
+
+    // Calldata can be parsed with `TryFrom`, which checks the selector.
+    let decoded: fooCall = call_data.as_slice().try_into().unwrap();
+    assert_eq!((decoded.a, decoded.b), (call.a, call.b));
+    assert!(overloaded_1Call::try_from(call_data.as_slice()).is_err());
+    assert_eq!(fooCall::try_from(&call_data[..3]).err(), Some(alloy_sol_types::Error::Overrun));
 
     let _ = overloaded_0Call {};
     assert_call_signature::<overloaded_0Call>("overloaded()");
@@ -41,6 +49,22 @@ fn function() {
     let _ = variableGetterReturn { v: false };
 }
 
+#[test]
+fn return_tuple() {
+    let data = getReservesReturn { reserve0: 1, reserve1: 2, blockTimestampLast: 3 }.abi_encode();
+
+    // Return values can be decoded into a tuple, in declaration order, instead of the
+    // `getReservesReturn` struct.
+    let (reserve0, reserve1, timestamp) =
+        getReservesCall::abi_decode_returns_tuple(&data, true).unwrap();
+    assert_eq!((reserve0, reserve1, timestamp), (1, 2, 3));
+
+    // A single return value is decoded into a one-element tuple.
+    let data = fooCall::abi_encode_returns(&(U256::from(4),));
+    let (value,) = fooCall::abi_decode_returns_tuple(&data, true).unwrap();
+    assert_eq!(value, U256::from(4));
+}
+
 #[test]
 fn error() {
     assert_error_signature::<MyError>("MyError(uint256,uint256)");
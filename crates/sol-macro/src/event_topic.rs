@@ -0,0 +1,59 @@
+//! `#[derive(EventTopic)]` expansion.
+
+use alloy_sol_macro_input::SolAttrs;
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{parse_quote, Data, DeriveInput, Error, Fields, Result};
+
+pub(crate) fn expand(input: DeriveInput) -> Result<TokenStream> {
+    let DeriveInput { attrs, ident, mut generics, data, .. } = input;
+
+    let (sol_attrs, _) = SolAttrs::parse(&attrs)?;
+    let alloy_sol_types =
+        sol_attrs.alloy_sol_types.unwrap_or_else(|| parse_quote!(::alloy_sol_types));
+
+    let inner = match &data {
+        Data::Struct(data) => match &data.fields {
+            Fields::Named(fields) if fields.named.len() == 1 => &fields.named[0].ty,
+            Fields::Unnamed(fields) if fields.unnamed.len() == 1 => &fields.unnamed[0].ty,
+            fields => return Err(Error::new_spanned(fields, NEWTYPE_ERROR)),
+        },
+        _ => return Err(Error::new_spanned(&ident, NEWTYPE_ERROR)),
+    };
+
+    generics.make_where_clause().predicates.push(parse_quote! {
+        Self: #alloy_sol_types::SolType<
+            RustType = <#inner as #alloy_sol_types::SolType>::RustType
+        >
+    });
+    generics
+        .make_where_clause()
+        .predicates
+        .push(parse_quote!(#inner: #alloy_sol_types::EventTopic));
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    Ok(quote! {
+        #[automatically_derived]
+        impl #impl_generics #alloy_sol_types::EventTopic for #ident #ty_generics #where_clause {
+            #[inline]
+            fn topic_preimage_length(rust: &Self::RustType) -> usize {
+                <#inner as #alloy_sol_types::EventTopic>::topic_preimage_length(rust)
+            }
+
+            #[inline]
+            fn encode_topic_preimage(
+                rust: &Self::RustType,
+                out: &mut #alloy_sol_types::private::Vec<u8>,
+            ) {
+                <#inner as #alloy_sol_types::EventTopic>::encode_topic_preimage(rust, out)
+            }
+
+            #[inline]
+            fn encode_topic(rust: &Self::RustType) -> #alloy_sol_types::abi::token::WordToken {
+                <#inner as #alloy_sol_types::EventTopic>::encode_topic(rust)
+            }
+        }
+    })
+}
+
+const NEWTYPE_ERROR: &str = "`EventTopic` can only be derived for structs with exactly one field";
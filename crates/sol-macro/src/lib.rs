@@ -17,6 +17,8 @@
 #[macro_use]
 extern crate proc_macro_error;
 
+mod event_topic;
+
 use alloy_sol_macro_expander::expand;
 use alloy_sol_macro_input::{SolAttrs, SolInput, SolInputExpander, SolInputKind};
 use proc_macro::TokenStream;
@@ -64,14 +66,23 @@ use syn::parse_macro_input;
 /// Solidity input can be either one of the following:
 /// - a Solidity item, which is a [Solidity source unit][sol-item] which generates one or more Rust
 ///   items,
-/// - a [Solidity type name][sol-types], which simply expands to the corresponding Rust type.
+/// - a [Solidity type name][sol-types], which simply expands to the corresponding Rust type,
+/// - a list of function signatures in a `selectors { ... }` block, which only generates [selector
+///   constants](#selectors).
 ///
 /// **IMPORTANT!** This is **NOT** a Solidity compiler, or a substitute for one! It only parses a
 /// Solidity-like syntax to generate Rust types, designed for simple interfaces defined inline with
 /// your other Rust code.
 ///
-/// Further, this macro does not resolve imports or dependencies, and it does not handle
-/// inheritance. All required types must be provided in the same macro scope.
+/// Further, this macro does not resolve imports or dependencies, and it only handles inheritance
+/// from contracts defined in the same macro scope. All required types must be provided in the same
+/// macro scope.
+///
+/// A path to a Solidity file can be preceded by a module name, like with a [JSON ABI](#json-abi):
+/// `sol!(MyContracts, "path/to/file.sol")` generates all of the file's items in a `pub mod
+/// MyContracts`, which glob-imports its parent module. This is equivalent to wrapping the `sol!`
+/// invocation in a module manually, and allows generating many files side by side without name
+/// conflicts.
 ///
 /// [sol-item]: https://docs.soliditylang.org/en/latest/grammar.html#a4.SolidityParser.sourceUnit
 /// [sol-types]: https://docs.soliditylang.org/en/latest/types.html
@@ -135,17 +146,80 @@ use syn::parse_macro_input;
 /// - `all_derives [ = <bool = false>]`: adds all possible `#[derive(...)]` attributes to all
 ///   generated types. May significantly increase compile times due to all the extra generated code.
 ///   This is the default behavior of [`abigen`]
+/// - `extra_derives(<paths...>)`: adds the given `#[derive(...)]` attributes to all generated
+///   types, in addition to the ones added by `all_derives`. The derive macros must be in scope at
+///   the `sol!` call site. For example, `#![sol(extra_derives(arbitrary::Arbitrary))]` can be used
+///   together with the `arbitrary` feature of [`alloy-sol-types`], which implements `Arbitrary` for
+///   all the primitive types, to fuzz generated types. Dynamic fields such as `Vec<T>`, `String`
+///   and `Bytes` are generated from the fuzzer's input, so their length is bounded by the size of
+///   that input. Note that integer types which are represented by a wider Rust type, such as
+///   `uint24` as `u32`, may be generated with out-of-range values. On an item, e.g.
+///   `#[sol(extra_derives(PartialEq, Eq, Hash))]`, it only applies to that item, or to all of the
+///   contract's items, and takes precedence over the attribute passed to the macro. If a field's
+///   type does not implement a derived trait, the compiler error points to that field.
+///   `all_derives` can be used on an item in the same way
 /// - `extra_methods [ = <bool = false>]`: adds extra implementations and methods to all applicable
-///   generated types, such as `From` impls and `as_<variant>` methods. May significantly increase
-///   compile times due to all the extra generated code. This is the default behavior of [`abigen`]
+///   generated types, such as `TryFrom` impls and `as_<variant>` methods. May significantly
+///   increase compile times due to all the extra generated code. This is the default behavior of
+///   [`abigen`]
 /// - `docs [ = <bool = true>]`: adds doc comments to all generated types. This is the default
-///   behavior of [`abigen`]
+///   behavior of [`abigen`]. This includes the NatSpec `@param` and `@return` descriptions of
+///   functions, events and errors, which are added to the corresponding generated struct fields. As
+///   an inner attribute, e.g. `#![sol(docs = false)]`, it sets the default for all the items in the
+///   macro input; on a contract, it sets the default for all of the contract's items. The attribute
+///   on an item always takes precedence
+/// - `cfg = <string literal>` (top-level items only): gates all the generated Rust items, including
+///   impls and `const` blocks, behind `#[cfg(...)]` with the given predicate, e.g. `#[sol(cfg =
+///   "feature = \"erc20\"")]`. As an inner attribute, it applies to all the items in the macro
+///   input. It cannot be used on items inside of a contract, since the contract's container enums
+///   would still reference them
+/// - `non_exhaustive [ = <bool = false>]` (contracts and alike only): marks the generated
+///   `{contract_name}Calls`, `{contract_name}Errors` and `{contract_name}Events` container enums as
+///   `#[non_exhaustive]`, so that adding new items to the contract is not a breaking change for
+///   downstream crates. Note that this forces downstream `match` expressions on these enums to
+///   include a wildcard (`_ => ...`) arm.
 /// - `bytecode = <hex string literal>` (contract-like only): specifies the creation/init bytecode
 ///   of a contract. This will emit a `static` item with the specified bytes.
 /// - `deployed_bytecode = <hex string literal>` (contract-like only): specifies the deployed
 ///   bytecode of a contract. This will emit a `static` item with the specified bytes.
 /// - `type_check = <string literal>` (UDVT only): specifies a function to be used to check an User
 ///   Defined Type.
+/// - `array_repr = <"vec" | "box">` (structs only): specifies the Rust representation of dynamic
+///   array fields. `"box"` generates `Box<[T]>` fields instead of `Vec<T>`, which are converted
+///   from the decoded `Vec<T>` without reallocating. Only the outermost array of a field is
+///   affected. Defaults to `"vec"`.
+/// - `transparent [ = <bool = false>]` (structs only): generates single-field structs as
+///   `#[repr(transparent)]` newtypes, with `From` conversions to and from the field's Rust type.
+///   This only affects the Rust representation: ABI-wise a single-field struct is still a tuple, so
+///   it is encoded and decoded exactly like `(T,)`, e.g. with an offset if `T` is dynamic. As an
+///   inner attribute, it applies to all single-field structs and is ignored for other structs.
+/// - `builder [ = <bool = false>]` (structs only): generates a `<struct_name>Builder` type with a
+///   setter method for each field, created with `<struct_name>::builder()`. Its `build` method
+///   returns a [`MissingFieldError`] listing all the fields that were not set. As an inner
+///   attribute, it applies to all structs.
+/// - `pretty_debug [ = <bool = false>]` (structs only): implements `Debug` with addresses [EIP-55]
+///   checksummed, integers in decimal and bytes as `0x`-prefixed hex, instead of deriving it.
+///   Arrays and nested structs are formatted the same way, so nested structs must also be
+///   `pretty_debug`. Enum fields are formatted as their variant name, and user-defined value type
+///   fields as their underlying value. As an inner attribute, it applies to all structs.
+/// - `serde(<attrs...>)` (structs only): forwards the given container attributes to the generated
+///   struct as `#[serde(<attrs...>)]`, after its derives. For example, `#[derive(serde::Serialize)]
+///   #[sol(serde(rename_all = "camelCase"))]` serializes `uint256 some_value` as `"someValue"`. The
+///   serde derives must be added separately, e.g. with `extra_derives`. As an inner attribute, it
+///   applies to all structs.
+/// - `event_trait [ = <bool = false>]` (contracts and alike only): generates a
+///   `<contract_name>Event` trait implemented by all of the contract's events and its
+///   `<contract_name>Events` enum, see [Contracts/interfaces](#contractsinterfaces). As an inner
+///   attribute, it applies to all contracts.
+/// - `error_impls [ = <bool = false>]` (errors only): implements `Display` and
+///   `std::error::Error` for the generated error struct, see [Functions and
+///   errors](#functions-and-errors). On a contract, or as an inner attribute, it applies to all
+///   errors.
+/// - `filters [ = <bool = false>]` (events only): generates a `<event_name>Filter` topic filter
+///   type, see [Events](#events). On a contract, or as an inner attribute, it applies to all
+///   events.
+///
+/// [EIP-55]: https://eips.ethereum.org/EIPS/eip-55
 ///
 /// ### Structs and enums
 ///
@@ -177,6 +251,20 @@ use syn::parse_macro_input;
 /// Functions generate two structs that implement `SolCall`: `<name>Call` for
 /// the function arguments, and `<name>Return` for the return values.
 ///
+/// Functions that return exactly one value additionally generate
+/// `<name>Call::abi_decode_returns_single`, which decodes the return data
+/// directly into that value instead of the `<name>Return` struct.
+/// Functions that return values also generate `<name>Call::abi_decode_returns_tuple`, which
+/// decodes the return data into a Rust tuple of the values in declaration order, for quick
+/// destructuring. A single return value is decoded into a one-element tuple `(T,)`, like the
+/// `ReturnTuple`'s Rust type, so that the shape does not depend on the number of return values.
+/// `<name>Return::abi_encode` encodes the return values back into return data,
+/// for example to mock the result of a call.
+///
+/// `<name>Call` also implements `TryFrom<&[u8]>`, which decodes calldata with
+/// `SolCall::abi_decode` with validation enabled, checking the selector. Slices
+/// shorter than a selector return `Error::Overrun`.
+///
 /// In the case of overloaded functions, an underscore and the index of the
 /// function will be appended to `<name>` (like `foo_0`, `foo_1`...) for
 /// disambiguation, but the signature will remain the same.
@@ -184,6 +272,21 @@ use syn::parse_macro_input;
 /// E.g. if there are two functions named `foo`, the generated types will be
 /// `foo_0Call` and `foo_1Call`, each of which will implement `SolCall`
 /// with their respective signatures.
+///
+/// Signatures are canonical, like the ones the Solidity compiler hashes: parameter names and
+/// whitespace are removed, `uint` and `int` are expanded to `uint256` and `int256`, structs are
+/// flattened into tuples, enums become `uint8`, and user-defined value types become their
+/// underlying type. Every generated selector is the hash of its item's `SIGNATURE`:
+/// `keccak256(SIGNATURE)[..4] == SELECTOR` for calls and errors, and
+/// `keccak256(SIGNATURE) == SIGNATURE_HASH` for events. `SolCall::signature_selector` recomputes
+/// the selector at runtime.
+///
+/// Errors generate a struct that implements `SolError`. With `#[sol(error_impls)]`,
+/// it also implements `Display`, which prints the error's signature. If the struct
+/// also implements `Debug`, e.g. through `#[derive(Debug)]`, `#![sol(all_derives)]`, or
+/// because all of its parameters are elementary types (see below),
+/// it will then also implement `std::error::Error` when the `std` feature of
+/// `alloy-sol-types` is enabled.
 /// ```ignore
 #[doc = include_str!("../doctests/function_like.rs")]
 /// ```
@@ -195,7 +298,21 @@ use syn::parse_macro_input;
 /// Note that events have special encoding rules in Solidity. For example,
 /// `string indexed` will be encoded in the topics as its `bytes32` Keccak-256
 /// hash, and as such the generated field for this argument will be `bytes32`,
-/// and not `string`.
+/// and not `string`. The declared types of the indexed parameters are still
+/// available through the generated `TOPIC_TYPES` associated constant, e.g.
+/// `["string", "address"]`.
+///
+/// Events also generate `matches_selector` and `matches_log` associated
+/// functions, which check whether a log's first topic is the event's signature
+/// hash without decoding the log. Anonymous events have no signature topic, so
+/// these always return `false` for them.
+///
+/// With `#[sol(filters)]`, each event also generates a `<name>Filter` type, created
+/// with `<name>::filter()`, which converts into the `[Option<B256>; 4]` topics of a
+/// log filter, such as the ones used by `eth_getLogs`. It has a setter for every indexed parameter, and
+/// parameters that are not set match any value. Indexed dynamic parameters, like
+/// `string indexed`, can only be filtered by the hash of their value, so their
+/// setters take the `bytes32` hash.
 /// ```ignore
 #[doc = include_str!("../doctests/events.rs")]
 /// ```
@@ -207,12 +324,57 @@ use syn::parse_macro_input;
 /// - functions: `<contract_name>Calls`
 /// - errors: `<contract_name>Errors`
 /// - events: `<contract_name>Events`
+///
+/// The container enums always implement `Clone`. The call, return, error and event structs and the
+/// container enums derive `Debug`, `PartialEq` and `Eq` when all their parameters are elementary
+/// types, arrays or tuples, so that decoded values can be compared directly in tests. Custom types
+/// only implement these traits with `#![sol(all_derives)]`, which also derives `Hash` when the
+/// parameters of every variant support them. The variants always hold the owned generated
+/// structs, so no derives are lost to borrowed payloads.
+///
+/// Every variant's payload also converts into its container enum with `From`, including each of
+/// the `<name>_<index>Call` structs generated for overloaded functions, e.g.
+/// `IERC20Calls::from(transfer_0Call { .. })`.
+///
+/// With `#[sol(event_trait)]`, contracts with events also generate a `<contract_name>Event` trait,
+/// which is implemented by
+/// every event struct and by the `<contract_name>Events` enum, so that functions can be generic
+/// over any of the contract's events. It provides `signature_hash`, and `decode_raw_event` and
+/// `decode_event` to decode log topics and data without checking the emitting address.
+///
+/// The functions, errors, events and types of base contracts (`contract C is A, B`) defined in the
+/// same macro scope are included in the derived contract's module and container enums. Each base is
+/// only included once, even when it is reached through multiple paths. Bases defined elsewhere are
+/// ignored.
+///
 /// Note that by default only ABI encoding are generated. In order to generate bindings for RPC
 /// calls, you must enable the `#[sol(rpc)]` attribute.
 /// ```ignore
 #[doc = include_str!("../doctests/contracts.rs")]
 /// ```
 /// 
+/// ### Selectors
+///
+/// When only function selectors are needed, for example to route calls in a proxy, a list of
+/// signatures can be wrapped in `selectors { ... }` to generate `[u8; 4]` constants named after
+/// the functions, without any of the call types. Overloaded functions are suffixed with their
+/// index, in declaration order. Only elementary, array and tuple types are allowed.
+/// ```
+/// use alloy_sol_macro::sol;
+///
+/// sol!(selectors {
+///     transfer(address,uint256),
+///     approve(address,uint256),
+///     safeTransferFrom(address,address,uint256),
+///     safeTransferFrom(address,address,uint256,bytes),
+/// });
+///
+/// assert_eq!(transfer, [0xa9, 0x05, 0x9c, 0xbb]);
+/// assert_eq!(approve, [0x09, 0x5e, 0xa7, 0xb3]);
+/// assert_eq!(safeTransferFrom_0, [0x42, 0x84, 0x2e, 0x0e]);
+/// assert_eq!(safeTransferFrom_1, [0xb8, 0x8d, 0x4f, 0xde]);
+/// ```
+/// 
 /// ## JSON ABI
 ///
 /// Contracts can also be generated from ABI JSON strings and files, similar to
@@ -231,6 +393,7 @@ use syn::parse_macro_input;
 ///
 /// [abigen]: https://docs.rs/ethers/latest/ethers/contract/macro.abigen.html
 /// [`abigen`]: https://docs.rs/ethers/latest/ethers/contract/macro.abigen.html
+/// [`MissingFieldError`]: https://docs.rs/alloy-sol-types/latest/alloy_sol_types/struct.MissingFieldError.html
 /// ```ignore
 #[doc = include_str!("../doctests/json.rs")]
 /// ```
@@ -242,6 +405,22 @@ pub fn sol(input: TokenStream) -> TokenStream {
     SolMacroExpander.expand(&input).unwrap_or_else(syn::Error::into_compile_error).into()
 }
 
+/// Derives [`EventTopic`] for a newtype around a type that already implements it.
+///
+/// The struct must have exactly one field, and must implement `SolType` with the same `RustType`
+/// as the wrapped type. All methods are forwarded to the wrapped type, so dynamic types like
+/// `sol_data::String` are hashed into their topic just like the wrapped type.
+///
+/// The path to the `alloy-sol-types` crate can be changed with
+/// `#[sol(alloy_sol_types = <path>)]`.
+///
+/// [`EventTopic`]: https://docs.rs/alloy-sol-types/latest/alloy_sol_types/trait.EventTopic.html
+#[proc_macro_derive(EventTopic, attributes(sol))]
+pub fn event_topic(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as syn::DeriveInput);
+    event_topic::expand(input).unwrap_or_else(syn::Error::into_compile_error).into()
+}
+
 struct SolMacroExpander;
 
 impl SolInputExpander for SolMacroExpander {
@@ -257,7 +436,7 @@ impl SolInputExpander for SolMacroExpander {
         #[cfg(feature = "json")]
         let input = input.normalize_json()?;
 
-        let SolInput { attrs, path, kind } = input;
+        let SolInput { attrs, path, module, kind, .. } = input;
         let include = path.map(|p| {
             let p = p.to_str().unwrap();
             quote! { const _: &'static [u8] = ::core::include_bytes!(#p); }
@@ -286,10 +465,35 @@ impl SolInputExpander for SolMacroExpander {
                 crates.fill(&sol_attrs);
                 Ok(crate::expand::expand_type(&ty, &crates))
             }
+            SolInputKind::Selectors(signatures) => {
+                // Selectors don't reference any crates, so `#[sol]` attributes are ignored.
+                let (_sol_attrs, rest) = SolAttrs::parse(&attrs)?;
+                if !rest.is_empty() {
+                    return Err(syn::Error::new_spanned(
+                        rest.first().unwrap(),
+                        "only `#[sol]` attributes are allowed here",
+                    ));
+                }
+
+                crate::expand::expand_selectors(&signatures)
+            }
             #[cfg(feature = "json")]
             SolInputKind::Json(_, _) => unreachable!("input already normalized"),
+            _ => Err(syn::Error::new(proc_macro2::Span::call_site(), "unsupported input kind")),
         }?;
 
+        let tokens = match module {
+            Some(name) => quote! {
+                #[allow(non_camel_case_types, non_snake_case, clippy::style)]
+                pub mod #name {
+                    use super::*;
+
+                    #tokens
+                }
+            },
+            None => tokens,
+        };
+
         Ok(quote! {
             #include
             #tokens
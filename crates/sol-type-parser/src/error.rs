@@ -47,6 +47,12 @@ impl Error {
         Self::_new("invalid identifier string: ", &identifier)
     }
 
+    /// Instantiate a nesting depth error. These are for inputs which are nested
+    /// deeper than the allowed maximum, e.g. `((uint256))` with a maximum of 1.
+    pub fn max_depth_exceeded(max_depth: usize) -> Self {
+        Self::_new("nesting depth exceeds the maximum of ", &max_depth)
+    }
+
     /// Instantiate an invalid size error. Invalid size errors are for valid
     /// primitive types with invalid sizes. E.g. `"uint7"` or `"bytes1337"` or
     /// `"string[aaaaaa]"`.
@@ -14,6 +14,12 @@ extern crate alloc;
 mod error;
 pub use error::{Error, Result};
 
+/// The default maximum nesting depth of tuples when parsing from a string.
+///
+/// Inputs with parentheses nested deeper than this are rejected with an error,
+/// instead of potentially overflowing the stack while parsing.
+pub const DEFAULT_MAX_DEPTH: usize = 64;
+
 /// Solidity ident rules.
 mod ident;
 pub use ident::{is_id_continue, is_id_start, is_valid_identifier, IDENT_REGEX};
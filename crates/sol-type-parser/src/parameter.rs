@@ -1,5 +1,5 @@
 use crate::{
-    utils::{opt_ws_ident, spanned, tuple_parser},
+    utils::{check_list_nesting_depth, check_nesting_depth, opt_ws_ident, spanned, tuple_parser},
     Error, Result, TypeSpecifier,
 };
 use alloc::vec::Vec;
@@ -36,6 +36,7 @@ impl<'a> ParameterSpecifier<'a> {
     /// Parse a parameter from a string.
     #[inline]
     pub fn parse(input: &'a str) -> Result<Self> {
+        check_nesting_depth(input, crate::DEFAULT_MAX_DEPTH)?;
         Self::parser.parse(input).map_err(Error::parser)
     }
 
@@ -99,6 +100,7 @@ impl<'a> Parameters<'a> {
     /// Parse a parameter list from a string.
     #[inline]
     pub fn parse(input: &'a str) -> Result<Self> {
+        check_list_nesting_depth(input, crate::DEFAULT_MAX_DEPTH)?;
         Self::parser.parse(input).map_err(Error::parser)
     }
 
@@ -301,4 +303,24 @@ mod tests {
         );
         ParameterSpecifier::parse("foo storag bar").unwrap_err();
     }
+
+    #[test]
+    fn max_depth() {
+        let max = crate::DEFAULT_MAX_DEPTH;
+        let nested = |n: usize| format!("{}uint256{}", "(".repeat(n), ",)".repeat(n));
+        let err = Error::max_depth_exceeded(max);
+
+        ParameterSpecifier::parse(&nested(max)).unwrap();
+        assert_eq!(ParameterSpecifier::parse(&nested(max + 1)), Err(err.clone()));
+
+        // the parentheses of the parameter list are not a level of nesting
+        Parameters::parse(&format!("({})", nested(max))).unwrap();
+        assert_eq!(Parameters::parse(&format!("({})", nested(max + 1))), Err(err.clone()));
+
+        let signature = |s: &str| crate::utils::parse_signature::<true, _, _>(s, |p| p.span.len());
+        signature(&format!("f({})", nested(max))).unwrap();
+        signature(&format!("f()({})", nested(max))).unwrap();
+        assert_eq!(signature(&format!("f({})", nested(max + 1))).unwrap_err(), err);
+        assert_eq!(signature(&format!("f()({})", nested(max + 1))).unwrap_err(), err);
+    }
 }
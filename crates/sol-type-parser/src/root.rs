@@ -85,10 +85,12 @@ impl<'a> RootType<'a> {
                     return Self("uint8");
                 }
 
-                // Normalize the `u?int` aliases to the canonical `u?int256`
+                // Normalize the `u?int` aliases to the canonical `u?int256`, and the legacy `byte`
+                // alias to `bytes1`
                 match ident {
                     "uint" => Self("uint256"),
                     "int" => Self("int256"),
+                    "byte" => Self("bytes1"),
                     _ => Self(ident),
                 }
             })
@@ -145,5 +147,6 @@ mod tests {
 
         assert_eq!(RootType::parse("int"), Ok(RootType("int256")));
         assert_eq!(RootType::parse("uint"), Ok(RootType("uint256")));
+        assert_eq!(RootType::parse("byte"), Ok(RootType("bytes1")));
     }
 }
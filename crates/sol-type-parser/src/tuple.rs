@@ -1,5 +1,5 @@
 use crate::{
-    utils::{spanned, tuple_parser},
+    utils::{check_nesting_depth, spanned, tuple_parser},
     Error, Result, TypeSpecifier,
 };
 use alloc::vec::Vec;
@@ -50,8 +50,21 @@ impl AsRef<str> for TupleSpecifier<'_> {
 
 impl<'a> TupleSpecifier<'a> {
     /// Parse a tuple specifier from a string.
+    ///
+    /// Tuples may be nested up to [`DEFAULT_MAX_DEPTH`](crate::DEFAULT_MAX_DEPTH)
+    /// levels deep, including this one. Use
+    /// [`parse_with_max_depth`](Self::parse_with_max_depth) to configure this
+    /// limit.
     #[inline]
     pub fn parse(input: &'a str) -> Result<Self> {
+        Self::parse_with_max_depth(input, crate::DEFAULT_MAX_DEPTH)
+    }
+
+    /// Parse a tuple specifier from a string, allowing tuples to be nested up
+    /// to `max_depth` levels deep, including this one.
+    #[inline]
+    pub fn parse_with_max_depth(input: &'a str, max_depth: usize) -> Result<Self> {
+        check_nesting_depth(input, max_depth)?;
         Self::parser.parse(input).map_err(Error::parser)
     }
 
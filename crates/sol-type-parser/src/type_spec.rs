@@ -1,5 +1,5 @@
 use crate::{
-    utils::{spanned, str_parser},
+    utils::{check_nesting_depth, spanned, str_parser},
     Error, Result, TypeStem,
 };
 use alloc::vec::Vec;
@@ -86,8 +86,28 @@ impl AsRef<str> for TypeSpecifier<'_> {
 
 impl<'a> TypeSpecifier<'a> {
     /// Parse a type specifier from a string.
+    ///
+    /// Tuples may be nested up to [`DEFAULT_MAX_DEPTH`](crate::DEFAULT_MAX_DEPTH)
+    /// levels deep. Use [`parse_with_max_depth`](Self::parse_with_max_depth) to
+    /// configure this limit.
     #[inline]
     pub fn parse(s: &'a str) -> Result<Self> {
+        Self::parse_with_max_depth(s, crate::DEFAULT_MAX_DEPTH)
+    }
+
+    /// Parse a type specifier from a string, allowing tuples to be nested up
+    /// to `max_depth` levels deep.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use alloy_sol_type_parser::TypeSpecifier;
+    /// assert!(TypeSpecifier::parse_with_max_depth("((bool,),)", 2).is_ok());
+    /// assert!(TypeSpecifier::parse_with_max_depth("((bool,),)", 1).is_err());
+    /// ```
+    #[inline]
+    pub fn parse_with_max_depth(s: &'a str, max_depth: usize) -> Result<Self> {
+        check_nesting_depth(s, max_depth)?;
         Self::parser.parse(s).map_err(Error::parser)
     }
 
@@ -309,4 +329,26 @@ mod test {
             Err(Error::invalid_type_string("MyStruct"))
         );
     }
+
+    #[test]
+    fn max_depth() {
+        let nested = |n: usize| format!("{}uint256{}", "(".repeat(n), ",)".repeat(n));
+
+        TypeSpecifier::parse(&nested(crate::DEFAULT_MAX_DEPTH)).unwrap();
+        let e = TypeSpecifier::parse(&nested(crate::DEFAULT_MAX_DEPTH + 1)).unwrap_err();
+        assert_eq!(e, Error::max_depth_exceeded(crate::DEFAULT_MAX_DEPTH));
+        assert_error_contains(&e, "nesting depth exceeds the maximum of 64");
+
+        // errors cleanly instead of overflowing the stack
+        let s = nested(1000);
+        assert!(TypeSpecifier::parse(&s).is_err());
+        assert!(TupleSpecifier::parse(&s).is_err());
+        assert!(crate::Parameters::parse(&format!("({s})")).is_err());
+
+        TypeSpecifier::parse_with_max_depth(&nested(100), 100).unwrap();
+        TypeSpecifier::parse_with_max_depth("uint256[][]", 0).unwrap();
+        TypeSpecifier::parse_with_max_depth("(uint256,bool)", 0).unwrap_err();
+        TupleSpecifier::parse_with_max_depth("((uint256,),bool)", 2).unwrap();
+        TupleSpecifier::parse_with_max_depth("((uint256,),bool)", 1).unwrap_err();
+    }
 }
@@ -107,6 +107,40 @@ pub fn opt_ws_ident<'a>(input: &mut &'a str) -> PResult<Option<&'a str>> {
     preceded(space0, opt(identifier)).parse_next(input)
 }
 
+/// Returns an error if the parentheses in `input` are nested deeper than
+/// `max_depth`.
+///
+/// This is checked before parsing, as the parsers are recursive.
+pub fn check_nesting_depth(input: &str, max_depth: usize) -> Result<()> {
+    check_depth(input, max_depth, max_depth)
+}
+
+/// Returns an error if the tuples in the parameter lists of `input` are nested
+/// deeper than `max_depth`.
+///
+/// Unlike [`check_nesting_depth`], the outermost parentheses delimit a
+/// parameter list, and are not counted as a level.
+pub fn check_list_nesting_depth(input: &str, max_depth: usize) -> Result<()> {
+    check_depth(input, max_depth.saturating_add(1), max_depth)
+}
+
+fn check_depth(input: &str, limit: usize, max_depth: usize) -> Result<()> {
+    let mut depth = 0usize;
+    for b in input.bytes() {
+        match b {
+            b'(' => {
+                depth += 1;
+                if depth > limit {
+                    return Err(Error::max_depth_exceeded(max_depth));
+                }
+            }
+            b')' => depth = depth.saturating_sub(1),
+            _ => {}
+        }
+    }
+    Ok(())
+}
+
 // Not public API.
 #[doc(hidden)]
 #[inline]
@@ -120,6 +154,7 @@ pub fn parse_signature<'a, const OUT: bool, F: Fn(ParameterSpecifier<'a>) -> T,
     s: &'a str,
     f: F,
 ) -> Result<(String, Vec<T>, Vec<T>, bool)> {
+    check_list_nesting_depth(s, crate::DEFAULT_MAX_DEPTH)?;
     trace(
         "signature",
         (
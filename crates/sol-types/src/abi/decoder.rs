@@ -9,7 +9,7 @@
 //
 
 use crate::{
-    abi::{encode_sequence, token::TokenSeq, Token},
+    abi::{encode_sequence, token::TokenSeq, Encoder, Token},
     utils, Error, Result, Word,
 };
 use alloc::{borrow::Cow, vec::Vec};
@@ -32,6 +32,8 @@ pub struct Decoder<'de> {
     buf: &'de [u8],
     // The current offset in the buffer.
     offset: usize,
+    // The offset of `buf` in the original input.
+    base: usize,
     // Whether to validate type correctness and blob re-encoding.
     validate: bool,
     /// The current recursion depth.
@@ -77,7 +79,7 @@ impl<'de> Decoder<'de> {
     /// to an identical bytestring.
     #[inline]
     pub const fn new(buf: &'de [u8], validate: bool) -> Self {
-        Self { buf, offset: 0, validate, depth: 0 }
+        Self { buf, offset: 0, base: 0, validate, depth: 0 }
     }
 
     /// Returns the current offset in the buffer.
@@ -147,13 +149,24 @@ impl<'de> Decoder<'de> {
             return Err(Error::RecursionLimitExceeded(RECURSION_LIMIT));
         }
         match self.buf.get(offset..) {
-            Some(buf) => {
-                Ok(Decoder { buf, offset: 0, validate: self.validate, depth: self.depth + 1 })
-            }
-            None => Err(Error::Overrun),
+            Some(buf) => Ok(Decoder {
+                buf,
+                offset: 0,
+                base: self.base + offset,
+                validate: self.validate,
+                depth: self.depth + 1,
+            }),
+            None => Err(self.error_at(offset, Error::Overrun)),
         }
     }
 
+    /// Wraps `error` in an [`Error::Decode`] at `offset` bytes from the start
+    /// of this decoder's buffer.
+    #[cold]
+    pub fn error_at(&self, offset: usize, error: Error) -> Error {
+        Error::decode_at(self.base.saturating_add(offset), error)
+    }
+
     /// Advance the offset by `len` bytes.
     #[inline]
     fn increase_offset(&mut self, len: usize) {
@@ -161,16 +174,22 @@ impl<'de> Decoder<'de> {
     }
 
     /// Peek into the buffer.
+    ///
+    /// If `index` is out of bounds, the returned [`Error::Overrun`] is at the
+    /// current offset.
     #[inline]
     pub fn peek<I: SliceIndex<[u8]>>(&self, index: I) -> Result<&'de I::Output, Error> {
-        self.buf.get(index).ok_or(Error::Overrun)
+        self.buf.get(index).ok_or_else(|| self.error_at(self.offset, Error::Overrun))
     }
 
     /// Peek a slice of size `len` from the buffer at a specific offset, without
     /// advancing the offset.
     #[inline]
     pub fn peek_len_at(&self, offset: usize, len: usize) -> Result<&'de [u8], Error> {
-        self.peek(offset..offset + len)
+        offset
+            .checked_add(len)
+            .and_then(|end| self.buf.get(offset..end))
+            .ok_or_else(|| self.error_at(offset, Error::Overrun))
     }
 
     /// Peek a slice of size `len` from the buffer without advancing the offset.
@@ -196,13 +215,15 @@ impl<'de> Decoder<'de> {
     /// the offset.
     #[inline]
     pub fn peek_offset_at(&self, offset: usize) -> Result<usize> {
-        self.peek_word_at(offset).and_then(|word| utils::as_offset(word, self.validate))
+        self.peek_word_at(offset).and_then(|word| {
+            utils::as_offset(word, self.validate).map_err(|e| self.error_at(offset, e))
+        })
     }
 
     /// Peek a `usize` from the buffer, without advancing the offset.
     #[inline]
     pub fn peek_offset(&self) -> Result<usize> {
-        self.peek_word().and_then(|word| utils::as_offset(word, self.validate))
+        self.peek_offset_at(self.offset)
     }
 
     /// Take a word from the buffer, advancing the offset.
@@ -223,19 +244,26 @@ impl<'de> Decoder<'de> {
     /// Takes a `usize` offset from the buffer by consuming a word.
     #[inline]
     pub fn take_offset(&mut self) -> Result<usize> {
-        self.take_word().and_then(|word| utils::as_offset(word, self.validate))
+        let offset = self.peek_offset()?;
+        self.increase_offset(Word::len_bytes());
+        Ok(offset)
     }
 
     /// Takes a slice of bytes of the given length by consuming up to the next
     /// word boundary.
     pub fn take_slice(&mut self, len: usize) -> Result<&'de [u8]> {
         if self.validate {
+            // `len` comes from the input, so bound it before padding it
+            if len > self.buf.len() {
+                return Err(self.error_at(self.offset, Error::Overrun));
+            }
             let padded_len = utils::next_multiple_of_32(len);
             if self.offset + padded_len > self.buf.len() {
-                return Err(Error::Overrun);
+                return Err(self.error_at(self.offset, Error::Overrun));
             }
-            if !utils::check_zeroes(self.peek(self.offset + len..self.offset + padded_len)?) {
-                return Err(Error::Other(Cow::Borrowed("non-empty bytes after packed array")));
+            if !utils::check_zeroes(&self.buf[self.offset + len..self.offset + padded_len]) {
+                let error = Error::Other(Cow::Borrowed("non-empty bytes after packed array"));
+                return Err(self.error_at(self.offset + len, error));
             }
         }
         self.take_slice_unchecked(len)
@@ -325,6 +353,88 @@ pub fn decode_sequence<'de, T: TokenSeq<'de>>(data: &'de [u8], validate: bool) -
     Ok(result)
 }
 
+/// A reusable scratch buffer for ABI-decoding.
+///
+/// Decoding with validation re-encodes the decoded tokens to check them
+/// against the input, which allocates a new buffer on every call. Holding a
+/// [`DecodeBuffer`] across decodes, e.g. when decoding many values in a loop,
+/// allows reusing that allocation.
+///
+/// The buffer is cleared at the start of every decode, so no data from a
+/// previous decode is ever observed, even if it failed or was of a different
+/// type.
+///
+/// See [`SolType::abi_decode_with`](crate::SolType::abi_decode_with).
+#[derive(Clone, Debug, Default)]
+pub struct DecodeBuffer {
+    encoder: Encoder,
+}
+
+impl DecodeBuffer {
+    /// Instantiate a new empty buffer.
+    #[inline]
+    pub const fn new() -> Self {
+        Self { encoder: Encoder::new() }
+    }
+
+    /// Re-encodes `token` into the buffer and checks that the result is equal
+    /// to `data`.
+    fn reencodes<'a, T: TokenSeq<'a>>(&mut self, token: &T, data: &[u8]) -> bool {
+        self.encoder.clear();
+        self.encoder.append_head_tail(token);
+        let words = self.encoder.words();
+        data.len() == words.len() * 32
+            && words.iter().zip(data.chunks_exact(32)).all(|(word, chunk)| word[..] == *chunk)
+    }
+}
+
+/// ABI-decodes a token by wrapping it in a single-element tuple, using `buf`
+/// as scratch space.
+///
+/// See [`decode`] and [`DecodeBuffer`] for more information.
+#[inline]
+pub fn decode_with<'de, T: Token<'de>>(
+    data: &'de [u8],
+    validate: bool,
+    buf: &mut DecodeBuffer,
+) -> Result<T> {
+    decode_sequence_with::<(T,)>(data, validate, buf).map(|(t,)| t)
+}
+
+/// ABI-decodes top-level function args, using `buf` as scratch space.
+///
+/// See [`decode_params`] and [`DecodeBuffer`] for more information.
+#[inline]
+pub fn decode_params_with<'de, T: TokenSeq<'de>>(
+    data: &'de [u8],
+    validate: bool,
+    buf: &mut DecodeBuffer,
+) -> Result<T> {
+    if T::IS_TUPLE {
+        decode_sequence_with(data, validate, buf)
+    } else {
+        decode_with(data, validate, buf)
+    }
+}
+
+/// Decodes ABI compliant vector of bytes into vector of tokens described by
+/// types param, using `buf` as scratch space.
+///
+/// See [`decode_sequence`] and [`DecodeBuffer`] for more information.
+#[inline]
+pub fn decode_sequence_with<'de, T: TokenSeq<'de>>(
+    data: &'de [u8],
+    validate: bool,
+    buf: &mut DecodeBuffer,
+) -> Result<T> {
+    let mut decoder = Decoder::new(data, validate);
+    let result = decoder.decode_sequence::<T>()?;
+    if validate && !buf.reencodes(&result, data) {
+        return Err(Error::ReserMismatch);
+    }
+    Ok(result)
+}
+
 #[cfg(test)]
 mod tests {
     use crate::{sol, sol_data, utils::pad_usize, SolType, SolValue};
@@ -782,4 +892,136 @@ mod tests {
 
         assert_eq!(<Ty as SolType>::abi_decode(&encoded, false).unwrap(), ty);
     }
+
+    #[test]
+    fn truncated_dyn_array_offset() {
+        type Arr = sol_data::Array<sol_data::Uint<256>>;
+        let encoded = hex!(
+            "0000000000000000000000000000000000000000000000000000000000000020" // offset
+            "0000000000000000000000000000000000000000000000000000000000000003" // length
+            "0000000000000000000000000000000000000000000000000000000000000001" // arr[0]
+            "0000000000000000000000000000000000000000000000000000000000000002" // arr[1]
+            "0000000000000000000000000000000000000000000000000000000000000003" // arr[2]
+        );
+        assert!(Arr::abi_decode(&encoded, true).is_ok());
+
+        // the last element starts at byte 128
+        for len in [128, 129, 159] {
+            let err = Arr::abi_decode(&encoded[..len], false).unwrap_err();
+            assert_eq!(
+                err,
+                crate::Error::Decode {
+                    offset: 128,
+                    ty: Some("uint256[]".into()),
+                    error: Box::new(crate::Error::Overrun),
+                }
+            );
+            assert_eq!(
+                err.to_string(),
+                "failed to decode uint256[] at offset 128: buffer overrun while deserializing"
+            );
+            assert_eq!(*err.root(), crate::Error::Overrun);
+        }
+
+        // offsets are absolute, even in nested arrays
+        type Nested = (sol_data::Uint<256>, sol_data::Array<Arr>);
+        let value = (U256::from(1), vec![vec![U256::from(2)], vec![U256::from(3), U256::from(4)]]);
+        let encoded = Nested::abi_encode_params(&value);
+        assert_eq!(Nested::abi_decode_params(&encoded, true), Ok(value));
+        assert_eq!(
+            Nested::abi_decode_params(&encoded[..encoded.len() - 1], false),
+            Err(crate::Error::Decode {
+                offset: encoded.len() - 32,
+                ty: Some("(uint256,uint256[][])".into()),
+                error: Box::new(crate::Error::Overrun),
+            })
+        );
+    }
+
+    #[test]
+    fn peek_overrun_offset() {
+        let data = [0u8; 64];
+        let mut decoder = super::Decoder::new(&data, false);
+        decoder.take_word().unwrap();
+        let child = decoder.child(32).unwrap();
+        assert_eq!(
+            decoder.peek(..65),
+            Err(crate::Error::Decode {
+                offset: 32,
+                ty: None,
+                error: Box::new(crate::Error::Overrun),
+            })
+        );
+        assert_eq!(
+            child.error_at(16, crate::Error::Overrun),
+            decoder.error_at(48, crate::Error::Overrun)
+        );
+    }
+
+    #[test]
+    fn huge_packed_length() {
+        let encoded = hex!(
+            "0000000000000000000000000000000000000000000000000000000000000020" // offset
+            "ffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff" // length
+        );
+        for validate in [false, true] {
+            assert!(sol_data::Bytes::abi_decode(&encoded, validate).is_err());
+            assert!(sol_data::String::abi_decode(&encoded, validate).is_err());
+        }
+        assert_eq!(
+            sol_data::Bytes::abi_decode(&encoded, false),
+            Err(crate::Error::Decode {
+                offset: 64,
+                ty: Some("bytes".into()),
+                error: Box::new(crate::Error::Overrun),
+            })
+        );
+    }
+
+    #[test]
+    fn decode_buffer_reuse() {
+        type Pair = (sol_data::Address, sol_data::Bytes);
+        type Strings = sol_data::Array<sol_data::String>;
+
+        let pair = (address!("c02aaa39b223fe8d0a0e5c4f27ead9083c756cc2"), bytes!("deadbeef"));
+        let strings = vec!["a".to_string(), "hello world".repeat(4)];
+        let pair_data = Pair::abi_encode_params(&pair);
+        let strings_data = Strings::abi_encode(&strings);
+        let uint_data = sol_data::Uint::<256>::abi_encode(&U256::from(42u64));
+        // valid without validation, but not re-encodable
+        let mut dirty_uint_data = uint_data.clone();
+        dirty_uint_data.extend_from_slice(&[0u8; 32]);
+
+        let mut buf = super::DecodeBuffer::new();
+        for _ in 0..2 {
+            for validate in [false, true] {
+                assert_eq!(
+                    Strings::abi_decode_with(&strings_data, validate, &mut buf),
+                    Strings::abi_decode(&strings_data, validate)
+                );
+                assert_eq!(
+                    Pair::abi_decode_params_with(&pair_data, validate, &mut buf),
+                    Pair::abi_decode_params(&pair_data, validate)
+                );
+                assert_eq!(
+                    sol_data::Uint::<256>::abi_decode_with(&dirty_uint_data, validate, &mut buf),
+                    sol_data::Uint::<256>::abi_decode(&dirty_uint_data, validate)
+                );
+                assert_eq!(
+                    sol_data::Uint::<256>::abi_decode_with(&uint_data, validate, &mut buf),
+                    Ok(U256::from(42u64))
+                );
+                // truncated data from a previous decode must not leak into this one
+                assert_eq!(
+                    Pair::abi_decode_params_with(&pair_data[..64], validate, &mut buf),
+                    Pair::abi_decode_params(&pair_data[..64], validate)
+                );
+            }
+        }
+        assert_eq!(
+            sol_data::Uint::<256>::abi_decode_with(&dirty_uint_data, true, &mut buf),
+            Err(crate::Error::ReserMismatch)
+        );
+        assert_eq!(Pair::abi_decode_params_with(&pair_data, true, &mut buf).unwrap(), pair);
+    }
 }
@@ -43,6 +43,20 @@ impl Encoder {
         }
     }
 
+    /// Clears the encoder, discarding all the encoded words while keeping the
+    /// allocated capacity.
+    #[inline]
+    pub fn clear(&mut self) {
+        self.buf.clear();
+        self.suffix_offset.clear();
+    }
+
+    /// Returns the words encoded so far.
+    #[inline]
+    pub(crate) fn words(&self) -> &[Word] {
+        &self.buf
+    }
+
     /// Finish the encoding process, returning the encoded words.
     ///
     /// Use `into_bytes` instead to flatten the words into bytes.
@@ -40,10 +40,13 @@ mod encoder;
 pub use encoder::{encode, encode_params, encode_sequence, Encoder};
 
 mod decoder;
-pub use decoder::{decode, decode_params, decode_sequence, Decoder, RECURSION_LIMIT};
+pub use decoder::{
+    decode, decode_params, decode_params_with, decode_sequence, decode_sequence_with, decode_with,
+    DecodeBuffer, Decoder, RECURSION_LIMIT,
+};
 
 pub mod token;
-pub use token::{Token, TokenSeq};
+pub use token::{Token, TokenLocation, TokenSeq};
 
 /// The ABI encoding of an empty byte array (`bytes` or `string`).
 pub const EMPTY_BYTES: &[u8; 64] = &alloy_primitives::hex!(
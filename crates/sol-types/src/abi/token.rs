@@ -13,11 +13,11 @@
 
 use crate::{
     abi::{Decoder, Encoder},
-    Result, Word,
+    Error, Result, Word,
 };
 use alloc::vec::Vec;
 use alloy_primitives::{utils::vec_try_with_capacity, Bytes, FixedBytes, I256, U256};
-use core::fmt;
+use core::{fmt, ops::Range};
 
 #[allow(unknown_lints, unnameable_types)]
 mod sealed {
@@ -55,6 +55,13 @@ pub trait Token<'de>: Sealed + Sized {
     /// True if the token represents a dynamically-sized type.
     const DYNAMIC: bool;
 
+    /// Returns [`DYNAMIC`](Self::DYNAMIC): whether the token is encoded in the tail, and
+    /// referenced by an offset in the head, when it is part of a sequence.
+    #[inline]
+    fn is_dynamic(&self) -> bool {
+        Self::DYNAMIC
+    }
+
     /// Decode a token from a decoder.
     fn decode_from(dec: &mut Decoder<'de>) -> Result<Self>;
 
@@ -91,6 +98,79 @@ pub trait TokenSeq<'a>: Token<'a> {
 
     /// ABI-decode the token sequence from the encoder.
     fn decode_sequence(dec: &mut Decoder<'a>) -> Result<Self>;
+
+    /// Returns the location of each top-level token of this sequence in `data`, the
+    /// ABI-encoded sequence it was decoded from.
+    ///
+    /// Ranges are relative to the start of `data`. The offsets of dynamic tokens are read
+    /// from `data`, and their ranges span their canonical encoded size from there.
+    ///
+    /// Nested tokens are not located. ABI offsets are relative to the enclosing container,
+    /// so call this method on a nested sequence with the data of its container, e.g.
+    /// `&data[location.range]` for a dynamic tuple, to get ranges relative to that
+    /// container. For dynamic arrays, skip the length word first.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use alloy_sol_types::{abi::TokenSeq, sol_data, SolType};
+    ///
+    /// type T = (sol_data::Uint<256>, sol_data::String, sol_data::Bool);
+    /// let data = T::abi_encode_sequence(&(alloy_primitives::U256::from(1), "hi", true));
+    /// let token: <T as SolType>::Token<'_> = alloy_sol_types::abi::decode_sequence(&data, false)?;
+    /// let locations = token.locate(&data)?;
+    ///
+    /// // the string's offset word is in the head, and its length and contents in the tail
+    /// assert!(!locations[0].dynamic);
+    /// assert_eq!(locations[0].range, 0..32);
+    /// assert!(locations[1].dynamic);
+    /// assert_eq!(locations[1].head, 32..64);
+    /// assert_eq!(locations[1].range, 96..160);
+    /// assert_eq!(locations[2].range, 64..96);
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    fn locate(&self, data: &[u8]) -> Result<Vec<TokenLocation>>;
+}
+
+/// The location of a token in an ABI-encoded sequence.
+///
+/// See [`TokenSeq::locate`] for more details.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TokenLocation {
+    /// Whether the token is dynamic. Dynamic tokens are encoded in the tail of the
+    /// sequence, and referenced by an offset word in its head.
+    pub dynamic: bool,
+    /// The byte range of the token's head: the offset word for dynamic tokens, or the
+    /// whole encoding for static tokens.
+    pub head: Range<usize>,
+    /// The byte range of the token's encoding: its tail for dynamic tokens, or its head
+    /// for static tokens.
+    pub range: Range<usize>,
+}
+
+/// Locates `token`, whose head starts at `*head` in `data`, and advances `*head` past it.
+fn locate_token<'de, T: Token<'de>>(
+    token: &T,
+    data: &[u8],
+    head: &mut usize,
+    locations: &mut Vec<TokenLocation>,
+) -> Result<()> {
+    let head_start = *head;
+    let head_end = head_start + token.head_words() * Word::len_bytes();
+    let range = if T::DYNAMIC {
+        let start = Decoder::new(data, false).peek_offset_at(head_start)?;
+        let end =
+            start.checked_add(token.tail_words() * Word::len_bytes()).ok_or(Error::Overrun)?;
+        start..end
+    } else {
+        head_start..head_end
+    };
+    if head_end > data.len() || range.end > data.len() {
+        return Err(Error::Overrun);
+    }
+    locations.push(TokenLocation { dynamic: T::DYNAMIC, head: head_start..head_end, range });
+    *head = head_end;
+    Ok(())
 }
 
 /// A single EVM word - T for any value type.
@@ -321,6 +401,15 @@ impl<'de, T: Token<'de>, const N: usize> TokenSeq<'de> for FixedSeqToken<T, N> {
     fn decode_sequence(dec: &mut Decoder<'de>) -> Result<Self> {
         crate::impl_core::try_from_fn(|_| T::decode_from(dec)).map(Self)
     }
+
+    fn locate(&self, data: &[u8]) -> Result<Vec<TokenLocation>> {
+        let mut locations = Vec::with_capacity(N);
+        let mut head = 0;
+        for inner in &self.0 {
+            locate_token(inner, data, &mut head, &mut locations)?;
+        }
+        Ok(locations)
+    }
 }
 
 impl<T, const N: usize> FixedSeqToken<T, N> {
@@ -425,6 +514,15 @@ impl<'de, T: Token<'de>> TokenSeq<'de> for DynSeqToken<T> {
     fn decode_sequence(dec: &mut Decoder<'de>) -> Result<Self> {
         Self::decode_from(dec)
     }
+
+    fn locate(&self, data: &[u8]) -> Result<Vec<TokenLocation>> {
+        let mut locations = Vec::with_capacity(self.0.len());
+        let mut head = 0;
+        for inner in &self.0 {
+            locate_token(inner, data, &mut head, &mut locations)?;
+        }
+        Ok(locations)
+    }
 }
 
 impl<T> DynSeqToken<T> {
@@ -609,6 +707,16 @@ macro_rules! tuple_impls {
                     },
                 )+))
             }
+
+            fn locate(&self, data: &[u8]) -> Result<Vec<TokenLocation>> {
+                let ($($ty,)+) = self;
+                let mut locations = Vec::with_capacity($count);
+                let mut head = 0;
+                $(
+                    locate_token($ty, data, &mut head, &mut locations)?;
+                )+
+                Ok(locations)
+            }
         }
     };
 }
@@ -648,6 +756,11 @@ impl<'de> TokenSeq<'de> for () {
     fn decode_sequence(_dec: &mut Decoder<'de>) -> Result<Self> {
         Ok(())
     }
+
+    #[inline]
+    fn locate(&self, _data: &[u8]) -> Result<Vec<TokenLocation>> {
+        Ok(Vec::new())
+    }
 }
 
 all_the_tuples!(tuple_impls);
@@ -670,6 +783,59 @@ mod tests {
         };
     }
 
+    #[test]
+    fn locate() {
+        type T = (
+            sol_data::Uint<8>,
+            sol_data::Bytes,
+            (sol_data::Bool, sol_data::String),
+            sol_data::FixedArray<sol_data::Uint<256>, 2>,
+            sol_data::Array<sol_data::String>,
+        );
+        let value = (
+            1,
+            Bytes::from_static(&[0xaa; 33]),
+            (true, String::from("x")),
+            [U256::from(2), U256::from(3)],
+            vec![String::from("a"), String::from("b")],
+        );
+        let data = T::abi_encode_sequence(&value);
+        let token: <T as SolType>::Token<'_> = crate::abi::decode_sequence(&data, true).unwrap();
+        assert!(token.is_dynamic());
+        assert!(!token.0.is_dynamic() && token.1.is_dynamic());
+
+        let locations = token.locate(&data).unwrap();
+        let heads = locations.iter().map(|l| l.head.clone()).collect::<Vec<_>>();
+        assert_eq!(heads, [0..32, 32..64, 64..96, 96..160, 160..192]);
+        let dynamic = locations.iter().map(|l| l.dynamic).collect::<Vec<_>>();
+        assert_eq!(dynamic, [false, true, true, false, true]);
+        // bytes: length word + 2 words of data
+        assert_eq!(locations[1].range, 192..288);
+        // (bool, string): bool + offset + length + data
+        assert_eq!(locations[2].range, 288..416);
+        assert_eq!(locations[3].range, 96..160);
+        // string[]: length + 2 offsets + 2 * (length + data)
+        assert_eq!(locations[4].range, 416..640);
+        assert_eq!(locations.last().unwrap().range.end, data.len());
+
+        // nested ranges are relative to their container
+        let inner = &data[locations[2].range.clone()];
+        let inner_locations = token.2.locate(inner).unwrap();
+        assert_eq!(inner_locations[0].range, 0..32);
+        assert_eq!(inner_locations[1].range, 64..128);
+        assert_eq!(&inner[96..97], b"x");
+
+        let array = &data[locations[4].range.start + 32..locations[4].range.end];
+        let array_locations = token.4.locate(array).unwrap();
+        assert_eq!(array_locations[0].range, 64..128);
+        assert_eq!(array_locations[1].range, 128..192);
+        assert_eq!(&array[160..161], b"b");
+
+        // mismatched data
+        assert_eq!(token.locate(&data[..200]), Err(crate::Error::Overrun));
+        assert_eq!(().locate(&data), Ok(vec![]));
+    }
+
     #[test]
     fn test_type_check() {
         assert_type_check!(
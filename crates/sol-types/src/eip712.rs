@@ -57,6 +57,11 @@ impl Eip712Domain {
     }
 
     /// Calculate the domain separator for the domain object.
+    ///
+    /// This is the [`hash_struct`](Self::hash_struct) of the domain, which only depends on the
+    /// domain itself and can be cached across messages. Only the fields that are present are
+    /// included, in the canonical order: `name`, `version`, `chainId`, `verifyingContract`,
+    /// `salt`.
     #[inline]
     pub fn separator(&self) -> B256 {
         self.hash_struct()
@@ -323,4 +328,37 @@ mod tests {
             version: my_cow.into_owned(),
         };
     }
+
+    #[test]
+    fn separator() {
+        // USDC on Ethereum mainnet, `DOMAIN_SEPARATOR()`.
+        let usdc = eip712_domain! {
+            name: "USD Coin",
+            version: "2",
+            chain_id: 1,
+            verifying_contract: alloy_primitives::address!("A0b86991c6218b36c1d19D4a2e9Eb0cE3606eB48"),
+        };
+        assert_eq!(
+            usdc.separator(),
+            alloy_primitives::b256!(
+                "06c37168a7db5138defc7866392bb87a741f9b3d104deb5094588ce041cae335"
+            )
+        );
+
+        // Absent fields are left out of both the type string and the encoded data.
+        let domain = eip712_domain! {
+            name: "Permit2",
+            chain_id: 1,
+            verifying_contract: Address::with_last_byte(1),
+        };
+        assert_eq!(
+            domain.encode_type(),
+            "EIP712Domain(string name,uint256 chainId,address verifyingContract)"
+        );
+        let mut encoded = keccak256(domain.encode_type()).to_vec();
+        encoded.extend_from_slice(keccak256("Permit2").as_slice());
+        encoded.extend_from_slice(&U256::from(1).to_be_bytes::<32>());
+        encoded.extend_from_slice(Address::with_last_byte(1).into_word().as_slice());
+        assert_eq!(domain.separator(), keccak256(encoded));
+    }
 }
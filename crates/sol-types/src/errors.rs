@@ -8,7 +8,7 @@
 // except according to those terms.
 
 use crate::abi;
-use alloc::{borrow::Cow, boxed::Box, collections::TryReserveError, string::String};
+use alloc::{borrow::Cow, boxed::Box, collections::TryReserveError, string::String, vec::Vec};
 use alloy_primitives::LogData;
 use core::fmt;
 
@@ -67,6 +67,20 @@ pub enum Error {
         selector: alloy_primitives::FixedBytes<4>,
     },
 
+    /// An error occurred while decoding at a byte offset in the input.
+    ///
+    /// This wraps the errors returned by the [`Decoder`](abi::Decoder), such
+    /// as [`Error::Overrun`]. Use [`Error::root`] to match on the underlying
+    /// error regardless of whether it has this context.
+    Decode {
+        /// The byte offset in the input at which decoding failed.
+        offset: usize,
+        /// The Solidity type that was being decoded, if known.
+        ty: Option<Cow<'static, str>>,
+        /// The underlying error.
+        error: Box<Self>,
+    },
+
     /// Hex error.
     FromHexError(hex::FromHexError),
 
@@ -80,6 +94,7 @@ impl std::error::Error for Error {
         match self {
             Self::Reserve(e) => Some(e),
             Self::FromHexError(e) => Some(e),
+            Self::Decode { error, .. } => Some(&**error),
             _ => None,
         }
     }
@@ -107,6 +122,13 @@ impl fmt::Display for Error {
             Self::UnknownSelector { name, selector } => {
                 write!(f, "unknown selector `{selector}` for {name}")
             }
+            Self::Decode { offset, ty, error } => {
+                f.write_str("failed to decode")?;
+                if let Some(ty) = ty {
+                    write!(f, " {ty}")?;
+                }
+                write!(f, " at offset {offset}: {error}")
+            }
             Self::FromHexError(e) => e.fmt(f),
             Self::Other(e) => f.write_str(e),
         }
@@ -142,6 +164,49 @@ impl Error {
         Self::TypeCheckFail { expected_type: expected_type.into(), data: hex::encode(data) }
     }
 
+    /// Returns the underlying error, without any decoding context.
+    ///
+    /// Decoding errors such as [`Error::Overrun`] are wrapped in
+    /// [`Error::Decode`], so match on this instead of on the error itself.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use alloy_sol_types::{sol_data, Error, SolType};
+    ///
+    /// let err = sol_data::Uint::<256>::abi_decode(&[0; 31], false).unwrap_err();
+    /// assert_eq!(
+    ///     err.to_string(),
+    ///     "failed to decode uint256 at offset 0: buffer overrun while deserializing"
+    /// );
+    /// assert_eq!(*err.root(), Error::Overrun);
+    /// ```
+    pub fn root(&self) -> &Self {
+        match self {
+            Self::Decode { error, .. } => error.root(),
+            _ => self,
+        }
+    }
+
+    /// Instantiates a new [`Error::Decode`] without a type.
+    #[cold]
+    pub(crate) fn decode_at(offset: usize, error: Self) -> Self {
+        Self::Decode { offset, ty: None, error: Box::new(error) }
+    }
+
+    /// Sets the type of an [`Error::Decode`], if it was not already set.
+    ///
+    /// Other errors are returned unchanged.
+    #[cold]
+    pub fn with_decode_type(self, ty: impl Into<Cow<'static, str>>) -> Self {
+        match self {
+            Self::Decode { offset, ty: None, error } => {
+                Self::Decode { offset, ty: Some(ty.into()), error }
+            }
+            error => error,
+        }
+    }
+
     /// Instantiates a new [`Error::UnknownSelector`] with the provided data.
     #[cold]
     pub fn unknown_selector(name: &'static str, selector: [u8; 4]) -> Self {
@@ -162,3 +227,29 @@ impl From<TryReserveError> for Error {
         Self::Reserve(value)
     }
 }
+
+/// Error returned by the `build` method of the builders generated with
+/// `#[sol(builder)]` when some of the fields were not set.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MissingFieldError {
+    /// The name of the struct being built.
+    pub name: &'static str,
+    /// The names of all the fields that were not set, in declaration order.
+    pub fields: Vec<&'static str>,
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for MissingFieldError {}
+
+impl fmt::Display for MissingFieldError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "missing fields when building {}: ", self.name)?;
+        for (i, field) in self.fields.iter().enumerate() {
+            if i > 0 {
+                f.write_str(", ")?;
+            }
+            write!(f, "`{field}`")?;
+        }
+        Ok(())
+    }
+}
@@ -21,7 +21,7 @@ mod macros;
 pub mod abi;
 
 mod errors;
-pub use errors::{Error, Result};
+pub use errors::{Error, MissingFieldError, Result};
 
 #[cfg(feature = "json")]
 mod ext;
@@ -30,6 +30,8 @@ pub use ext::JsonAbiExt;
 
 mod impl_core;
 
+mod pretty;
+
 mod types;
 pub use types::{
     data_type as sol_data, decode_revert_reason, ContractError, EventTopic, GenericContractError,
@@ -47,7 +49,7 @@ pub use eip712::Eip712Domain;
 pub type Word = alloy_primitives::B256;
 
 #[doc(no_inline)]
-pub use alloy_sol_macro::sol;
+pub use alloy_sol_macro::{sol, EventTopic};
 
 // Not public API.
 #[doc(hidden)]
@@ -55,6 +57,7 @@ pub use alloy_sol_macro::sol;
 pub mod private {
     pub use super::{
         abi::RECURSION_LIMIT,
+        pretty::{Pretty, PrettyDebug},
         utils::{just_ok, next_multiple_of_32, words_for, words_for_len},
     };
     pub use alloc::{
@@ -71,7 +74,7 @@ pub mod private {
     };
     pub use core::{
         borrow::{Borrow, BorrowMut},
-        convert::From,
+        convert::{From, TryFrom},
         default::Default,
         option::Option,
         result::Result,
@@ -83,6 +86,14 @@ pub mod private {
     #[cfg(feature = "json")]
     pub use alloy_json_abi;
 
+    #[cfg(feature = "std")]
+    pub use std::error::Error as StdError;
+
+    /// Stand-in for `std::error::Error` in `no_std` builds, so that the `sol!`
+    /// generated implementations compile regardless of the `std` feature.
+    #[cfg(not(feature = "std"))]
+    pub trait StdError: core::fmt::Debug + core::fmt::Display {}
+
     /// An ABI-encodable is any type that may be encoded via a given `SolType`.
     ///
     /// The `SolType` trait contains encoding logic for a single associated
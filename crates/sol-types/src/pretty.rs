@@ -0,0 +1,146 @@
+//! Human-readable `Debug` formatting, used by `#[sol(pretty_debug)]` structs.
+
+use alloc::{boxed::Box, string::String, vec::Vec};
+use alloy_primitives::{Address, Bytes, FixedBytes, Function, Signed, Uint};
+use core::fmt;
+
+/// Formats a value for `#[sol(pretty_debug)]` `Debug` implementations.
+///
+/// Addresses are [EIP-55] checksummed, integers are printed in decimal, and
+/// bytes are printed as `0x`-prefixed hex. Arrays and tuples format their
+/// elements with this trait.
+///
+/// **Note:** this trait is an implementation detail, and is implemented for
+/// all `#[sol(pretty_debug)]` structs.
+///
+/// [EIP-55]: https://eips.ethereum.org/EIPS/eip-55
+pub trait PrettyDebug {
+    /// Formats the value using the given formatter.
+    fn pretty_fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result;
+}
+
+/// Wraps a [`PrettyDebug`] value so that its [`Debug`](fmt::Debug)
+/// implementation uses [`PrettyDebug::pretty_fmt`].
+pub struct Pretty<'a, T: ?Sized>(pub &'a T);
+
+impl<T: ?Sized + PrettyDebug> fmt::Debug for Pretty<'_, T> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.pretty_fmt(f)
+    }
+}
+
+macro_rules! impl_debug {
+    ($($t:ty),* $(,)?) => {$(
+        impl PrettyDebug for $t {
+            #[inline]
+            fn pretty_fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                fmt::Debug::fmt(self, f)
+            }
+        }
+    )*};
+}
+
+impl_debug!(bool, i8, i16, i32, i64, i128, u8, u16, u32, u64, u128, String, Bytes, Function);
+
+impl<const N: usize> PrettyDebug for FixedBytes<N> {
+    #[inline]
+    fn pretty_fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(self, f)
+    }
+}
+
+impl PrettyDebug for Address {
+    #[inline]
+    fn pretty_fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.to_checksum_buffer(None).as_str())
+    }
+}
+
+impl<const BITS: usize, const LIMBS: usize> PrettyDebug for Uint<BITS, LIMBS> {
+    #[inline]
+    fn pretty_fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(self, f)
+    }
+}
+
+impl<const BITS: usize, const LIMBS: usize> PrettyDebug for Signed<BITS, LIMBS> {
+    #[inline]
+    fn pretty_fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(self, f)
+    }
+}
+
+impl<T: PrettyDebug> PrettyDebug for [T] {
+    #[inline]
+    fn pretty_fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.iter().map(Pretty)).finish()
+    }
+}
+
+impl<T: PrettyDebug, const N: usize> PrettyDebug for [T; N] {
+    #[inline]
+    fn pretty_fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.as_slice().pretty_fmt(f)
+    }
+}
+
+impl<T: PrettyDebug> PrettyDebug for Vec<T> {
+    #[inline]
+    fn pretty_fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.as_slice().pretty_fmt(f)
+    }
+}
+
+impl<T: PrettyDebug> PrettyDebug for Box<[T]> {
+    #[inline]
+    fn pretty_fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        (**self).pretty_fmt(f)
+    }
+}
+
+macro_rules! tuple_impls {
+    ($count:literal $($ty:ident),+) => {
+        #[allow(non_snake_case)]
+        impl<$($ty: PrettyDebug,)+> PrettyDebug for ($($ty,)+) {
+            #[inline]
+            fn pretty_fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                let ($($ty,)+) = self;
+                f.debug_tuple("")
+                    $(.field(&Pretty($ty)))+
+                    .finish()
+            }
+        }
+    };
+}
+
+all_the_tuples!(tuple_impls);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::{format, string::ToString};
+    use alloy_primitives::{address, bytes, I256, U256};
+
+    #[test]
+    fn pretty() {
+        let addr = address!("5aaeb6053f3e94c9b9a09f33669435e7ef1beaed");
+        assert_eq!(format!("{:?}", Pretty(&addr)), "0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed");
+        assert_eq!(format!("{:?}", Pretty(&U256::MAX)), U256::MAX.to_string());
+        assert_eq!(format!("{:?}", Pretty(&u128::MAX)), u128::MAX.to_string());
+        assert_eq!(format!("{:?}", Pretty(&i128::MIN)), i128::MIN.to_string());
+        assert_eq!(format!("{:?}", Pretty(&I256::MINUS_ONE)), "-1");
+        assert_eq!(format!("{:?}", Pretty(&bytes!("dead"))), "0xdead");
+        assert_eq!(format!("{:?}", Pretty(&FixedBytes([0xab; 2]))), "0xabab");
+        assert_eq!(
+            format!("{:?}", Pretty(&Function::repeat_byte(0xab))),
+            format!("0x{}", "ab".repeat(24))
+        );
+        assert_eq!(format!("{:?}", Pretty(&vec![[U256::from(1), U256::from(2)]])), "[[1, 2]]");
+        assert_eq!(format!("{:?}", Pretty(&(U256::from(1),))), "(1,)");
+        assert_eq!(
+            format!("{:?}", Pretty(&(I256::MINUS_ONE, (addr, bytes!("dead"))))),
+            "(-1, (0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed, 0xdead))"
+        );
+    }
+}
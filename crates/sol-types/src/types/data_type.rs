@@ -9,28 +9,69 @@
 #![allow(missing_copy_implementations, missing_debug_implementations)]
 
 use crate::{abi::token::*, private::SolTypeValue, utils, SolType, Word};
-use alloc::{string::String as RustString, vec::Vec};
+use alloc::{boxed::Box, string::String as RustString, vec::Vec};
 use alloy_primitives::{
     keccak256, Address as RustAddress, Bytes as RustBytes, FixedBytes as RustFixedBytes,
     Function as RustFunction, I256, U256,
 };
 use core::{borrow::Borrow, fmt::*, hash::Hash, marker::PhantomData, ops::*};
 
+/// Implements `SolTypeValue<$sol>` for `&V` and `&mut V` where `V: SolTypeValue<$sol>`.
+///
+/// This allows encoding borrowed values, and tuples or arrays of borrowed values, without
+/// cloning them. Types whose implementation is already generic over `Borrow` or `AsRef`, like
+/// integers and `bytes`, don't need this.
+macro_rules! ref_impls {
+    ([$($gen:tt)*] $sol:ty) => {
+        ref_impls!(@impl [$($gen)*] $sol, &V);
+        ref_impls!(@impl [$($gen)*] $sol, &mut V);
+    };
+
+    (@impl [$($gen:tt)*] $sol:ty, $ref:ty) => {
+        impl<V: ?Sized + SolTypeValue<$sol>, $($gen)*> SolTypeValue<$sol> for $ref {
+            #[inline]
+            fn stv_to_tokens(&self) -> <$sol as SolType>::Token<'_> {
+                (**self).stv_to_tokens()
+            }
+
+            #[inline]
+            fn stv_abi_encoded_size(&self) -> usize {
+                (**self).stv_abi_encoded_size()
+            }
+
+            #[inline]
+            fn stv_eip712_data_word(&self) -> Word {
+                (**self).stv_eip712_data_word()
+            }
+
+            #[inline]
+            fn stv_abi_encode_packed_to(&self, out: &mut Vec<u8>) {
+                (**self).stv_abi_encode_packed_to(out)
+            }
+
+            #[inline]
+            fn stv_abi_packed_encoded_size(&self) -> usize {
+                (**self).stv_abi_packed_encoded_size()
+            }
+        }
+    };
+}
+
 // IMPORTANT: Keep in sync with `rec_expand_rust_type` in
 // `sol-macro/src/expand/ty.rs`
 
 /// Bool - `bool`
 pub struct Bool;
 
-impl SolTypeValue<Bool> for bool {
+impl<T: Borrow<bool>> SolTypeValue<Bool> for T {
     #[inline]
     fn stv_to_tokens(&self) -> WordToken {
-        WordToken(Word::with_last_byte(*self as u8))
+        WordToken(Word::with_last_byte(*self.borrow() as u8))
     }
 
     #[inline]
     fn stv_abi_encode_packed_to(&self, out: &mut Vec<u8>) {
-        out.push(*self as u8);
+        out.push(*self.borrow() as u8);
     }
 
     #[inline]
@@ -49,7 +90,8 @@ impl SolType for Bool {
 
     #[inline]
     fn valid_token(token: &Self::Token<'_>) -> bool {
-        utils::check_zeroes(&token.0[..31])
+        // Only `0` and `1` are valid, like in Solidity's ABI decoder.
+        utils::check_zeroes(&token.0[..31]) && token.0[31] <= 1
     }
 
     #[inline]
@@ -444,45 +486,16 @@ where
     }
 }
 
-impl<T, U> SolTypeValue<Array<U>> for &[T]
-where
-    T: SolTypeValue<U>,
-    U: SolType,
-{
-    #[inline]
-    fn stv_to_tokens(&self) -> DynSeqToken<U::Token<'_>> {
-        (**self).stv_to_tokens()
-    }
-
-    #[inline]
-    fn stv_abi_encoded_size(&self) -> usize {
-        (**self).stv_abi_encoded_size()
-    }
-
-    #[inline]
-    fn stv_eip712_data_word(&self) -> Word {
-        (**self).stv_eip712_data_word()
-    }
-
-    #[inline]
-    fn stv_abi_encode_packed_to(&self, out: &mut Vec<u8>) {
-        (**self).stv_abi_encode_packed_to(out)
-    }
+ref_impls!([U: SolType] Array<U>);
 
-    #[inline]
-    fn stv_abi_packed_encoded_size(&self) -> usize {
-        (**self).stv_abi_packed_encoded_size()
-    }
-}
-
-impl<T, U> SolTypeValue<Array<U>> for &mut [T]
+impl<T, U> SolTypeValue<Array<U>> for Vec<T>
 where
     T: SolTypeValue<U>,
     U: SolType,
 {
     #[inline]
     fn stv_to_tokens(&self) -> DynSeqToken<U::Token<'_>> {
-        (**self).stv_to_tokens()
+        <[T] as SolTypeValue<Array<U>>>::stv_to_tokens(self)
     }
 
     #[inline]
@@ -506,7 +519,7 @@ where
     }
 }
 
-impl<T, U> SolTypeValue<Array<U>> for Vec<T>
+impl<T, U> SolTypeValue<Array<U>> for Box<[T]>
 where
     T: SolTypeValue<U>,
     U: SolType,
@@ -613,67 +626,7 @@ where
     }
 }
 
-impl<T, U, const N: usize> SolTypeValue<FixedArray<U, N>> for &[T; N]
-where
-    T: SolTypeValue<U>,
-    U: SolType,
-{
-    #[inline]
-    fn stv_to_tokens(&self) -> <FixedArray<U, N> as SolType>::Token<'_> {
-        <[T; N] as SolTypeValue<FixedArray<U, N>>>::stv_to_tokens(&**self)
-    }
-
-    #[inline]
-    fn stv_abi_encoded_size(&self) -> usize {
-        SolTypeValue::<FixedArray<U, N>>::stv_abi_encoded_size(&**self)
-    }
-
-    #[inline]
-    fn stv_eip712_data_word(&self) -> Word {
-        SolTypeValue::<FixedArray<U, N>>::stv_eip712_data_word(&**self)
-    }
-
-    #[inline]
-    fn stv_abi_encode_packed_to(&self, out: &mut Vec<u8>) {
-        SolTypeValue::<FixedArray<U, N>>::stv_abi_encode_packed_to(&**self, out)
-    }
-
-    #[inline]
-    fn stv_abi_packed_encoded_size(&self) -> usize {
-        SolTypeValue::<FixedArray<U, N>>::stv_abi_packed_encoded_size(&**self)
-    }
-}
-
-impl<T, U, const N: usize> SolTypeValue<FixedArray<U, N>> for &mut [T; N]
-where
-    T: SolTypeValue<U>,
-    U: SolType,
-{
-    #[inline]
-    fn stv_to_tokens(&self) -> <FixedArray<U, N> as SolType>::Token<'_> {
-        <[T; N] as SolTypeValue<FixedArray<U, N>>>::stv_to_tokens(&**self)
-    }
-
-    #[inline]
-    fn stv_abi_encoded_size(&self) -> usize {
-        SolTypeValue::<FixedArray<U, N>>::stv_abi_encoded_size(&**self)
-    }
-
-    #[inline]
-    fn stv_eip712_data_word(&self) -> Word {
-        SolTypeValue::<FixedArray<U, N>>::stv_eip712_data_word(&**self)
-    }
-
-    #[inline]
-    fn stv_abi_encode_packed_to(&self, out: &mut Vec<u8>) {
-        SolTypeValue::<FixedArray<U, N>>::stv_abi_encode_packed_to(&**self, out)
-    }
-
-    #[inline]
-    fn stv_abi_packed_encoded_size(&self) -> usize {
-        SolTypeValue::<FixedArray<U, N>>::stv_abi_packed_encoded_size(&**self)
-    }
-}
+ref_impls!([U: SolType, const N: usize] FixedArray<U, N>);
 
 impl<T: SolType, const N: usize> SolType for FixedArray<T, N> {
     type RustType = [T::RustType; N];
@@ -748,6 +701,8 @@ macro_rules! tuple_encodable_impls {
                 0 $(+ $ty.stv_abi_packed_encoded_size())+
             }
         }
+
+        ref_impls!([$($uty: SolType),+] ($($uty,)+));
     };
 }
 
@@ -816,6 +771,8 @@ impl SolTypeValue<()> for () {
     fn stv_abi_encode_packed_to(&self, _out: &mut Vec<u8>) {}
 }
 
+ref_impls!([]());
+
 all_the_tuples!(@double tuple_encodable_impls);
 
 impl SolType for () {
@@ -1024,14 +981,9 @@ macro_rules! int_impls {
         }
 
         #[inline]
-        fn detokenize_int(mut token: WordToken) -> $ity {
+        fn detokenize_int(token: WordToken) -> $ity {
             // sign extend bits to ignore
-            let is_negative = token.0[Self::WORD_MSB] & 0x80 == 0x80;
-            let sign_extension = is_negative as u8 * 0xff;
-            token.0[Self::WORD_MSB - Self::SKIP_BYTES..Self::WORD_MSB].fill(sign_extension);
-
-            let s = &token.0[Self::WORD_MSB - Self::SKIP_BYTES..];
-            <$ity>::from_be_bytes::<32>(s.try_into().unwrap())
+            <$ity>::from_be_bytes_signed(token.0 .0, Self::BITS)
         }
 
         #[inline]
@@ -1489,6 +1441,28 @@ mod tests {
         };
     }
 
+    #[test]
+    fn bool_valid_token() {
+        let token = |last: u8| {
+            let mut word = [0; 32];
+            word[31] = last;
+            WordToken::new(word)
+        };
+        assert!(Bool::valid_token(&token(0)));
+        assert!(Bool::valid_token(&token(1)));
+        for last in 2..=u8::MAX {
+            assert!(!Bool::valid_token(&token(last)), "{last}");
+        }
+
+        let mut word = [0; 32];
+        word[0] = 1;
+        word[31] = 1;
+        assert!(!Bool::valid_token(&WordToken::new(word)));
+
+        assert!(Bool::abi_decode(&token(2).0 .0, true).is_err());
+        assert_eq!(Bool::abi_decode(&token(2).0 .0, false), Ok(true));
+    }
+
     #[test]
     fn detokenize_negative_int() {
         let word = [0xff; 32];
@@ -1527,6 +1501,34 @@ mod tests {
         assert_eq!(<Int<256>>::detokenize(token), I256::MINUS_ONE);
     }
 
+    #[test]
+    fn detokenize_sign_extends() {
+        // int24 -1, e.g. a Uniswap V3 tick, without its sign extension
+        let mut word = [0u8; 32];
+        word[29..].fill(0xff);
+        assert_eq!(<Int<24>>::abi_decode(&word, false).unwrap(), -1);
+        assert!(<Int<24>>::abi_decode(&word, true).is_err());
+        assert_eq!(<Int<24>>::abi_decode(&[0xff; 32], true).unwrap(), -1);
+
+        // negative boundaries
+        let mut word = [0xff; 32];
+        word[29] = 0x80;
+        word[30..].fill(0);
+        assert_eq!(<Int<24>>::abi_decode(&word, true).unwrap(), -(1 << 23));
+        word[29] = 0x7f;
+        assert!(<Int<24>>::abi_decode(&word, true).is_err());
+        assert_eq!(<Int<24>>::abi_decode(&word, false).unwrap(), 0x7f0000);
+
+        let min = I256::MINUS_ONE.wrapping_shl(135);
+        assert_eq!(<Int<136>>::abi_decode(&min.to_be_bytes::<32>(), true).unwrap(), min);
+        let mut word = [0u8; 32];
+        word[15] = 0x80;
+        assert_eq!(<Int<136>>::abi_decode(&word, false).unwrap(), min);
+        word[15] = 0x7f;
+        word[16..].fill(0xff);
+        assert_eq!(<Int<136>>::abi_decode(&word, true).unwrap(), !min);
+    }
+
     #[test]
     #[rustfmt::skip]
     fn detokenize_int() {
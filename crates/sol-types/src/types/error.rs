@@ -411,7 +411,8 @@ impl PanicKind {
 ///
 /// If successful, it returns the decoded revert reason wrapped in an `Option`.
 ///
-/// If both attempts fail, it returns `None`.
+/// If both attempts fail, or if the output data is empty (a revert without a reason), it returns
+/// `None`.
 pub fn decode_revert_reason(out: &[u8]) -> Option<String> {
     RevertReason::decode(out).map(|x| x.to_string())
 }
@@ -419,7 +420,10 @@ pub fn decode_revert_reason(out: &[u8]) -> Option<String> {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::{sol, types::interface::SolInterface};
+    use crate::{
+        sol,
+        types::interface::{ContractError, GenericRevertReason, SolInterface},
+    };
     use alloc::string::ToString;
     use alloy_primitives::{address, hex, keccak256};
 
@@ -483,6 +487,29 @@ mod tests {
         assert_eq!(decoded, "revert: UniswapV2: INSUFFICIENT_INPUT_AMOUNT");
     }
 
+    #[test]
+    fn decode_solidity_panic_reason() {
+        let panic = Panic::from(PanicKind::UnderOverflow);
+        let encoded = panic.abi_encode();
+        assert_eq!(encoded[..4], hex!("4e487b71"));
+
+        let decoded = decode_revert_reason(&encoded).unwrap();
+        assert_eq!(decoded, "panic: arithmetic underflow or overflow (0x11)");
+
+        let Some(RevertReason::ContractError(ContractError::Panic(decoded))) =
+            GenericRevertReason::decode(&encoded)
+        else {
+            panic!("expected a panic");
+        };
+        assert_eq!(decoded.kind(), Some(PanicKind::UnderOverflow));
+    }
+
+    #[test]
+    fn decode_empty_revert_reason() {
+        assert_eq!(decode_revert_reason(&[]), None);
+        assert_eq!(GenericRevertReason::decode(&[]), None);
+    }
+
     #[test]
     fn decode_random_revert_reason() {
         let revert_reason = String::from("test_revert_reason");
@@ -16,6 +16,18 @@ use alloy_primitives::keccak256;
 /// It should not be necessary to implement this trait manually. Instead, use
 /// the [`sol!`](crate::sol!) procedural macro to parse Solidity syntax into
 /// types that implement this trait.
+///
+/// Custom [`SolType`]s that wrap an existing type can use
+/// [`#[derive(EventTopic)]`](macro@crate::EventTopic) to forward to the
+/// wrapped type's implementation.
+///
+/// When implementing it manually, note that value types are encoded as their
+/// single ABI word, while `bytes`, `string`, arrays (including fixed-size
+/// arrays) and structs are encoded as the Keccak-256 hash of their topic
+/// preimage. For
+/// `bytes` and `string`, the preimage is the unpadded contents when hashed
+/// directly, but is padded to a multiple of 32 bytes when nested in another
+/// type's preimage.
 pub trait EventTopic: SolType {
     /// The number of bytes this type occupies in another topic's preimage,
     /// usually a multiple of 32.
@@ -4,6 +4,7 @@ use crate::{
     Result, SolType, Word,
 };
 use alloc::vec::Vec;
+use alloy_primitives::{Bytes, Selector};
 
 /// A Solidity function call.
 ///
@@ -33,11 +34,44 @@ pub trait SolCall: Sized {
     type ReturnToken<'a>: TokenSeq<'a>;
 
     /// The function's ABI signature.
+    ///
+    /// This is the canonical signature used by the Solidity compiler: custom
+    /// types are resolved to their ABI types, e.g. structs to tuples and enums
+    /// to `uint8`, and there is no whitespace.
     const SIGNATURE: &'static str;
 
     /// The function selector: `keccak256(SIGNATURE)[0..4]`
+    ///
+    /// For types generated by [`sol!`](crate::sol!), this is guaranteed to be
+    /// computed from [`SIGNATURE`](Self::SIGNATURE).
     const SELECTOR: [u8; 4];
 
+    /// The function's state mutability, as it appears in the JSON ABI:
+    /// `"pure"`, `"view"`, `"nonpayable"` or `"payable"`.
+    ///
+    /// The legacy `constant` modifier is treated as `"view"`.
+    ///
+    /// Defaults to `"nonpayable"`, which is the state mutability of functions
+    /// without a modifier.
+    const STATE_MUTABILITY: &'static str = "nonpayable";
+
+    /// Returns the function's ABI signature.
+    ///
+    /// This is the same as [`SIGNATURE`](Self::SIGNATURE).
+    #[inline]
+    fn signature() -> &'static str {
+        Self::SIGNATURE
+    }
+
+    /// Computes the function selector from [`SIGNATURE`](Self::SIGNATURE).
+    ///
+    /// This is always equal to [`SELECTOR`](Self::SELECTOR), but is hashed at
+    /// runtime, which can be used to sanity check the signature.
+    #[inline]
+    fn signature_selector() -> Selector {
+        Selector::from_slice(&alloy_primitives::keccak256(Self::SIGNATURE)[..4])
+    }
+
     /// Convert from the tuple type used for ABI encoding and decoding.
     fn new(tuple: <Self::Parameters<'_> as SolType>::RustType) -> Self;
 
@@ -58,6 +92,11 @@ pub trait SolCall: Sized {
 
     /// ABI decode this call's arguments from the given slice, **without** its
     /// selector.
+    ///
+    /// `data` must only contain the encoded arguments, i.e. the calldata after
+    /// the 4-byte selector. The selector is neither stripped nor checked, so
+    /// this is useful when it has already been matched, e.g. by a dispatcher.
+    /// Use [`abi_decode`](SolCall::abi_decode) to decode full calldata.
     #[inline]
     fn abi_decode_raw(data: &[u8], validate: bool) -> Result<Self> {
         <Self::Parameters<'_> as SolType>::abi_decode_sequence(data, validate).map(Self::new)
@@ -89,6 +128,20 @@ pub trait SolCall: Sized {
         out
     }
 
+    /// ABI encode the call, returning its selector and its encoded arguments
+    /// separately.
+    ///
+    /// Concatenating the two yields the same bytes as [`abi_encode`].
+    /// The arguments are empty if the function takes no parameters.
+    ///
+    /// [`abi_encode`]: SolCall::abi_encode
+    #[inline]
+    fn abi_encode_split(&self) -> (Selector, Bytes) {
+        let mut out = Vec::with_capacity(self.abi_encoded_size());
+        self.abi_encode_raw(&mut out);
+        (Selector::new(Self::SELECTOR), out.into())
+    }
+
     /// ABI decode this call's return values from the given slice.
     fn abi_decode_returns(data: &[u8], validate: bool) -> Result<Self::Return>;
 
@@ -1,5 +1,5 @@
 use crate::{Result, Word};
-use alloy_primitives::Log;
+use alloy_primitives::{Log, LogData};
 
 /// A collection of [`SolEvent`]s.
 ///
@@ -18,11 +18,20 @@ pub trait SolEventInterface: Sized {
     const COUNT: usize;
 
     /// Decode the events from the given log info.
+    ///
+    /// Non-anonymous events are dispatched on the first topic, which must match one of the
+    /// events' [`SIGNATURE_HASH`](crate::SolEvent::SIGNATURE_HASH). Anonymous events have no
+    /// signature topic, so if no non-anonymous event matches, each anonymous event is tried in
+    /// declaration order and the first one that decodes successfully is returned.
     fn decode_raw_log(topics: &[Word], data: &[u8], validate: bool) -> Result<Self>;
 
+    /// Decode the events from the given log data.
+    fn decode_log_data(log: &LogData, validate: bool) -> Result<Self> {
+        Self::decode_raw_log(log.topics(), &log.data, validate)
+    }
+
     /// Decode the events from the given log object.
     fn decode_log(log: &Log, validate: bool) -> Result<Log<Self>> {
-        Self::decode_raw_log(log.topics(), &log.data.data, validate)
-            .map(|data| Log { address: log.address, data })
+        Self::decode_log_data(&log.data, validate).map(|data| Log { address: log.address, data })
     }
 }
@@ -430,8 +430,14 @@ where
     ///
     /// If successful, it returns the decoded revert reason wrapped in an `Option`.
     ///
-    /// If both attempts fail, it returns `None`.
+    /// If both attempts fail, or if the output data is empty (a revert without a reason), it
+    /// returns `None`.
     pub fn decode(out: &[u8]) -> Option<Self> {
+        // A revert without a reason returns no data.
+        if out.is_empty() {
+            return None;
+        }
+
         // Try to decode as a generic contract error.
         if let Ok(error) = ContractError::<T>::abi_decode(out, false) {
             return Some(error.into());
@@ -2,7 +2,7 @@
 //! Solidity structs logic, particularly for EIP-712 encoding/decoding.
 
 use super::SolType;
-use crate::Eip712Domain;
+use crate::{private::SolTypeValue, Eip712Domain, Word};
 use alloc::{borrow::Cow, string::String, vec::Vec};
 use alloy_primitives::{keccak256, B256};
 
@@ -106,3 +106,37 @@ pub trait SolStruct: SolType<RustType = Self> {
         keccak256(digest_input)
     }
 }
+
+// Allows encoding borrowed structs, and tuples or arrays of borrowed structs, without cloning.
+macro_rules! ref_impls {
+    ($($ref:ty),+) => {$(
+        impl<T: SolStruct + SolTypeValue<T>> SolTypeValue<T> for $ref {
+            #[inline]
+            fn stv_to_tokens(&self) -> T::Token<'_> {
+                <T as SolTypeValue<T>>::stv_to_tokens(self)
+            }
+
+            #[inline]
+            fn stv_abi_encoded_size(&self) -> usize {
+                <T as SolTypeValue<T>>::stv_abi_encoded_size(self)
+            }
+
+            #[inline]
+            fn stv_eip712_data_word(&self) -> Word {
+                <T as SolTypeValue<T>>::stv_eip712_data_word(self)
+            }
+
+            #[inline]
+            fn stv_abi_encode_packed_to(&self, out: &mut Vec<u8>) {
+                <T as SolTypeValue<T>>::stv_abi_encode_packed_to(self, out)
+            }
+
+            #[inline]
+            fn stv_abi_packed_encoded_size(&self) -> usize {
+                <T as SolTypeValue<T>>::stv_abi_packed_encoded_size(self)
+            }
+        }
+    )+};
+}
+
+ref_impls!(&T, &mut T);
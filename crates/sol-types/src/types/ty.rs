@@ -245,7 +245,7 @@ pub trait SolType: Sized {
     /// See the [`abi`] module for more information.
     #[inline]
     fn abi_decode(data: &[u8], validate: bool) -> Result<Self::RustType> {
-        abi::decode::<Self::Token<'_>>(data, validate).and_then(check_decode::<Self>(validate))
+        check_decode::<Self>(abi::decode(data, validate), validate)
     }
 
     /// Decodes this type's value from an ABI blob by interpreting it as
@@ -257,8 +257,7 @@ pub trait SolType: Sized {
     where
         Self::Token<'de>: TokenSeq<'de>,
     {
-        abi::decode_params::<Self::Token<'_>>(data, validate)
-            .and_then(check_decode::<Self>(validate))
+        check_decode::<Self>(abi::decode_params(data, validate), validate)
     }
 
     /// Decodes this type's value from an ABI blob by interpreting it as a
@@ -270,17 +269,81 @@ pub trait SolType: Sized {
     where
         Self::Token<'de>: TokenSeq<'de>,
     {
-        abi::decode_sequence::<Self::Token<'_>>(data, validate)
-            .and_then(check_decode::<Self>(validate))
+        check_decode::<Self>(abi::decode_sequence(data, validate), validate)
+    }
+
+    /// Decodes this type's value from an ABI blob by interpreting it as a
+    /// single-element sequence, using `buf` as scratch space.
+    ///
+    /// This is equivalent to [`abi_decode`](SolType::abi_decode), but reuses
+    /// the buffer's allocation across calls. See [`abi::DecodeBuffer`] for
+    /// more information.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use alloy_sol_types::{abi::DecodeBuffer, sol_data::*, SolType};
+    ///
+    /// let mut buf = DecodeBuffer::new();
+    /// let blobs = [Uint::<64>::abi_encode(&1), Uint::<64>::abi_encode(&2)];
+    /// for (blob, expected) in blobs.iter().zip(1..) {
+    ///     let value = Uint::<64>::abi_decode_with(blob, true, &mut buf)?;
+    ///     assert_eq!(value, Uint::<64>::abi_decode(blob, true)?);
+    ///     assert_eq!(value, expected);
+    /// }
+    /// # Ok::<_, alloy_sol_types::Error>(())
+    /// ```
+    #[inline]
+    fn abi_decode_with(
+        data: &[u8],
+        validate: bool,
+        buf: &mut abi::DecodeBuffer,
+    ) -> Result<Self::RustType> {
+        check_decode::<Self>(abi::decode_with(data, validate, buf), validate)
+    }
+
+    /// Decodes this type's value from an ABI blob by interpreting it as
+    /// function parameters, using `buf` as scratch space.
+    ///
+    /// See [`abi_decode_params`](SolType::abi_decode_params) and
+    /// [`abi::DecodeBuffer`] for more information.
+    #[inline]
+    fn abi_decode_params_with<'de>(
+        data: &'de [u8],
+        validate: bool,
+        buf: &mut abi::DecodeBuffer,
+    ) -> Result<Self::RustType>
+    where
+        Self::Token<'de>: TokenSeq<'de>,
+    {
+        check_decode::<Self>(abi::decode_params_with(data, validate, buf), validate)
+    }
+
+    /// Decodes this type's value from an ABI blob by interpreting it as a
+    /// sequence, using `buf` as scratch space.
+    ///
+    /// See [`abi_decode_sequence`](SolType::abi_decode_sequence) and
+    /// [`abi::DecodeBuffer`] for more information.
+    #[inline]
+    fn abi_decode_sequence_with<'de>(
+        data: &'de [u8],
+        validate: bool,
+        buf: &mut abi::DecodeBuffer,
+    ) -> Result<Self::RustType>
+    where
+        Self::Token<'de>: TokenSeq<'de>,
+    {
+        check_decode::<Self>(abi::decode_sequence_with(data, validate, buf), validate)
     }
 }
 
+/// Adds the type name to decoding errors, and type checks the decoded token if
+/// `validate` is true.
 #[inline]
-fn check_decode<T: SolType>(validate: bool) -> impl FnOnce(T::Token<'_>) -> Result<T::RustType> {
-    move |token| {
-        if validate {
-            T::type_check(&token)?;
-        }
-        Ok(T::detokenize(token))
+fn check_decode<T: SolType>(token: Result<T::Token<'_>>, validate: bool) -> Result<T::RustType> {
+    let token = token.map_err(|e| e.with_decode_type(T::SOL_NAME))?;
+    if validate {
+        T::type_check(&token)?;
     }
+    Ok(T::detokenize(token))
 }
@@ -31,6 +31,11 @@ use alloy_primitives::{Address, Bytes, FixedBytes, Function, I256, U256};
 /// let _ = my_values.abi_encode();
 /// let _ = my_values.abi_encode_packed();
 /// assert_eq!(my_values.sol_type_name(), "(string,uint32,bool,bytes24)");
+///
+/// // Borrowed values, and tuples or arrays of them, are encoded without cloning.
+/// let array = vec![1_u64, 2, 3];
+/// let string = String::from("hello");
+/// assert_eq!((&array, &string).abi_encode(), (array.clone(), string.clone()).abi_encode());
 /// ```
 pub trait SolValue: SolTypeValue<Self::SolType> {
     /// The Solidity type that this type corresponds to.
@@ -474,4 +479,54 @@ mod tests {
             <(sol_data::Bytes, sol_data::Bytes)>::abi_encode(&(b"a", b""))
         );
     }
+
+    #[test]
+    fn borrowed() {
+        crate::sol! {
+            struct MyStruct {
+                uint256 a;
+                string b;
+            }
+        }
+
+        let a = vec![U256::from(1); 4];
+        let s = String::from("hello");
+        let addrs = [Address::ZERO; 3];
+        let flag = true;
+        let my_struct = MyStruct { a: U256::from(2), b: s.clone() };
+        let structs = vec![MyStruct { a: U256::from(3), b: s.clone() }];
+
+        let owned =
+            (a.clone(), s.clone(), addrs, flag, my_struct.clone(), structs.clone()).abi_encode();
+        assert_eq!((&a, &s, &addrs, &flag, &my_struct, &structs).abi_encode(), owned);
+        assert_eq!(
+            (&a[..], s.as_str(), &addrs, flag, &my_struct, &structs[..]).abi_encode(),
+            owned
+        );
+        assert_eq!(&(&a, &s, &addrs, &flag, &my_struct, &structs).abi_encode(), &owned);
+
+        // nested borrows
+        let refs: Vec<&U256> = a.iter().collect();
+        assert_eq!(refs.abi_encode(), a.abi_encode());
+        assert_eq!(refs[..].abi_encode(), a.abi_encode());
+        assert_eq!((&&a, &&s).abi_encode(), (&a, &s).abi_encode());
+        let nested = vec![a.clone(), a.clone()];
+        let nested_refs: Vec<&[U256]> = nested.iter().map(Vec::as_slice).collect();
+        assert_eq!(nested_refs.abi_encode(), nested.abi_encode());
+        let struct_refs: Vec<&MyStruct> = structs.iter().collect();
+        assert_eq!(struct_refs.abi_encode(), structs.abi_encode());
+        assert_eq!([&a, &a].abi_encode(), [a.clone(), a.clone()].abi_encode());
+        let mut tuple = (a.clone(), (s.clone(), flag));
+        assert_eq!((&mut tuple,).abi_encode(), (tuple.clone(),).abi_encode());
+
+        // other encodings
+        let borrowed = (&a, &s, &my_struct);
+        let owned = (a.clone(), s.clone(), my_struct.clone());
+        assert_eq!(borrowed.abi_encode_params(), owned.abi_encode_params());
+        assert_eq!(borrowed.abi_encode_sequence(), owned.abi_encode_sequence());
+        assert_eq!(borrowed.abi_encode_packed(), owned.abi_encode_packed());
+        assert_eq!(borrowed.abi_encoded_size(), owned.abi_encoded_size());
+        assert_eq!(borrowed.eip712_data_word(), owned.eip712_data_word());
+        assert_eq!(borrowed.sol_name(), owned.sol_name());
+    }
 }
@@ -0,0 +1,19 @@
+// SPDX-License-Identifier: MIT
+pragma solidity ^0.8.0;
+
+struct Point {
+    uint256 x;
+    uint256 y;
+}
+
+error OutOfBounds(Point point);
+
+event Moved(address indexed by, Point to);
+
+function distance(Point a, Point b) pure returns (uint256);
+
+interface IShapes {
+    function area(Point[] calldata points) external view returns (uint256);
+
+    event Drawn(uint256 indexed id);
+}
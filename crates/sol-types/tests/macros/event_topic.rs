@@ -0,0 +1,95 @@
+use alloy_primitives::{keccak256, U256};
+use alloy_sol_types::{
+    abi::token::{PackedSeqToken, WordToken},
+    private::SolTypeValue,
+    sol_data, EventTopic, SolType, Word,
+};
+
+/// Implements `SolType` for a newtype around `$inner` by forwarding to it.
+macro_rules! newtype_sol_type {
+    ($name:ident($inner:ty) => $rust:ty) => {
+        impl SolType for $name {
+            type RustType = $rust;
+            type Token<'a> = <$inner as SolType>::Token<'a>;
+
+            const SOL_NAME: &'static str = <$inner as SolType>::SOL_NAME;
+            const ENCODED_SIZE: Option<usize> = <$inner as SolType>::ENCODED_SIZE;
+            const PACKED_ENCODED_SIZE: Option<usize> = <$inner as SolType>::PACKED_ENCODED_SIZE;
+
+            fn valid_token(token: &Self::Token<'_>) -> bool {
+                <$inner as SolType>::valid_token(token)
+            }
+
+            fn detokenize(token: Self::Token<'_>) -> Self::RustType {
+                <$inner as SolType>::detokenize(token)
+            }
+        }
+    };
+}
+
+#[derive(EventTopic)]
+struct Amount(sol_data::Uint<256>);
+newtype_sol_type!(Amount(sol_data::Uint<256>) => U256);
+
+impl SolTypeValue<Amount> for U256 {
+    fn stv_to_tokens(&self) -> WordToken {
+        SolTypeValue::<sol_data::Uint<256>>::stv_to_tokens(self)
+    }
+
+    fn stv_abi_encode_packed_to(&self, out: &mut Vec<u8>) {
+        SolTypeValue::<sol_data::Uint<256>>::stv_abi_encode_packed_to(self, out)
+    }
+
+    fn stv_eip712_data_word(&self) -> Word {
+        SolTypeValue::<sol_data::Uint<256>>::stv_eip712_data_word(self)
+    }
+}
+
+#[derive(EventTopic)]
+struct Name {
+    #[allow(dead_code)]
+    inner: sol_data::String,
+}
+newtype_sol_type!(Name(sol_data::String) => String);
+
+impl SolTypeValue<Name> for String {
+    fn stv_abi_encoded_size(&self) -> usize {
+        SolTypeValue::<sol_data::String>::stv_abi_encoded_size(self)
+    }
+
+    fn stv_to_tokens(&self) -> PackedSeqToken<'_> {
+        SolTypeValue::<sol_data::String>::stv_to_tokens(self)
+    }
+
+    fn stv_abi_packed_encoded_size(&self) -> usize {
+        SolTypeValue::<sol_data::String>::stv_abi_packed_encoded_size(self)
+    }
+
+    fn stv_abi_encode_packed_to(&self, out: &mut Vec<u8>) {
+        SolTypeValue::<sol_data::String>::stv_abi_encode_packed_to(self, out)
+    }
+
+    fn stv_eip712_data_word(&self) -> Word {
+        SolTypeValue::<sol_data::String>::stv_eip712_data_word(self)
+    }
+}
+
+#[test]
+fn derive_static() {
+    let amount = U256::from(1337);
+    assert_eq!(Amount::encode_topic(&amount).0, amount.to_be_bytes::<32>());
+    assert_eq!(Amount::topic_preimage_length(&amount), 32);
+}
+
+#[test]
+fn derive_dynamic() {
+    let name = String::from("alloy");
+    assert_eq!(Name::encode_topic(&name).0, keccak256("alloy"));
+
+    let mut preimage = Vec::new();
+    Name::encode_topic_preimage(&name, &mut preimage);
+    assert_eq!(preimage.len(), 32);
+    assert_eq!(Name::topic_preimage_length(&name), 32);
+    assert_eq!(preimage[..5], *b"alloy");
+    assert!(preimage[5..].iter().all(|&b| b == 0));
+}
@@ -2,4 +2,5 @@
 #![allow(unknown_lints, non_local_definitions)]
 #![allow(clippy::missing_const_for_fn)]
 
+mod event_topic;
 mod sol;
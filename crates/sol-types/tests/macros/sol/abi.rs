@@ -562,3 +562,65 @@ fn eparam(s: &str, indexed: bool) -> EventParam {
         indexed,
     }
 }
+
+// Getters match the ABI generated by solc, except for output names: solc leaves the output of
+// non-mapping getters unnamed, and names struct outputs after their members.
+#[test]
+fn state_variable_getters() {
+    sol! {
+        #![sol(abi)]
+
+        contract Getters {
+            struct Data {
+                uint256 a;
+                bytes3 b;
+                uint256[] c;
+            }
+
+            uint256 public total;
+            mapping(address => uint256) public balances;
+            mapping(address owner => mapping(address spender => uint256)) public allowance;
+            address[] public owners;
+            mapping(uint256 => Data) public data;
+        }
+    }
+
+    let functions = Getters::abi::functions();
+    let getter = |name: &str, selector: [u8; 4], inputs: &[&str], outputs: &[&str]| {
+        let f = &functions[name][0];
+        assert_eq!(f.selector(), selector, "{name}");
+        assert_eq!(f.inputs.iter().map(|p| p.ty.as_str()).collect::<Vec<_>>(), inputs, "{name}");
+        assert_eq!(f.outputs.iter().map(|p| p.ty.as_str()).collect::<Vec<_>>(), outputs, "{name}");
+        assert_eq!(f.state_mutability, StateMutability::View, "{name}");
+    };
+    assert_eq!(functions.len(), 5);
+    getter("total", [0x2d, 0xdb, 0xd1, 0x3a], &[], &["uint256"]);
+    getter("balances", [0x27, 0xe2, 0x35, 0xe3], &["address"], &["uint256"]);
+    getter("allowance", [0xdd, 0x62, 0xed, 0x3e], &["address", "address"], &["uint256"]);
+    getter("owners", [0x02, 0x5e, 0x7c, 0x27], &["uint256"], &["address"]);
+    getter("data", [0xf0, 0xba, 0x84, 0x40], &["uint256"], &["uint256", "bytes3"]);
+
+    let names =
+        |name: &str| functions[name][0].inputs.iter().map(|p| p.name.clone()).collect::<Vec<_>>();
+    assert_eq!(names("allowance"), vec!["owner".to_string(), "spender".to_string()]);
+    assert_eq!(names("balances"), vec![String::new()]);
+}
+
+// The legacy `constant` modifier maps to `view`, like the generated `STATE_MUTABILITY`.
+#[test]
+fn constant_state_mutability() {
+    sol! {
+        #![sol(abi)]
+
+        contract Constant {
+            function constantFn() external constant;
+        }
+    }
+
+    let functions = Constant::abi::functions();
+    assert_eq!(functions["constantFn"][0].state_mutability, StateMutability::View);
+    assert_eq!(
+        functions["constantFn"][0].state_mutability.as_json_str(),
+        <Constant::constantFnCall as alloy_sol_types::SolCall>::STATE_MUTABILITY
+    );
+}
@@ -218,3 +218,71 @@ fn balancer_v2_vault() {
         "PoolBalanceChanged(bytes32,address,address[],int256[],uint256[])"
     );
 }
+
+// `JsonAbi::to_sol` output must be accepted by `sol!` and expand to an equivalent ABI.
+#[test]
+fn to_sol_round_trip() {
+    fn signatures(abi: &JsonAbi) -> [std::collections::BTreeSet<String>; 3] {
+        [
+            abi.functions().map(Function::signature).collect(),
+            abi.events().map(alloy_json_abi::Event::signature).collect(),
+            abi.errors().map(alloy_json_abi::Error::signature).collect(),
+        ]
+    }
+
+    macro_rules! round_trip {
+        ($($name:ident => $path:tt),+ $(,)?) => {$({
+            sol!(#[sol(abi)] $name, $path);
+            let json = std::fs::read_to_string(concat!(env!("CARGO_MANIFEST_DIR"), "/", $path));
+            let original: JsonAbi = serde_json::from_str(&json.unwrap()).unwrap();
+            assert_eq!(signatures(&$name::abi::contract()), signatures(&original), stringify!($name));
+        })+};
+    }
+
+    round_trip!(
+        AggregationRouterV5 => "../json-abi/tests/abi/AggregationRouterV5.json",
+        BalancerV2Vault => "../json-abi/tests/abi/BalancerV2Vault.json",
+        BlurExchange => "../json-abi/tests/abi/BlurExchange.json",
+        Errors => "../json-abi/tests/abi/Errors.json",
+        GnosisSafe => "../json-abi/tests/abi/GnosisSafe.json",
+        Seaport => "../json-abi/tests/abi/Seaport.json",
+        Udvts => "../json-abi/tests/abi/Udvts.json",
+        UniswapV1Exchange => "../json-abi/tests/abi/UniswapV1Exchange.json",
+        console => "../json-abi/tests/abi/console.json",
+        ZeroxExchangeProxy => "../json-abi/tests/abi/ZeroxExchangeProxy.json",
+    );
+}
+
+// Parameter names that are keywords or duplicates are renamed.
+#[test]
+fn to_sol_reserved_param_names() {
+    sol!(
+        #[sol(abi)]
+        ReservedNames,
+        r#"[
+            {
+                "type": "function",
+                "name": "f",
+                "inputs": [
+                    { "name": "type", "type": "uint256" },
+                    { "name": "memory", "type": "bytes" },
+                    { "name": "self", "type": "address" }
+                ],
+                "outputs": [
+                    { "name": "out", "type": "uint256" },
+                    { "name": "out", "type": "uint256" }
+                ],
+                "stateMutability": "view"
+            }
+        ]"#
+    );
+
+    let _ = ReservedNames::fCall {
+        type_: U256::ZERO,
+        memory_: Default::default(),
+        self_: Address::ZERO,
+    };
+    let ReservedNames::fReturn { out: _, out_1: _ } =
+        ReservedNames::fCall::abi_decode_returns(&[0; 64], true).unwrap();
+    assert_eq!(ReservedNames::fCall::SIGNATURE, "f(uint256,bytes,address)");
+}
@@ -9,6 +9,7 @@ mod abi;
 mod json;
 
 mod eip712;
+mod fuzz;
 
 #[test]
 fn e2e() {
@@ -112,6 +113,15 @@ fn function() {
         32 + (64 + 32) + (64 + 32 + 32) + (64 + 3 * 32) + 2 * 32 + (32 + 32) + (64 + 4 * (32 + 32))
     );
     assert_eq!(encoded.len(), 4 + call.abi_encoded_size());
+
+    let (selector, args) = call.abi_encode_split();
+    assert_eq!(selector, someFunctionCall::SELECTOR);
+    assert_eq!(args.len(), call.abi_encoded_size());
+    assert_eq!([&selector[..], &args[..]].concat(), encoded);
+
+    assert_eq!(someFunctionCall::abi_decode_raw(&args, true).unwrap(), call);
+    assert!(someFunctionCall::abi_decode(&args, true).is_err());
+    assert!(someFunctionCall::abi_decode_raw(&encoded, true).is_err());
 }
 
 #[test]
@@ -153,6 +163,66 @@ fn function_returns() {
         ),
         Ok(testReturn { _0: vec![U256::from(0x42), U256::from(0x69)] })
     );
+    assert_eq!(
+        testCall::abi_decode_returns_single(
+            &hex!(
+                "0000000000000000000000000000000000000000000000000000000000000020
+                 0000000000000000000000000000000000000000000000000000000000000001
+                 0000000000000000000000000000000000000000000000000000000000000002"
+            ),
+            true,
+        ),
+        Ok(vec![U256::from(2)])
+    );
+
+    sol! {
+        function named() returns (string name);
+    }
+    let data = namedCall::abi_encode_returns(&(String::from("hello"),));
+    assert_eq!(namedCall::abi_decode_returns_single(&data, true), Ok(String::from("hello")));
+    assert_eq!(namedCall::abi_decode_returns_tuple(&data, true), Ok((String::from("hello"),)));
+
+    sol! {
+        struct Pair { address a; bool b; }
+        function many() returns (Pair pair, uint8[] small, string name, int256 last);
+    }
+    let ret = manyReturn {
+        pair: Pair { a: Address::repeat_byte(1), b: true },
+        small: vec![1, 2],
+        name: String::from("n"),
+        last: I256::MINUS_ONE,
+    };
+    let (pair, small, name, last) =
+        manyCall::abi_decode_returns_tuple(&ret.abi_encode(), true).unwrap();
+    assert_eq!((pair.a, pair.b), (ret.pair.a, ret.pair.b));
+    assert_eq!((small, name, last), (ret.small, ret.name, ret.last));
+}
+
+#[test]
+fn return_abi_encode() {
+    sol! {
+        function none();
+        function single(uint256) returns (string);
+        function multi() returns (uint256 a, bytes b, address[] c);
+    }
+
+    assert_eq!(noneReturn {}.abi_encode(), Vec::<u8>::new());
+    assert!(noneCall::abi_decode_returns(&[], true).is_ok());
+
+    // a single dynamic return value is still encoded as a sequence, with a leading offset
+    let ret = singleReturn { _0: "hello".into() };
+    let data = ret.abi_encode();
+    assert_eq!(data, singleCall::abi_encode_returns(&(ret._0.clone(),)));
+    assert_eq!(data[..32], U256::from(32).to_be_bytes::<32>());
+    assert_eq!(singleCall::abi_decode_returns(&data, true).unwrap()._0, ret._0);
+
+    let ret = multiReturn {
+        a: U256::from(1),
+        b: bytes!("c0ffee"),
+        c: vec![Address::ZERO, Address::with_last_byte(1)],
+    };
+    let decoded = multiCall::abi_decode_returns(&ret.abi_encode(), true).unwrap();
+    assert_eq!((decoded.a, decoded.b, decoded.c), (ret.a, ret.b, ret.c));
 }
 
 #[test]
@@ -169,6 +239,47 @@ fn error() {
     assert_eq!(e.abi_encoded_size(), 64);
 }
 
+#[test]
+fn error_std_error() {
+    sol! {
+        #[derive(Debug)]
+        #[sol(error_impls)]
+        error Unauthorized(address account);
+    }
+
+    fn check() -> Result<(), Box<dyn std::error::Error>> {
+        Err(Unauthorized { account: Address::ZERO })?
+    }
+    let err = check().unwrap_err();
+    assert_eq!(err.to_string(), "Unauthorized(address)");
+    assert!(err.downcast_ref::<Unauthorized>().is_some());
+}
+
+#[test]
+fn error_impls_opt_in() {
+    sol! {
+        #[derive(Debug)]
+        error InsufficientBalance(uint256 available);
+
+        #[sol(error_impls)]
+        contract Vault {
+            error Paused();
+        }
+    }
+
+    // without `error_impls`, `Display` can be implemented manually
+    impl std::fmt::Display for InsufficientBalance {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            write!(f, "insufficient balance: {}", self.available)
+        }
+    }
+    impl std::error::Error for InsufficientBalance {}
+
+    let err = InsufficientBalance { available: U256::from(1) };
+    assert_eq!(err.to_string(), "insufficient balance: 1");
+    assert_eq!(Vault::Paused {}.to_string(), "Paused()");
+}
+
 // Handle empty call encoding/decoding correctly
 // https://github.com/alloy-rs/core/issues/158
 #[test]
@@ -185,9 +296,13 @@ fn empty_call() {
     let mut out = vec![];
     depositCall {}.abi_encode_raw(&mut out);
     assert!(out.is_empty());
+    let (selector, args) = depositCall {}.abi_encode_split();
+    assert_eq!(selector, depositCall::SELECTOR);
+    assert!(args.is_empty());
 
     let depositCall {} = depositCall::abi_decode(&depositCall::SELECTOR, true).unwrap();
     let depositCall {} = depositCall::abi_decode_raw(&[], true).unwrap();
+    let WETH::depositReturn {} = depositCall::abi_decode_returns(&[], true).unwrap();
 }
 
 #[test]
@@ -208,6 +323,380 @@ fn function_names() {
     assert!(!call.is___f());
 }
 
+#[test]
+fn non_exhaustive_enums() {
+    sol! {
+        #[sol(non_exhaustive)]
+        contract NonExhaustive {
+            function f();
+            event E();
+            error Err();
+        }
+    }
+    use alloy_sol_types::{SolEventInterface, SolInterface};
+    use NonExhaustive::*;
+
+    // `#[non_exhaustive]` only restricts matching outside of the defining crate.
+    let call = NonExhaustiveCalls::abi_decode(&fCall::SELECTOR, true).unwrap();
+    assert!(matches!(call, NonExhaustiveCalls::f(fCall {})));
+    let error = NonExhaustiveErrors::abi_decode(&Err::SELECTOR, true).unwrap();
+    assert!(matches!(error, NonExhaustiveErrors::Err(Err {})));
+    let event = NonExhaustiveEvents::decode_raw_log(&[E::SIGNATURE_HASH], &[], true).unwrap();
+    assert!(matches!(event, NonExhaustiveEvents::E(E {})));
+}
+
+#[test]
+fn contract_selectors() {
+    sol! {
+        contract Router {
+            function swap(uint256 amount);
+            function swap(uint256 amount, address to);
+            function swap(bytes data);
+            function quote() returns (uint256);
+            uint256 public fee;
+        }
+    }
+    use alloy_sol_types::SolInterface;
+    use Router::*;
+
+    let selectors = RouterCalls::SELECTORS;
+    assert_eq!(selectors.len(), RouterCalls::COUNT);
+    assert_eq!(selectors.len(), 5);
+    assert!(selectors.windows(2).all(|w| w[0] < w[1]), "selectors must be sorted and unique");
+    assert_eq!(RouterCalls::selectors().collect::<Vec<_>>(), selectors);
+
+    let calls = [
+        RouterCalls::swap_0(swap_0Call { amount: U256::from(1) }),
+        RouterCalls::swap_1(swap_1Call { amount: U256::from(1), to: Address::ZERO }),
+        RouterCalls::swap_2(swap_2Call { data: bytes!("1234") }),
+        RouterCalls::quote(quoteCall {}),
+        RouterCalls::fee(feeCall {}),
+    ];
+    let mut call_selectors = calls.iter().map(|call| call.selector()).collect::<Vec<_>>();
+    call_selectors.sort_unstable();
+    assert_eq!(call_selectors, selectors);
+
+    for call in calls {
+        assert!(RouterCalls::valid_selector(call.selector()));
+        let decoded = RouterCalls::abi_decode(&call.abi_encode(), true).unwrap();
+        assert_eq!(decoded.selector(), call.selector());
+    }
+}
+
+#[test]
+fn event_interface_dispatch() {
+    sol! {
+        #![sol(all_derives)]
+        contract Events {
+            event Transfer(address indexed from, address indexed to, uint256 value);
+            event Approval(address indexed owner, address indexed spender, uint256 value);
+            event Anon(uint256 indexed a, uint256 indexed b) anonymous;
+        }
+    }
+    use alloy_sol_types::SolEventInterface;
+    use Events::*;
+
+    let transfer = Transfer {
+        from: Address::repeat_byte(1),
+        to: Address::repeat_byte(2),
+        value: U256::from(3),
+    };
+    let log = transfer.encode_log_data();
+    assert_eq!(log.topics()[0], Transfer::SIGNATURE_HASH);
+    let decoded = EventsEvents::decode_log_data(&log, true).unwrap();
+    assert_eq!(decoded, EventsEvents::Transfer(transfer));
+
+    let approval = Approval {
+        owner: Address::repeat_byte(1),
+        spender: Address::repeat_byte(2),
+        value: U256::from(3),
+    };
+    let decoded = EventsEvents::decode_log_data(&approval.encode_log_data(), true).unwrap();
+    assert_eq!(decoded, EventsEvents::Approval(approval));
+
+    // Anonymous events have no signature topic and are tried after dispatch fails.
+    let anon = Anon { a: U256::from(1), b: U256::from(2) };
+    let log = anon.encode_log_data();
+    assert_eq!(log.topics().len(), 2);
+    let decoded = EventsEvents::decode_log_data(&log, true).unwrap();
+    assert_eq!(decoded, EventsEvents::Anon(anon));
+
+    let unknown =
+        alloy_primitives::LogData::new_unchecked(vec![B256::repeat_byte(0xff)], Default::default());
+    assert!(EventsEvents::decode_log_data(&unknown, true).is_err());
+}
+
+#[test]
+fn event_trait_generic() {
+    sol! {
+        #![sol(all_derives, event_trait)]
+        contract Token {
+            event Transfer(address indexed from, address indexed to, uint256 value);
+            event Approval(address indexed owner, address indexed spender, uint256 value);
+            event Anon(uint256 indexed a) anonymous;
+        }
+    }
+    use alloy_primitives::Log;
+    use Token::*;
+
+    // Decodes all the logs that are `E`, from any address.
+    fn decode_all<E: TokenEvent>(logs: &[Log]) -> Vec<(B256, E)> {
+        logs.iter()
+            .filter_map(|log| E::decode_event(&log.data, true).ok())
+            .map(|event| (event.signature_hash(), event))
+            .collect()
+    }
+
+    let transfer = Transfer {
+        from: Address::repeat_byte(1),
+        to: Address::repeat_byte(2),
+        value: U256::from(3),
+    };
+    let approval = Approval {
+        owner: Address::repeat_byte(1),
+        spender: Address::repeat_byte(2),
+        value: U256::from(3),
+    };
+    let logs = [
+        Log { address: Address::repeat_byte(0xaa), data: transfer.encode_log_data() },
+        Log { address: Address::repeat_byte(0xbb), data: approval.encode_log_data() },
+    ];
+
+    assert_eq!(decode_all::<Transfer>(&logs), [(Transfer::SIGNATURE_HASH, transfer.clone())]);
+    assert_eq!(decode_all::<Approval>(&logs), [(Approval::SIGNATURE_HASH, approval.clone())]);
+    assert_eq!(
+        decode_all::<TokenEvents>(&logs),
+        [
+            (Transfer::SIGNATURE_HASH, TokenEvents::Transfer(transfer)),
+            (Approval::SIGNATURE_HASH, TokenEvents::Approval(approval)),
+        ]
+    );
+
+    let anon = Anon { a: U256::from(1) };
+    let event = TokenEvents::decode_raw_event(anon.encode_log_data().topics(), &[], true).unwrap();
+    assert_eq!(event.signature_hash(), Anon::SIGNATURE_HASH);
+    assert_eq!(event, TokenEvents::Anon(anon));
+}
+
+#[test]
+fn event_trait_opt_in() {
+    // without `event_trait`, no `VaultEvent` trait is generated, so the name is free
+    sol! {
+        contract Vault {
+            event VaultEvent(uint256 a);
+            event Deposit(uint256 b);
+        }
+    }
+    let _ = Vault::VaultEvents::VaultEvent(Vault::VaultEvent { a: U256::ZERO });
+}
+
+#[test]
+fn event_log_into_raw() {
+    sol! {
+        event Transfer(address indexed from, address indexed to, uint256 value);
+    }
+    use alloy_primitives::{IntoLogData, Log};
+
+    let address = Address::repeat_byte(0xaa);
+    let transfer = Transfer {
+        from: Address::repeat_byte(1),
+        to: Address::repeat_byte(2),
+        value: U256::from(3),
+    };
+    let log = Log::new_from_event(address, transfer).unwrap();
+    assert_eq!(log.to_log_data(), log.data.encode_log_data());
+
+    let raw = log.into_raw();
+    assert_eq!(raw.address, address);
+    assert_eq!(raw.topics()[0], Transfer::SIGNATURE_HASH);
+
+    let decoded = Transfer::decode_log(&raw, true).unwrap();
+    assert_eq!(decoded.address, address);
+    assert_eq!(decoded.into_raw(), raw);
+}
+
+#[test]
+fn contract_enum_derives() {
+    sol! {
+        #![sol(all_derives)]
+        interface IERC20 {
+            struct Permit {
+                address owner;
+                uint256[] values;
+            }
+
+            function transfer(address to, uint256 amount) external returns (bool);
+            function transfer(Permit memory permit) external returns (bool);
+            function name() external view returns (string memory);
+
+            error Insufficient(uint256 available, uint256 required);
+
+            event Transfer(address indexed from, address indexed to, uint256 value);
+            event Transfer(Permit permit);
+        }
+    }
+    use alloy_sol_types::{SolError, SolEventInterface, SolInterface};
+    use IERC20::*;
+
+    let call = IERC20Calls::transfer_0(transfer_0Call {
+        to: Address::repeat_byte(1),
+        amount: U256::from(2),
+    });
+    let decoded = IERC20Calls::abi_decode(&call.abi_encode(), true).unwrap();
+    assert_eq!(decoded, call);
+    assert_ne!(decoded, IERC20Calls::name(nameCall {}));
+
+    let permit = Permit { owner: Address::repeat_byte(3), values: vec![U256::from(4)] };
+    let call = IERC20Calls::transfer_1(transfer_1Call { permit: permit.clone() });
+    assert_eq!(IERC20Calls::abi_decode(&call.abi_encode(), true).unwrap(), call);
+    assert!(format!("{call:?}").starts_with("transfer_1(transfer_1Call"));
+
+    let error = Insufficient { available: U256::from(1), required: U256::from(2) };
+    let decoded = IERC20Errors::abi_decode(&error.abi_encode(), true).unwrap();
+    assert_eq!(decoded, IERC20Errors::Insufficient(error));
+
+    // overloaded events are named `Transfer_0` and `Transfer_1`
+    let event = Transfer_1 { permit };
+    let decoded = IERC20Events::decode_log_data(&event.encode_log_data(), true).unwrap();
+    assert_eq!(decoded, IERC20Events::Transfer_1(event));
+}
+
+#[test]
+fn contract_enum_clone() {
+    sol! {
+        contract C {
+            function f(uint256 a);
+            error E();
+            event Ev();
+        }
+    }
+
+    // `Clone` is always derived, even without `all_derives`
+    fn assert_clone<T: Clone>() {}
+    assert_clone::<C::CCalls>();
+    assert_clone::<C::CErrors>();
+    assert_clone::<C::CEvents>();
+}
+
+#[test]
+fn contract_enum_builtin_derives() {
+    sol! {
+        contract C {
+            struct S {
+                uint256 a;
+            }
+
+            function transfer(address to, uint256[] amounts, (bool, bytes) t) returns (bool);
+            function name() returns (string);
+            error E(uint256 a);
+            event Ev(address indexed a, string b);
+        }
+
+        contract WithStruct {
+            struct S {
+                uint256 a;
+            }
+
+            function f(S s);
+            // already derived traits are not derived again
+            #[derive(Debug)]
+            function g(uint256 a);
+        }
+    }
+    use alloy_sol_types::{SolError, SolEvent, SolInterface};
+    use C::*;
+
+    // derived without `all_derives`, since all the parameters are elementary types
+    let call = CCalls::transfer(transferCall {
+        to: Address::repeat_byte(1),
+        amounts: vec![U256::from(2)],
+        t: (true, bytes!("dead")),
+    });
+    let decoded = CCalls::abi_decode(&call.abi_encode(), true).unwrap();
+    assert_eq!(decoded, call);
+    assert_ne!(decoded, CCalls::name(nameCall {}));
+    assert_eq!(
+        format!("{call:?}"),
+        format!(
+            "transfer(transferCall {{ to: {}, amounts: [2], t: (true, 0xdead) }})",
+            Address::repeat_byte(1)
+        )
+    );
+    assert_eq!(nameReturn { _0: "a".into() }, nameReturn { _0: "a".into() });
+
+    let error = E { a: U256::from(1) };
+    let decoded = CErrors::abi_decode(&error.abi_encode(), true).unwrap();
+    assert_eq!(decoded, CErrors::E(error));
+    assert_eq!(format!("{decoded:?}"), "E(E { a: 1 })");
+
+    let event = Ev { a: Address::repeat_byte(2), b: "b".into() };
+    let data = event.encode_log_data();
+    assert_eq!(Ev::decode_log_data(&data, true).unwrap(), event);
+
+    // custom types only derive the builtin traits with `all_derives`
+    fn assert_debug<T: std::fmt::Debug + Eq>() {}
+    assert_debug::<WithStruct::gCall>();
+    let _ =
+        WithStruct::WithStructCalls::f(WithStruct::fCall { s: WithStruct::S { a: U256::ZERO } });
+}
+
+#[test]
+fn contract_enum_from_overloads() {
+    sol! {
+        interface IERC20 {
+            function transfer(address to, uint256 amount) returns (bool);
+            function transfer(address to, uint256 amount, bytes data) returns (bool);
+            // structurally identical to `transfer(address,uint256)`
+            function transfer(address from, uint256 value, bool) returns (bool);
+            function approve(address spender, uint256 amount) returns (bool);
+
+            error Unauthorized(address);
+            event Approval(address indexed owner, address indexed spender, uint256 value);
+        }
+    }
+    use IERC20::*;
+
+    let to = Address::repeat_byte(0x11);
+    let amount = U256::from(1);
+
+    let call: IERC20Calls = transfer_0Call { to, amount }.into();
+    assert!(matches!(call, IERC20Calls::transfer_0(_)));
+    let call: IERC20Calls = transfer_1Call { to, amount, data: Default::default() }.into();
+    assert!(matches!(call, IERC20Calls::transfer_1(_)));
+    let call: IERC20Calls = transfer_2Call { from: to, value: amount, _2: true }.into();
+    assert!(matches!(call, IERC20Calls::transfer_2(_)));
+    let call = IERC20Calls::from(approveCall { spender: to, amount });
+    assert!(matches!(call, IERC20Calls::approve(_)));
+
+    let error: IERC20Errors = Unauthorized { _0: to }.into();
+    assert!(matches!(error, IERC20Errors::Unauthorized(_)));
+    let event: IERC20Events = Approval { owner: to, spender: to, value: amount }.into();
+    assert!(matches!(event, IERC20Events::Approval(_)));
+}
+
+#[test]
+fn state_mutability() {
+    sol! {
+        contract Mutability {
+            uint256 public value;
+
+            function pureFn() external pure;
+            function viewFn() external view;
+            function constantFn() external constant;
+            function payableFn() external payable;
+            function nonpayableFn() external;
+        }
+    }
+    use Mutability::*;
+
+    assert_eq!(valueCall::STATE_MUTABILITY, "view");
+    assert_eq!(pureFnCall::STATE_MUTABILITY, "pure");
+    assert_eq!(viewFnCall::STATE_MUTABILITY, "view");
+    assert_eq!(constantFnCall::STATE_MUTABILITY, "view");
+    assert_eq!(payableFnCall::STATE_MUTABILITY, "payable");
+    assert_eq!(nonpayableFnCall::STATE_MUTABILITY, "nonpayable");
+}
+
 #[test]
 fn getters() {
     // modified from https://docs.soliditylang.org/en/latest/contracts.html#getter-functions
@@ -267,6 +756,147 @@ fn getter_names() {
     let _ = Getters::nestedMapWithNamesReturn { v2: String::new() };
 }
 
+#[test]
+fn selectors() {
+    mod calls {
+        alloy_sol_types::sol! {
+            function transfer(address to, uint256 amount);
+            function f(uint x, bytes32[2][] y, (bool, string)[] z);
+            function g();
+            function g(int8);
+            function g(int8, (int8, uint));
+            function h((uint,) x);
+        }
+    }
+
+    mod selectors {
+        alloy_sol_types::sol!(selectors {
+            transfer(address,uint256),
+            f(uint, bytes32[2][], (bool, string)[]),
+            g(),
+            g(int8),
+            g(int8,(int8,uint256)),
+            h((uint256,)),
+        });
+    }
+
+    assert_eq!(selectors::transfer, calls::transferCall::SELECTOR);
+    assert_eq!(selectors::f, calls::fCall::SELECTOR);
+    assert_eq!(selectors::g_0, calls::g_0Call::SELECTOR);
+    assert_eq!(selectors::g_1, calls::g_1Call::SELECTOR);
+    assert_eq!(selectors::g_2, calls::g_2Call::SELECTOR);
+    assert_eq!(selectors::h, calls::hCall::SELECTOR);
+    assert_eq!(calls::fCall::SIGNATURE, "f(uint256,bytes32[2][],(bool,string)[])");
+    assert_eq!(selectors::f, keccak256("f(uint256,bytes32[2][],(bool,string)[])")[..4]);
+
+    // empty lists are allowed
+    sol!(selectors {});
+}
+
+#[test]
+fn signatures_hash_to_selectors() {
+    sol! {
+        struct Inner {
+            uint a;
+            bytes32[] b;
+        }
+        struct Outer {
+            Inner inner;
+            Inner[2] inners;
+            string c;
+        }
+        enum Kind { A, B }
+        type Price is uint128;
+
+        function empty();
+        function aliases(uint a, int b, address payable c);
+        function arrays(uint[] a, bool[2][] b, bytes[][3] c);
+        function tuples((uint, (bool, string)[]) a, (address,) b);
+        function structs(Outer o, Inner[] i);
+        function customs(Kind k, Price p, function(uint) external returns (bool) f);
+        function overloaded(uint a);
+        function overloaded(Inner i);
+
+        error CustomError(Outer o, Kind k);
+        error EmptyError();
+
+        event CustomEvent(Outer indexed o, Kind k, Price p);
+        event AnonymousEvent(uint indexed a) anonymous;
+    }
+
+    macro_rules! assert_signatures {
+        ($($ty:ty => $trait:ident $sig:literal),* $(,)?) => {$(
+            assert_signatures!(@ $trait $ty, $sig);
+        )*};
+        (@ SolCall $ty:ty, $sig:literal) => {
+            assert_eq!(<$ty as SolCall>::SIGNATURE, $sig);
+            assert_eq!(<$ty as SolCall>::signature(), $sig);
+            assert_eq!(<$ty as SolCall>::SELECTOR, keccak256($sig)[..4]);
+            assert_eq!(<$ty as SolCall>::signature_selector(), <$ty as SolCall>::SELECTOR);
+        };
+        (@ SolError $ty:ty, $sig:literal) => {
+            assert_eq!(<$ty as SolError>::SIGNATURE, $sig);
+            assert_eq!(<$ty as SolError>::SELECTOR, keccak256($sig)[..4]);
+        };
+        (@ SolEvent $ty:ty, $sig:literal) => {
+            assert_eq!(<$ty as SolEvent>::SIGNATURE, $sig);
+            assert_eq!(<$ty as SolEvent>::SIGNATURE_HASH, keccak256($sig));
+        };
+    }
+
+    assert_signatures! {
+        emptyCall => SolCall "empty()",
+        aliasesCall => SolCall "aliases(uint256,int256,address)",
+        arraysCall => SolCall "arrays(uint256[],bool[2][],bytes[][3])",
+        tuplesCall => SolCall "tuples((uint256,(bool,string)[]),(address))",
+        structsCall => SolCall
+            "structs(((uint256,bytes32[]),(uint256,bytes32[])[2],string),(uint256,bytes32[])[])",
+        customsCall => SolCall "customs(uint8,uint128,function)",
+        overloaded_0Call => SolCall "overloaded(uint256)",
+        overloaded_1Call => SolCall "overloaded((uint256,bytes32[]))",
+        CustomError => SolError
+            "CustomError(((uint256,bytes32[]),(uint256,bytes32[])[2],string),uint8)",
+        EmptyError => SolError "EmptyError()",
+        CustomEvent => SolEvent
+            "CustomEvent(((uint256,bytes32[]),(uint256,bytes32[])[2],string),uint8,uint128)",
+        AnonymousEvent => SolEvent "AnonymousEvent(uint256)",
+    }
+}
+
+#[test]
+fn abigen_sol_module() {
+    sol!(shapes, "tests/contracts/Module.sol");
+    // the same file can be generated again in another module
+    sol!(
+        #[sol(all_derives)]
+        shapes_with_derives,
+        "tests/contracts/Module.sol"
+    );
+
+    let point = shapes::Point { x: U256::from(1), y: U256::from(2) };
+    let call = shapes::distanceCall { a: point.clone(), b: point.clone() };
+    assert_eq!(shapes::distanceCall::SIGNATURE, "distance((uint256,uint256),(uint256,uint256))");
+    assert_eq!(call.a.y, U256::from(2));
+    assert_eq!(shapes::OutOfBounds::SIGNATURE, "OutOfBounds((uint256,uint256))");
+    assert_eq!(shapes::Moved::SIGNATURE, "Moved(address,(uint256,uint256))");
+
+    let area = shapes::IShapes::areaCall { points: vec![point] };
+    let calls = shapes::IShapes::IShapesCalls::area(area.clone());
+    assert_eq!(alloy_sol_types::SolInterface::abi_encode(&calls), area.abi_encode());
+    let _ = shapes::IShapes::IShapesEvents::Drawn(shapes::IShapes::Drawn { id: U256::ZERO });
+
+    let point = shapes_with_derives::Point { x: U256::ZERO, y: U256::ZERO };
+    assert_eq!(point, shapes_with_derives::Point::default());
+}
+
+// Pragmas and imports are accepted and ignored; imports are not resolved.
+#[test]
+fn abigen_sol_imports() {
+    sol!("../syn-solidity/tests/contracts/Imports.sol");
+
+    assert_eq!(IImports::transferCall::SIGNATURE, "transfer(address,uint256)");
+}
+
 #[test]
 fn abigen_sol_multicall() {
     sol!("../syn-solidity/tests/contracts/Multicall.sol");
@@ -399,6 +1029,162 @@ fn struct_field_attrs() {
     );
 }
 
+#[test]
+fn struct_serde_attrs() {
+    sol! {
+        #[derive(Default, Serialize)]
+        #[sol(serde(rename_all = "camelCase"))]
+        struct CamelCase {
+            uint64 some_value;
+            address owner_address;
+            #[serde(rename = "renamed")]
+            bool is_set;
+        }
+    }
+
+    assert_eq!(
+        serde_json::to_value(CamelCase::default()).unwrap(),
+        serde_json::json!({
+            "someValue": 0,
+            "ownerAddress": Address::ZERO,
+            "renamed": false,
+        })
+    );
+}
+
+#[test]
+fn struct_pretty_debug() {
+    sol! {
+        #![sol(all_derives, pretty_debug)]
+
+        struct Inner {
+            address owner;
+            int8 delta;
+        }
+
+        struct Outer {
+            uint256 amount;
+            bytes data;
+            bytes4 selector;
+            Inner[] inners;
+        }
+    }
+
+    let outer = Outer {
+        amount: U256::from(1_000_000),
+        data: bytes!("dead"),
+        selector: [0x12, 0x34, 0x56, 0x78].into(),
+        inners: vec![Inner {
+            owner: Address::from(hex!("5aaeb6053f3e94c9b9a09f33669435e7ef1beaed")),
+            delta: -1,
+        }],
+    };
+    assert_eq!(
+        format!("{outer:?}"),
+        "Outer { amount: 1000000, data: 0xdead, selector: 0x12345678, inners: [Inner { owner: \
+         0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed, delta: -1 }] }"
+    );
+}
+
+#[test]
+fn struct_pretty_debug_custom_fields() {
+    sol! {
+        #![sol(pretty_debug)]
+
+        enum Side { Buy, Sell }
+        type Wallet is address;
+        type Price is uint128;
+
+        struct Order {
+            uint128 amount;
+            int96 delta;
+            Side side;
+            Wallet wallet;
+            Price price;
+        }
+    }
+
+    let order = Order {
+        amount: u128::MAX,
+        delta: -5,
+        side: Side::Sell,
+        wallet: Address::from(hex!("5aaeb6053f3e94c9b9a09f33669435e7ef1beaed")),
+        price: 42,
+    };
+    assert_eq!(
+        format!("{order:?}"),
+        format!(
+            "Order {{ amount: {}, delta: -5, side: Sell, wallet: \
+             0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed, price: 42 }}",
+            u128::MAX
+        )
+    );
+}
+
+#[test]
+fn struct_pretty_debug_tuple() {
+    sol! {
+        #[sol(pretty_debug)]
+        struct Pair {
+            (uint256, address) t;
+            (bytes, (int8,))[] ts;
+        }
+    }
+
+    let owner = Address::from(hex!("5aaeb6053f3e94c9b9a09f33669435e7ef1beaed"));
+    let pair = Pair { t: (U256::from(1), owner), ts: vec![(bytes!("dead"), (-1,))] };
+    assert_eq!(
+        format!("{pair:?}"),
+        "Pair { t: (1, 0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed), ts: [(0xdead, (-1,))] }"
+    );
+}
+
+#[test]
+fn item_extra_derives() {
+    use std::collections::HashSet;
+
+    sol! {
+        #[sol(extra_derives(PartialEq, Eq, Hash))]
+        struct Transfer {
+            address to;
+            uint256 amount;
+            bytes32[] tags;
+            string memo;
+        }
+
+        #[sol(extra_derives(PartialEq, Eq, Hash))]
+        contract Token {
+            struct Approval {
+                address spender;
+                uint256[] pending;
+            }
+        }
+    }
+
+    let transfer = |amount: u64| Transfer {
+        to: Address::repeat_byte(0x11),
+        amount: U256::from(amount),
+        tags: vec![B256::repeat_byte(0x22)],
+        memo: "memo".into(),
+    };
+    let data = [transfer(1), transfer(2), transfer(1)]
+        .iter()
+        .map(Transfer::abi_encode)
+        .collect::<Vec<_>>();
+    let decoded =
+        data.iter().map(|d| Transfer::abi_decode(d, true).unwrap()).collect::<HashSet<_>>();
+    assert_eq!(decoded.len(), 2);
+    assert!(decoded.contains(&transfer(1)));
+    assert!(decoded.contains(&transfer(2)));
+
+    let approvals = [
+        Token::Approval { spender: Address::ZERO, pending: vec![U256::from(1)] },
+        Token::Approval { spender: Address::ZERO, pending: vec![U256::from(1)] },
+        Token::Approval { spender: Address::ZERO, pending: vec![] },
+    ];
+    assert_eq!(approvals.into_iter().collect::<HashSet<_>>().len(), 2);
+}
+
 #[test]
 fn enum_variant_attrs() {
     sol! {
@@ -637,6 +1423,371 @@ fn string_indexed_event() {
     }
 }
 
+#[test]
+fn cfg() {
+    // `all()` is always enabled, like an enabled feature.
+    mod enabled {
+        alloy_sol_types::sol! {
+            #![sol(cfg = "all()")]
+
+            struct S {
+                uint256 a;
+            }
+
+            #[sol(cfg = "all(all(), not(any()))")]
+            contract C {
+                function f(S s);
+                event E(uint256 a);
+            }
+        }
+    }
+
+    // `any()` is always disabled, like a disabled feature.
+    #[allow(dead_code, non_camel_case_types, non_snake_case, unreachable_pub)]
+    mod disabled {
+        alloy_sol_types::sol! {
+            #[sol(cfg = "any()")]
+            struct S {
+                uint256 a;
+            }
+
+            #[sol(cfg = "not(all())")]
+            contract C {
+                function f(S s);
+            }
+
+            #[sol(cfg = "any()")]
+            function g(uint256 a);
+        }
+
+        // These would conflict with the generated items if any were emitted.
+        pub struct S;
+        pub mod C {}
+        pub struct gCall;
+        pub struct gReturn;
+    }
+
+    let s = enabled::S { a: U256::from(1) };
+    assert_eq!(enabled::C::fCall { s }.abi_encode().len(), 4 + 32);
+    assert_eq!(enabled::C::E::SIGNATURE, "E(uint256)");
+    let _ = disabled::S;
+}
+
+#[test]
+fn transparent_structs() {
+    use alloy_sol_types::SolValue;
+
+    sol! {
+        #[derive(Debug, PartialEq)]
+        #[sol(transparent)]
+        struct Wrapper {
+            uint256 value;
+        }
+
+        #[derive(Debug, PartialEq)]
+        #[sol(transparent)]
+        struct DynWrapper {
+            bytes data;
+        }
+
+        #[derive(Debug, PartialEq)]
+        struct Pair {
+            bytes data;
+            uint256 value;
+        }
+    }
+
+    assert_eq!(std::mem::size_of::<Wrapper>(), std::mem::size_of::<U256>());
+    let w = Wrapper::from(U256::from(42));
+    assert_eq!(w.value, U256::from(42));
+    let value: U256 = w.into();
+    assert_eq!(value, U256::from(42));
+
+    // ABI-wise these are still tuples.
+    let w = Wrapper { value: U256::from(42) };
+    assert_eq!(<Wrapper as SolStruct>::eip712_encode_type(), "Wrapper(uint256 value)");
+    assert_eq!(w.abi_encode(), (U256::from(42),).abi_encode());
+    assert_eq!(<Wrapper as SolType>::abi_decode(&w.abi_encode(), true).unwrap(), w);
+
+    let d = DynWrapper::from(bytes!("c0ffee"));
+    let encoded = d.abi_encode();
+    assert_eq!(encoded, (bytes!("c0ffee"),).abi_encode());
+    assert_eq!(<DynWrapper as SolType>::abi_decode(&encoded, true).unwrap(), d);
+
+    // The field is encoded the same way as in a multi-field struct.
+    let p = Pair { data: bytes!("c0ffee"), value: U256::from(42) };
+    let pair_encoded = p.abi_encode();
+    assert_eq!(pair_encoded, (bytes!("c0ffee"), U256::from(42)).abi_encode());
+    assert_eq!(encoded[..32], pair_encoded[..32]);
+    assert_eq!(encoded[64..], pair_encoded[96..]);
+}
+
+#[test]
+fn struct_builder() {
+    use alloy_sol_types::{MissingFieldError, SolValue};
+
+    sol! {
+        #[derive(Debug, PartialEq)]
+        #[sol(builder)]
+        struct Order {
+            address maker;
+            uint256 amount;
+            bytes data;
+        }
+    }
+
+    let maker = Address::repeat_byte(0x11);
+    let order = Order::builder()
+        .data(bytes!("c0ffee"))
+        .maker(maker)
+        .amount(U256::from(42))
+        .build()
+        .unwrap();
+    let positional = Order { maker, amount: U256::from(42), data: bytes!("c0ffee") };
+    assert_eq!(order, positional);
+    assert_eq!(order.abi_encode(), positional.abi_encode());
+
+    // setting a field twice keeps the last value
+    let order = Order::builder()
+        .maker(Address::ZERO)
+        .maker(maker)
+        .amount(U256::ZERO)
+        .data(bytes!(""))
+        .build();
+    assert_eq!(order.unwrap().maker, maker);
+
+    let err = Order::builder().amount(U256::from(1)).build().unwrap_err();
+    assert_eq!(err, MissingFieldError { name: "Order", fields: vec!["maker", "data"] });
+    assert_eq!(err.to_string(), "missing fields when building Order: `maker`, `data`");
+    assert_eq!(Order::builder().build().unwrap_err().fields, ["maker", "amount", "data"]);
+}
+
+#[test]
+fn event_topic_types() {
+    sol! {
+        struct S {
+            uint256 a;
+            string b;
+        }
+
+        type Price is uint;
+
+        event E(string indexed name, uint256 value, address indexed owner, bytes indexed data);
+        event Anon(S indexed s, uint[] indexed arr, Price indexed p) anonymous;
+        event NoIndexed(uint256 a, string b);
+    }
+
+    assert_eq!(E::TOPIC_TYPES, ["string", "address", "bytes"]);
+    assert_eq!(Anon::TOPIC_TYPES, ["S", "uint[]", "Price"]);
+    assert_eq!(NoIndexed::TOPIC_TYPES, [] as [&str; 0]);
+}
+
+#[test]
+fn event_matches_selector() {
+    sol! {
+        event Transfer(address indexed from, address indexed to, uint256 value);
+        event Approval(address indexed owner, address indexed spender, uint256 value);
+        event Anon(address indexed from, uint256 value) anonymous;
+    }
+
+    let transfer = Transfer { from: Address::ZERO, to: Address::ZERO, value: U256::from(1) };
+    let log = transfer.encode_log_data();
+    assert!(Transfer::matches_selector(Transfer::SIGNATURE_HASH));
+    assert!(Transfer::matches_log(&log));
+    assert!(!Approval::matches_selector(Transfer::SIGNATURE_HASH));
+    assert!(!Approval::matches_log(&log));
+    assert!(!Transfer::matches_log(&alloy_primitives::LogData::new_unchecked(vec![], log.data)));
+
+    // anonymous events have no signature topic
+    let anon = Anon { from: Address::ZERO, value: U256::from(1) };
+    assert!(!Anon::matches_selector(Anon::SIGNATURE_HASH));
+    assert!(!Anon::matches_log(&anon.encode_log_data()));
+}
+
+#[test]
+fn event_filter() {
+    sol! {
+        #![sol(filters)]
+        event Transfer(address indexed from, address indexed to, uint256 value);
+        event Named(string indexed name, uint256 indexed id);
+        event Anon(address indexed from, bool indexed, uint256 value) anonymous;
+        event Topics(uint256 indexed topics);
+    }
+
+    fn topics(filter: impl Into<[Option<B256>; 4]>) -> [Option<B256>; 4] {
+        filter.into()
+    }
+
+    let from = Address::with_last_byte(1);
+    let from_topic = from.into_word();
+    assert_eq!(topics(Transfer::filter()), [Some(Transfer::SIGNATURE_HASH), None, None, None]);
+    assert_eq!(
+        topics(Transfer::filter().from(from)),
+        [Some(Transfer::SIGNATURE_HASH), Some(from_topic), None, None]
+    );
+    assert_eq!(
+        topics(Transfer::filter().to(from)),
+        [Some(Transfer::SIGNATURE_HASH), None, Some(from_topic), None]
+    );
+
+    // the filter matches the topics of an emitted log
+    let log = Transfer { from, to: Address::ZERO, value: U256::from(1) }.encode_log_data();
+    let filter = topics(Transfer::filter().from(from).to(Address::ZERO));
+    assert!(filter.iter().zip(log.topics()).all(|(f, t)| f.map_or(true, |f| f == *t)));
+
+    // indexed dynamic parameters are filtered by their hash
+    let hash = keccak256("alice");
+    assert_eq!(
+        topics(Named::filter().name(hash).id(U256::from(2))),
+        [Some(Named::SIGNATURE_HASH), Some(hash), Some(B256::from(U256::from(2))), None]
+    );
+
+    // anonymous events have no signature topic
+    assert_eq!(topics(Anon::filter()), [None; 4]);
+    assert_eq!(topics(Anon::filter()._1(true)), [None, Some(B256::with_last_byte(1)), None, None]);
+
+    // any parameter name can be filtered by
+    assert_eq!(
+        topics(Topics::filter().topics(U256::from(1))),
+        [Some(Topics::SIGNATURE_HASH), Some(B256::with_last_byte(1)), None, None]
+    );
+}
+
+#[test]
+fn event_filter_opt_in() {
+    // without `filters`, no `TransferFilter` is generated, so the name is free
+    sol! {
+        contract Token {
+            struct TransferFilter { address from; }
+            event Transfer(address indexed from, address indexed to, uint256 value);
+        }
+    }
+    let _ = Token::TransferFilter { from: Address::ZERO };
+
+    sol! {
+        #[sol(filters)]
+        contract FilteredToken {
+            event Transfer(address indexed from, address indexed to, uint256 value);
+        }
+    }
+    use FilteredToken::Transfer;
+    let filter: [Option<B256>; 4] = Transfer::filter().into();
+    assert_eq!(filter, [Some(Transfer::SIGNATURE_HASH), None, None, None]);
+}
+
+// Solidity packs adjacent `bool`s in storage, but the ABI gives each one its own word.
+#[test]
+fn struct_bools_not_packed() {
+    sol! {
+        #[derive(Debug, PartialEq)]
+        struct Flags {
+            bool a;
+            bool b;
+            uint256 x;
+            bool c;
+            uint256 y;
+            bool d;
+            bool e;
+        }
+
+        function f(Flags flags, bool g) returns (bool, bool);
+    }
+
+    let flags = Flags {
+        a: true,
+        b: false,
+        x: U256::from(0x1234),
+        c: true,
+        y: U256::MAX,
+        d: true,
+        e: false,
+    };
+    let encoded = Flags::abi_encode(&flags);
+    assert_eq!(encoded.len(), 7 * 32);
+    assert_eq!(Flags::abi_encoded_size(&flags), 7 * 32);
+    assert_eq!(
+        encoded,
+        hex!(
+            "0000000000000000000000000000000000000000000000000000000000000001
+             0000000000000000000000000000000000000000000000000000000000000000
+             0000000000000000000000000000000000000000000000000000000000001234
+             0000000000000000000000000000000000000000000000000000000000000001
+             ffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff
+             0000000000000000000000000000000000000000000000000000000000000001
+             0000000000000000000000000000000000000000000000000000000000000000"
+        )
+    );
+    assert_eq!(Flags::abi_decode(&encoded, true).unwrap(), flags);
+
+    // packed encoding is the only one that uses a single byte per `bool`
+    assert_eq!(Flags::abi_encode_packed(&flags).len(), 5 + 2 * 32);
+
+    // every combination round-trips
+    for bits in 0u8..32 {
+        let flags = Flags {
+            a: bits & 1 != 0,
+            b: bits & 2 != 0,
+            x: U256::from(bits),
+            c: bits & 4 != 0,
+            y: U256::from(!bits),
+            d: bits & 8 != 0,
+            e: bits & 16 != 0,
+        };
+        assert_eq!(Flags::abi_decode(&Flags::abi_encode(&flags), true).unwrap(), flags);
+    }
+
+    // a dirty `bool` word is rejected when validating
+    let mut dirty = encoded.clone();
+    dirty[31] = 2;
+    assert!(Flags::abi_decode(&dirty, true).is_err());
+
+    let call = fCall { flags: flags.clone(), g: true };
+    let encoded_call = call.abi_encode();
+    assert_eq!(encoded_call.len(), 4 + 8 * 32);
+    assert_eq!(encoded_call[4..4 + 7 * 32], encoded[..]);
+    assert_eq!(fCall::abi_decode(&encoded_call, true).unwrap().flags, flags);
+
+    let returns = fCall::abi_encode_returns(&(true, true));
+    assert_eq!(returns.len(), 2 * 32);
+    let fReturn { _0, _1 } = fCall::abi_decode_returns(&returns, true).unwrap();
+    assert!(_0 && _1);
+}
+
+#[test]
+fn call_arg_metadata() {
+    sol! {
+        struct S {
+            uint256 a;
+            string b;
+        }
+
+        function named(address to, uint amount, S memory s) external;
+        function unnamed(bytes32, (bool, uint8[2]) memory t, string) external;
+        function empty() external;
+    }
+
+    assert_eq!(namedCall::ARG_COUNT, 3);
+    assert_eq!(namedCall::ARG_TYPES, ["address", "uint256", "(uint256,string)"]);
+    assert_eq!(namedCall::ARG_NAMES, ["to", "amount", "s"]);
+
+    assert_eq!(unnamedCall::ARG_COUNT, 3);
+    assert_eq!(unnamedCall::ARG_TYPES, ["bytes32", "(bool,uint8[2])", "string"]);
+    assert_eq!(unnamedCall::ARG_NAMES, ["", "t", ""]);
+
+    assert_eq!(emptyCall::ARG_COUNT, 0);
+    assert_eq!(emptyCall::ARG_TYPES, [] as [&str; 0]);
+    assert_eq!(emptyCall::ARG_NAMES, [] as [&str; 0]);
+
+    assert_eq!(
+        format!("named({})", namedCall::ARG_TYPES.join(",")),
+        <namedCall as SolCall>::SIGNATURE
+    );
+    assert_eq!(
+        format!("unnamed({})", unnamedCall::ARG_TYPES.join(",")),
+        <unnamedCall as SolCall>::SIGNATURE
+    );
+}
+
 // TODO: make commented out code work
 #[test]
 fn paths_resolution_1() {
@@ -925,3 +2076,153 @@ fn contract_derive_default() {
     let MyContract::e2 {} = MyContract::e2::default();
     let MyContract::c {} = MyContract::c::default();
 }
+
+#[test]
+fn contract_inheritance() {
+    use alloy_sol_types::SolInterface;
+
+    sol! {
+        interface IA {
+            struct S { uint256 a; }
+            function a(S s) external returns (uint256);
+            event A(uint256 indexed a);
+            error AError();
+        }
+
+        interface IB is IA {
+            function b() external;
+        }
+
+        interface IC is IA {
+            function c() external;
+            event C();
+        }
+
+        interface ID is IB, IC {
+            function d() external;
+        }
+    }
+
+    let call = IB::aCall { s: IB::S { a: U256::from(1) } };
+    let data = call.abi_encode();
+    assert_eq!(data[..4], IA::aCall::SELECTOR);
+    let decoded = IB::IBCalls::abi_decode(&data, true).unwrap();
+    assert!(matches!(decoded, IB::IBCalls::a(IB::aCall { s }) if s.a == call.s.a));
+    assert_eq!(IB::IBCalls::SELECTORS.len(), 2);
+    assert_eq!(IB::IBErrors::SELECTORS, [IA::AError::SELECTOR]);
+    assert_eq!(IB::IBEvents::SELECTORS, [IA::A::SIGNATURE_HASH.0]);
+
+    // `IA` is reached through both `IB` and `IC`, but its items are only included once.
+    assert_eq!(ID::IDCalls::SELECTORS.len(), 4);
+    assert_eq!(ID::IDEvents::SELECTORS.len(), 2);
+    let _ = ID::IDCalls::d(ID::dCall {});
+    let _ = ID::IDCalls::b(ID::bCall {});
+    let _ = ID::IDCalls::c(ID::cCall {});
+    let _ = ID::IDEvents::C(ID::C {});
+}
+
+#[test]
+fn array_repr_box() {
+    sol! {
+        #![sol(array_repr = "box")]
+
+        struct Inner {
+            uint256[] values;
+            uint256[2] fixed;
+        }
+
+        struct Outer {
+            Inner[] inners;
+            bytes32[][] nested;
+            string name;
+        }
+
+        event E(Outer indexed outer);
+    }
+
+    let inner = Inner { values: Box::new([U256::from(1), U256::from(2)]), fixed: [U256::ZERO; 2] };
+    let outer = Outer {
+        inners: vec![inner.clone(), inner].into_boxed_slice(),
+        nested: Box::new([vec![B256::ZERO]]),
+        name: "boxed".into(),
+    };
+
+    let encoded = Outer::abi_encode(&outer);
+    let decoded = Outer::abi_decode(&encoded, true).unwrap();
+    assert_eq!(Outer::abi_encode(&decoded), encoded);
+    assert_eq!(decoded.inners.len(), 2);
+    assert_eq!(*decoded.inners[1].values, [U256::from(1), U256::from(2)]);
+    assert_eq!(decoded.eip712_hash_struct(), outer.eip712_hash_struct());
+
+    let mut preimage = Vec::new();
+    <Outer as alloy_sol_types::EventTopic>::encode_topic_preimage(&outer, &mut preimage);
+    assert_eq!(
+        preimage.len(),
+        <Outer as alloy_sol_types::EventTopic>::topic_preimage_length(&outer)
+    );
+}
+
+/// `alloy_sol_types` is shadowed by an empty module here, so the generated code only compiles if
+/// all of its paths resolve through the custom crate path.
+mod custom_sol_types_path {
+    #![allow(dead_code)]
+
+    use ::alloy_sol_types::{SolCall, SolError, SolEvent, SolInterface, SolStruct, SolValue};
+    use alloy_primitives::{Address, U256};
+
+    mod alloy_sol_types {}
+
+    mod reexports {
+        pub(super) use ::alloy_sol_types as sol_types;
+    }
+
+    ::alloy_sol_types::sol! {
+        #![sol(alloy_sol_types = crate::sol::custom_sol_types_path::reexports::sol_types)]
+
+        struct Outside {
+            uint256 a;
+        }
+
+        event OutsideEvent(address indexed from, uint256 value);
+
+        contract Renamed {
+            enum Kind { A, B }
+            type Amount is uint256;
+
+            struct Params {
+                address to;
+                Amount amount;
+                Kind kind;
+            }
+
+            function transfer(Params params) returns (bool);
+            error Failed(uint256 code);
+            event Transferred(address indexed to, Amount amount);
+        }
+    }
+
+    #[test]
+    fn custom_sol_types_path() {
+        use Renamed::*;
+
+        let params = Params { to: Address::repeat_byte(1), amount: U256::from(2), kind: Kind::B };
+        let call = transferCall { params: params.clone() };
+        let encoded = call.abi_encode();
+        assert_eq!(transferCall::abi_decode(&encoded, true).unwrap().params.to, params.to);
+        assert!(RenamedCalls::abi_decode(&encoded, true).is_ok());
+        assert_eq!(Params::eip712_root_type(), "Params(address to,uint256 amount,uint8 kind)");
+
+        let error = Failed { code: U256::from(3) };
+        assert_eq!(Failed::abi_decode(&error.abi_encode(), true).unwrap().code, error.code);
+
+        let event = Transferred { to: params.to, amount: params.amount };
+        assert_eq!(
+            Transferred::decode_log_data(&event.encode_log_data(), true).unwrap().to,
+            params.to
+        );
+
+        let outside = Outside { a: U256::from(4) };
+        assert_eq!(Outside::abi_decode(&outside.abi_encode(), true).unwrap().a, outside.a);
+        assert_eq!(OutsideEvent::SIGNATURE, "OutsideEvent(address,uint256)");
+    }
+}
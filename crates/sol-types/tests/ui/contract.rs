@@ -34,4 +34,25 @@ sol! {
     }
 }
 
+sol! {
+    contract SelectorCollision {
+        function burn(uint256);
+        function collate_propagate_storage(bytes16);
+    }
+}
+
+sol! {
+    interface CyclicA is CyclicB {}
+    interface CyclicB is CyclicA {}
+}
+
+sol! {
+    interface ConflictBase {
+        struct S { uint a; }
+    }
+    interface ConflictDerived is ConflictBase {
+        struct S { uint b; }
+    }
+}
+
 fn main() {}
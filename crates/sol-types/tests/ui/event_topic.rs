@@ -0,0 +1,14 @@
+use alloy_sol_types::{sol_data, EventTopic};
+
+#[derive(EventTopic)]
+struct Unit;
+
+#[derive(EventTopic)]
+struct TwoFields(sol_data::Uint<256>, sol_data::Bool);
+
+#[derive(EventTopic)]
+enum Enum {
+    A(sol_data::Bool),
+}
+
+fn main() {}
@@ -104,17 +104,21 @@ impl ItemEvent {
     }
 
     /// Asserts that the event has a valid amount of indexed parameters.
+    ///
+    /// The error points to the first `indexed` keyword that exceeds
+    /// [`Self::max_indexed`].
     pub fn assert_valid(&self) -> Result<()> {
-        if self.exceeds_max_indexed() {
-            let msg = if self.is_anonymous() {
-                "more than 4 indexed arguments for anonymous event"
-            } else {
-                "more than 3 indexed arguments for event"
-            };
-            Err(Error::new(self.span(), msg))
+        let max = self.max_indexed();
+        let Some(first_excess) = self.indexed_params().nth(max) else {
+            return Ok(());
+        };
+        let msg = if self.is_anonymous() {
+            format!("anonymous events allow at most {max} indexed parameters")
         } else {
-            Ok(())
-        }
+            format!("non-anonymous events allow at most {max} indexed parameters")
+        };
+        let span = first_excess.indexed.as_ref().map_or_else(|| first_excess.span(), |kw| kw.span);
+        Err(Error::new(span, msg))
     }
 
     pub fn params(&self) -> ParameterList {
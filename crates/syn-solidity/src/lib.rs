@@ -61,7 +61,7 @@ pub use stmt::{
 };
 
 mod r#type;
-pub use r#type::{Type, TypeArray, TypeFunction, TypeMapping, TypeTuple};
+pub use r#type::{Type, TypeArray, TypeFunction, TypeMapping, TypeTuple, MAX_TYPE_DEPTH};
 
 pub(crate) mod utils;
 
@@ -1,6 +1,7 @@
 use crate::{kw, sol_path, SolPath, Spanned};
 use proc_macro2::Span;
 use std::{
+    cell::Cell,
     fmt,
     fmt::Write,
     hash::{Hash, Hasher},
@@ -25,6 +26,38 @@ pub use mapping::TypeMapping;
 mod tuple;
 pub use tuple::TypeTuple;
 
+/// The maximum nesting depth of tuple, function and mapping types.
+///
+/// Deeper types are rejected with an error instead of overflowing the stack
+/// while parsing.
+pub const MAX_TYPE_DEPTH: usize = 64;
+
+thread_local! {
+    static TYPE_DEPTH: Cell<usize> = const { Cell::new(0) };
+}
+
+/// Calls `f`, which parses a type nested inside of another type, while keeping
+/// track of the nesting depth.
+fn parse_nested<T>(input: ParseStream<'_>, f: fn(ParseStream<'_>) -> Result<T>) -> Result<T> {
+    struct Guard;
+    impl Drop for Guard {
+        fn drop(&mut self) {
+            TYPE_DEPTH.with(|depth| depth.set(depth.get() - 1));
+        }
+    }
+
+    let depth = TYPE_DEPTH.with(|depth| {
+        depth.set(depth.get() + 1);
+        depth.get()
+    });
+    let _guard = Guard;
+    if depth > MAX_TYPE_DEPTH {
+        let msg = format!("type nesting depth exceeds the maximum of {MAX_TYPE_DEPTH}");
+        return Err(input.error(msg));
+    }
+    f(input)
+}
+
 /// A type name.
 ///
 /// Solidity reference:
@@ -453,11 +486,11 @@ impl Type {
     #[inline]
     fn parse_simple(input: ParseStream<'_>) -> Result<Self> {
         if input.peek(Paren) || input.peek(kw::tuple) {
-            input.parse().map(Self::Tuple)
+            parse_nested(input, |input| input.parse().map(Self::Tuple))
         } else if input.peek(kw::function) {
-            input.parse().map(Self::Function)
+            parse_nested(input, |input| input.parse().map(Self::Function))
         } else if input.peek(kw::mapping) {
-            input.parse().map(Self::Mapping)
+            parse_nested(input, |input| input.parse().map(Self::Mapping))
         } else if input.peek2(Token![.]) {
             input.parse().map(Self::Custom)
         } else if input.peek(Ident::peek_any) {
@@ -497,3 +530,26 @@ fn parse_size(s: &str, span: Span) -> Result<Option<Option<NonZeroU16>>> {
     };
     Ok(opt)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn max_depth() {
+        let nested = |n: usize| format!("{}uint256{}", "(".repeat(n), ",)".repeat(n));
+
+        syn::parse_str::<Type>(&nested(MAX_TYPE_DEPTH)).unwrap();
+        for n in [MAX_TYPE_DEPTH + 1, 1000] {
+            let e = syn::parse_str::<Type>(&nested(n)).unwrap_err();
+            assert_eq!(e.to_string(), "type nesting depth exceeds the maximum of 64");
+        }
+        let e = syn::parse_str::<crate::File>(&format!("struct S {{ {} a; }}", nested(1000)))
+            .unwrap_err();
+        assert_eq!(e.to_string(), "type nesting depth exceeds the maximum of 64");
+
+        // the depth is reset after both successes and errors
+        syn::parse_str::<Type>(&nested(MAX_TYPE_DEPTH)).unwrap();
+        TYPE_DEPTH.with(|depth| assert_eq!(depth.get(), 0));
+    }
+}
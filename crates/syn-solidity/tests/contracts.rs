@@ -8,7 +8,7 @@ use std::{
 };
 use syn_solidity::{
     visit::{self, Visit},
-    File,
+    File, ImportDirective, ImportPath, Item,
 };
 
 #[test]
@@ -35,6 +35,36 @@ fn contracts() {
     patcher.unpatch();
 }
 
+#[test]
+#[cfg_attr(miri, ignore = "no fs")]
+fn imports_and_pragmas() {
+    let path = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/contracts/Imports.sol");
+    let file = parse_file(&path).unwrap();
+    let count = |f: fn(&Item) -> bool| file.items.iter().filter(|item| f(item)).count();
+    assert_eq!(count(|item| matches!(item, Item::Pragma(_))), 3);
+    assert_eq!(count(|item| matches!(item, Item::Import(_))), 5);
+    assert_eq!(count(|item| matches!(item, Item::Contract(_))), 1);
+
+    let paths: Vec<_> = file
+        .items
+        .iter()
+        .filter_map(|item| match item {
+            Item::Import(import) => Some(import.path.path().value()),
+            _ => None,
+        })
+        .collect();
+    assert_eq!(
+        paths,
+        ["./IERC20.sol", "./IERC20.sol", "./Foo.sol", "./Foo.sol", "../utils/Utils.sol"]
+    );
+    match &file.items[6] {
+        Item::Import(ImportDirective { path: ImportPath::Aliases(aliases), .. }) => {
+            assert_eq!(aliases.imports.len(), 2)
+        }
+        item => panic!("expected import aliases, got {item:?}"),
+    }
+}
+
 /// Runs `unpatch` on drop. This ensures that the patch is always reset even if
 /// the test panics.
 struct GitPatcher<'a> {
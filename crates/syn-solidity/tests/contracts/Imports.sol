@@ -0,0 +1,15 @@
+// SPDX-License-Identifier: MIT
+pragma solidity ^0.8.0;
+pragma abicoder v2;
+pragma experimental ABIEncoderV2;
+
+/* Imports are recorded in the AST, but never resolved. */
+import "./IERC20.sol";
+import "./IERC20.sol" as IERC20Module;
+import {Foo} from "./Foo.sol";
+import {Foo as Bar, Baz} from "./Foo.sol";
+import * as Utils from "../utils/Utils.sol";
+
+interface IImports {
+    function transfer(address to, uint256 amount) external returns (bool);
+}